@@ -15,6 +15,11 @@ pub mod pallet {
     use sp_runtime::traits::Saturating;
     use sp_std::prelude::*;
     use scale_info::{TypeInfo, prelude::vec::Vec};
+    use kine_tags::{ContentModeration, ContentOwnership};
+    use kine_stat_tracker::{ReputationDomain, ReputationHandler};
+
+    // community reputation gained by the creator of a community approved by vote
+    pub const APPROVED_COMMUNITY_REPUTATION: u32 = 5;
 
     #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
     pub struct CommunityRemoveDetails<AccountId, BlockNumber, SocialUserName, ProposalReason> {
@@ -248,6 +253,7 @@ pub mod pallet {
         type MaxCommunitiesPerUser: Get<u32>;
         type Currency: Currency<Self::AccountId, Balance = u128>;
         type MaxCategoryLength: Get<u32>;
+        type Reputation: ReputationHandler<Self::AccountId>;
     }
     type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
     #[pallet::event]
@@ -262,6 +268,8 @@ pub mod pallet {
         MemberRemoved(T::AccountId, u32),
         CommunityDeleted(u32),
         CommunityAddedToUser(T::AccountId, u32),
+        CommunitySuspended(u32),
+        CommunityReinstated(u32),
     }
 
     #[pallet::storage]
@@ -442,6 +450,17 @@ pub mod pallet {
         ValueQuery
     >;
 
+    // Communities hidden by a successful report, they can't take in new members.
+    #[pallet::storage]
+    #[pallet::getter(fn suspended_communities)]
+    pub type SuspendedCommunities<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        (),
+        OptionQuery
+    >;
+
     #[pallet::error]
     pub enum Error<T> {
         NameTooLong,
@@ -461,11 +480,21 @@ pub mod pallet {
         AlreadyVoted,
         MaxCommunitiesReached,
         InsufficientBalance,
-        MemberNotFound
+        MemberNotFound,
+        CommunitySuspended,
+        CommunityNotSuspended
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        // Reserves the reputation given to the creators of the communities that
+        // may be approved when their voting is concluded in on_finalize.
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            let in_voting = CommunitiesByStatus::<T>::get(Status::Voting).len() as u64;
+            T::DbWeight::get().reads(1)
+                .saturating_add(T::Reputation::update_weight().saturating_mul(in_voting))
+        }
+
         fn on_finalize(now: BlockNumberFor<T>) {
             Self::conclude_voting(now);
         }
@@ -618,6 +647,7 @@ pub mod pallet {
             social_user_name: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            ensure!(!SuspendedCommunities::<T>::contains_key(community_id), Error::<T>::CommunitySuspended);
         
             Communities::<T>::try_mutate(community_id, |community| {
                 let community = community.as_mut().ok_or(Error::<T>::CommunityNotFound)?;
@@ -674,6 +704,7 @@ pub mod pallet {
             social_user_name: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            ensure!(!SuspendedCommunities::<T>::contains_key(community_id), Error::<T>::CommunitySuspended);
         
             Communities::<T>::try_mutate(community_id, |community| {
                 let community = community.as_mut().ok_or(Error::<T>::CommunityNotFound)?;
@@ -882,6 +913,7 @@ pub mod pallet {
             social_user_name: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            ensure!(!SuspendedCommunities::<T>::contains_key(community_id), Error::<T>::CommunitySuspended);
         
             Communities::<T>::try_mutate(community_id, |community| {
                 let community = community.as_mut().ok_or(Error::<T>::CommunityNotFound)?;
@@ -1091,6 +1123,7 @@ pub mod pallet {
                             }
                         });
     
+                        T::Reputation::update_reputation(&created_by, ReputationDomain::Community, APPROVED_COMMUNITY_REPUTATION, false);
                         Self::deposit_event(Event::MemberAdded(created_by, community_id));
                     }
     
@@ -1108,4 +1141,29 @@ pub mod pallet {
         }
         
     }
+
+    impl<T: Config> ContentOwnership<u32, T::AccountId> for Pallet<T> {
+        fn content_owner(community_id: &u32) -> Result<Option<T::AccountId>, DispatchError> {
+            let community = Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotFound)?;
+            Ok(Some(community.created_by))
+        }
+    }
+
+    impl<T: Config> ContentModeration<u32> for Pallet<T> {
+        fn suspend_content(community_id: &u32) -> DispatchResult {
+            ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotFound);
+            SuspendedCommunities::<T>::insert(community_id, ());
+
+            Self::deposit_event(Event::CommunitySuspended(*community_id));
+            Ok(())
+        }
+
+        fn reinstate_content(community_id: &u32) -> DispatchResult {
+            ensure!(SuspendedCommunities::<T>::contains_key(community_id), Error::<T>::CommunityNotSuspended);
+            SuspendedCommunities::<T>::remove(community_id);
+
+            Self::deposit_event(Event::CommunityReinstated(*community_id));
+            Ok(())
+        }
+    }
 }
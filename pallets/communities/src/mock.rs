@@ -57,7 +57,7 @@ impl frame_system::Config for Test {
 
 impl kinera_communities::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-
+	type Reputation = ();
 }
 
 
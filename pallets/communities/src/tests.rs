@@ -6,6 +6,7 @@ mod tests {
     use frame_system::{EventRecord, Phase};
     use frame_support::traits::{OnFinalize, OnInitialize};
     use crate::pallet::{Event, Error};
+    use kine_tags::ContentModeration;
 
     // Configurações de teste
     #[test]
@@ -287,4 +288,42 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn suspended_communities_cant_be_joined_until_reinstated() {
+        new_test_ext().execute_with(|| {
+            let creator = 1;
+            let user = 2;
+
+            assert_ok!(Communities::create_community(
+                Origin::signed(creator),
+                b"Kinera".to_vec(),
+                b"Community description".to_vec(),
+                CommunityType::Public { monthly_fee: 0 },
+                b"icon".to_vec(),
+                b"user_social".to_vec(),
+                b"Reason".to_vec(),
+            ));
+
+            assert_ok!(<Communities as ContentModeration<u32>>::suspend_content(&1));
+            assert_noop!(
+                Communities::add_member(Origin::signed(user), 1, b"username_social".to_vec()),
+                Error::<Test>::CommunitySuspended
+            );
+
+            assert_ok!(<Communities as ContentModeration<u32>>::reinstate_content(&1));
+            assert_ok!(Communities::add_member(Origin::signed(user), 1, b"username_social".to_vec()));
+
+            // a community that isn't suspended can't be reinstated
+            assert_noop!(
+                <Communities as ContentModeration<u32>>::reinstate_content(&1),
+                Error::<Test>::CommunityNotSuspended
+            );
+            // and only existing communities can be suspended
+            assert_noop!(
+                <Communities as ContentModeration<u32>>::suspend_content(&2),
+                Error::<Test>::CommunityNotFound
+            );
+        });
+    }
 }
@@ -0,0 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	// Lets the player check a wallet's festival pass before streaming.
+	pub trait FestivalPassApi<AccountId, FestivalId> where
+		AccountId: Codec,
+		FestivalId: Codec,
+	{
+		// true while the wallet holds a pass and the festival is running
+		fn has_valid_pass(festival_id: FestivalId, who: AccountId) -> bool;
+		// true if the wallet may watch the given internal movie of the festival
+		fn can_access_movie(festival_id: FestivalId, who: AccountId, movie_id: Vec<u8>) -> bool;
+	}
+}
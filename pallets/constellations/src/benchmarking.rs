@@ -1,9 +1,71 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for kinera-constellations
 
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_system::RawOrigin;
+use crate::Pallet as Festival;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{traits::Currency, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use scale_info::prelude::string::String;
+use sp_std::vec;
 
+type BalanceOf<T> = <<T as kine_stat_tracker::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+const MOVIE: &str = "benchmark-movie";
+
+// Inserts an active festival with a single internal movie, skipping the activation flow.
+fn setup_active_festival<T: Config>() -> (T::FestivalId, BoundedVec<u8, T::LinkStringLimit>) {
+	let festival_id = T::FestivalId::default();
+	let owner: T::AccountId = account("owner", 0, 0);
+	let movie_id: BoundedVec<u8, T::LinkStringLimit> = MOVIE.as_bytes().to_vec().try_into().unwrap();
+
+	let festival = crate::Festival {
+		id: festival_id,
+		owner: owner,
+		owner_id: BoundedVec::default(),
+		name: BoundedVec::default(),
+		description: BoundedVec::default(),
+		status: FestivalStatus::Active,
+		max_entry: BalanceOf::<T>::from(0u32),
+		total_lockup: BalanceOf::<T>::from(0u32),
+		categories_and_tags: BoundedVec::default(),
+		internal_movies: vec![movie_id.clone()].try_into().unwrap(),
+		external_movies: BoundedVec::default(),
+		winners: BoundedVec::default(),
+		block_start_end: (BlockNumberFor::<T>::from(0u32), BlockNumberFor::<T>::from(1_000_000u32)),
+		vote_power_decrease_block: BlockNumberFor::<T>::from(1_000_000u32),
+	};
+	Festivals::<T>::insert(festival_id, festival);
+
+	(festival_id, movie_id)
+}
+
+benchmarks! {
+	// `v` is the number of votes already cast in the festival, the cost of
+	// a new vote should not depend on it.
+	vote_for_movie_in_festival {
+		let v in 0 .. T::MaxVotes::get();
+		let (festival_id, movie_id) = setup_active_festival::<T>();
+		let amount = BalanceOf::<T>::from(1_000_000u32);
+
+		for i in 0 .. v {
+			let voter: T::AccountId = account("voter", i, 0);
+			Festival::<T>::do_record_vote(
+				festival_id, voter, BoundedVec::default(),
+				movie_id.clone(), amount, amount,
+			)?;
+		}
+
+		let caller: T::AccountId = whitelisted_caller();
+		<T as kine_stat_tracker::Config>::Currency::make_free_balance_be(
+			&caller, BalanceOf::<T>::from(1_000_000_000u32),
+		);
+	}: _(RawOrigin::Signed(caller.clone()), festival_id, String::from(MOVIE), amount, String::from("user"))
+	verify {
+		assert_eq!(FestivalMovieTallies::<T>::get(festival_id, movie_id).votes, v + 1);
+		assert!(FestivalVoterStakes::<T>::contains_key(festival_id, caller));
+	}
+
+	impl_benchmark_test_suite!(Festival, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -11,6 +11,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+
 #[frame_support::pallet]
 pub mod pallet {
   use frame_support::{
@@ -24,7 +26,6 @@ pub mod pallet {
               KeepAlive,
           },
       },
-      storage::bounded_btree_map::BoundedBTreeMap,
       PalletId
   };
   use frame_system::pallet_prelude::*;
@@ -51,17 +52,40 @@ pub mod pallet {
   use sp_std::{collections::btree_map::BTreeMap,vec};
   use kine_movie;
   use kine_tags;
+  use kine_stat_tracker::{
+      ReputationDomain,
+      ReputationHandler,
+  };
 
   // why does this need to be a crate?
   use crate::pallet::kine_tags::{
       CategoryId as CategoryId,
-      TagId as TagId
+      TagId as TagId,
+      ContentModeration,
+      ContentOwnership,
   };
 
 
 //* Config *//
 
+  const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+  // A festival holds up to MaxMoviesInFest internal and as many external movies,
+  // so a voter can have a stake in twice that many movies.
+  pub struct MaxMoviesStakedInFest<T>(PhantomData<T>);
+
+  impl<T: Config> Get<u32> for MaxMoviesStakedInFest<T> {
+    fn get() -> u32 {
+      T::MaxMoviesInFest::get().saturating_mul(2)
+    }
+  }
+
+  // reputation gained by each voter that backed a winning movie, and by the winning movies' uploaders
+  const WINNING_VOTER_REPUTATION: u32 = 2;
+  const WINNING_UPLOADER_REPUTATION: u32 = 5;
+
   #[pallet::pallet]
+  #[pallet::storage_version(STORAGE_VERSION)]
   pub struct Pallet<T>(_);
 
   #[pallet::config]
@@ -77,6 +101,11 @@ pub mod pallet {
           type MaxVotes: Get<u32>;
           type DescStringLimit: Get<u32>;
           type FestBlockSafetyMargin: Get<u32>;
+          type MaxSponsorsPerFestival: Get<u32>;
+          // share of each festival pool credited to the uploaders of the winning movies
+          type FilmmakerShare: Get<Perbill>;
+          // curation reputation for the winning voters, creation reputation for the winning uploaders
+          type Reputation: ReputationHandler<Self::AccountId>;
           type PalletId: Get<PalletId>;
       }
 
@@ -89,6 +118,17 @@ pub mod pallet {
     Active,
     Finished,
     FinishedNotEnoughVotes,
+    // cancelled after a successful report, everyone who paid into the pool was refunded
+    Cancelled,
+  }
+
+  // Who receives a sponsor's contribution when the festival is resolved.
+  // Split holds the share that goes to the top voters, the rest goes to the filmmakers.
+  #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+  pub enum SponsorshipBeneficiary {
+    TopVoters,
+    Filmmakers,
+    Split(Perbill),
   }
 
 //* Structs *//
@@ -102,7 +142,6 @@ pub mod pallet {
   BoundedDescString,
   FestivalStatus,
   BalanceOf,
-  VoteMap,
   CategoryTagList,
   MoviesInFest,
   BlockStartEnd,
@@ -116,7 +155,6 @@ pub mod pallet {
   pub status: FestivalStatus,
   pub max_entry: BalanceOf,
   pub total_lockup: BalanceOf,
-  pub vote_map: VoteMap,
   pub categories_and_tags: CategoryTagList,
   pub internal_movies: MoviesInFest,
   pub external_movies: MoviesInFest,
@@ -139,6 +177,53 @@ pub mod pallet {
     pub user: BoundedNameString,
   }
 
+  // Running totals of a movie inside a festival, updated on every vote.
+  #[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+  pub struct MovieTally<Balance> {
+    pub total_amount: Balance,
+    pub total_power: Balance,
+    pub votes: u32,
+  }
+
+  #[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+  pub struct MovieStake<BoundedMovieId, Balance> {
+    pub movie_id: BoundedMovieId,
+    pub amount: Balance,
+    pub amount_after_weight: Balance,
+    pub votes: u32,
+  }
+
+  // Everything a wallet staked in a festival, grouped by movie.
+  #[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+  pub struct VoterStake<BoundedNameString, Balance, MovieStakes> {
+    pub user: BoundedNameString,
+    pub total_amount: Balance,
+    pub stakes: MovieStakes,
+  }
+
+  #[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+  pub struct Sponsorship<AccountId, Balance, BoundedNameString, Hash> {
+    pub sponsor: AccountId,
+    pub amount: Balance,
+    pub label: Option<BoundedNameString>,
+    pub logo_hash: Option<Hash>,
+    pub beneficiary: SponsorshipBeneficiary,
+  }
+
+  // Ticketing for a festival's audience. The pool share of each sale is added
+  // to the festival's total_lockup, the rest goes straight to the organiser.
+  #[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+  pub struct PassSale<Balance, BlockNumber> {
+    pub price: Balance,
+    pub supply: u32,
+    pub sold: u32,
+    pub sale_start: BlockNumber,
+    pub sale_end: BlockNumber,
+    pub pool_share: Perbill,
+    pub pool_proceeds: Balance,
+    pub holders_only_voting: bool,
+  }
+
   #[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
   pub struct WalletData<BoundedFestivals> {
       pub all_owned_festivals: BoundedFestivals,
@@ -175,11 +260,6 @@ pub mod pallet {
           BoundedVec<u8, T::DescStringLimit>, //TODO-5
           FestivalStatus,
           BalanceOf<T>, //BalanceOf
-          BoundedBTreeMap<
-              BoundedVec<u8, T::LinkStringLimit>, 
-              BoundedVec<Vote<T::AccountId, BalanceOf<T>, BoundedVec<u8, T::NameStringLimit>>, T::MaxVotes>,
-              T::MaxVotes,
-          >, //VoteMap
           BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>, //CategoryTagList
           BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInFest>, //MoviesInFest
           (BlockNumberFor<T>, BlockNumberFor<T>),
@@ -189,6 +269,44 @@ pub mod pallet {
     >;
 
 
+    // Vote totals of each movie in a festival.
+    #[pallet::storage]
+    #[pallet::getter(fn get_festival_movie_tally)]
+    pub type FestivalMovieTallies<T: Config> = 
+      StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::FestivalId,
+        Blake2_128Concat, BoundedVec<u8, T::LinkStringLimit>,
+        MovieTally<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    // Stakes of each voter in a festival.
+    #[pallet::storage]
+    #[pallet::getter(fn get_festival_voter_stake)]
+    pub type FestivalVoterStakes<T: Config> = 
+      StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::FestivalId,
+        Blake2_128Concat, T::AccountId,
+        VoterStake<
+          BoundedVec<u8, T::NameStringLimit>,
+          BalanceOf<T>,
+          BoundedVec<MovieStake<BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>>, MaxMoviesStakedInFest<T>>,
+        >,
+    >;
+
+    // Number of voters with a stake in each festival, to charge its resolution per voter.
+    #[pallet::storage]
+    #[pallet::getter(fn get_festival_voter_count)]
+    pub type FestivalVoterCount<T: Config> = 
+      StorageMap<
+        _,
+        Blake2_128Concat, T::FestivalId,
+        u32,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn get_block_assignments)]
     pub(super) type BlockAssignments<T: Config> = 
@@ -198,6 +316,17 @@ pub mod pallet {
         BlockAssignment<BoundedVec<T::FestivalId, T::MaxFestivalsPerBlock>>,
     >;
 
+    // Blocks whose festivals were due to start or end while the storage was being
+    // migrated. They are handled one block at a time once the migration is done.
+    #[pallet::storage]
+    #[pallet::getter(fn get_postponed_assignment)]
+    pub type PostponedAssignments<T: Config> = 
+    StorageMap<
+        _,
+        Blake2_128Concat, BlockNumberFor<T>,
+        (),
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn get_wallet_festival_data)]
     pub(super) type WalletFestivalData<T: Config> = 
@@ -207,6 +336,40 @@ pub mod pallet {
         WalletData<BoundedVec<T::FestivalId, T::MaxOwnedFestivals>>,
     >;
 
+    // Non-refundable prize money added by sponsors, kept apart from the voters' lockup.
+    #[pallet::storage]
+    #[pallet::getter(fn get_festival_sponsorships)]
+    pub type FestivalSponsorships<T: Config> = 
+      StorageMap<
+        _,
+        Blake2_128Concat, T::FestivalId,
+        BoundedVec<
+          Sponsorship<T::AccountId, BalanceOf<T>, BoundedVec<u8, T::NameStringLimit>, T::Hash>,
+          T::MaxSponsorsPerFestival,
+        >,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_festival_pass_sale)]
+    pub type FestivalPassSales<T: Config> = 
+      StorageMap<
+        _,
+        Blake2_128Concat, T::FestivalId,
+        PassSale<BalanceOf<T>, BlockNumberFor<T>>,
+    >;
+
+    // Pass holders of each festival, mapped to the block the pass was bought in.
+    #[pallet::storage]
+    #[pallet::getter(fn get_festival_pass)]
+    pub type FestivalPasses<T: Config> = 
+      StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::FestivalId,
+        Blake2_128Concat, T::AccountId,
+        BlockNumberFor<T>,
+    >;
+
   #[pallet::event]
   #[pallet::generate_deposit(pub(super) fn deposit_event)]
   pub enum Event<T: Config> {
@@ -222,6 +385,14 @@ pub mod pallet {
       FestivalTokensClaimed(T::AccountId, BalanceOf<T>),
       FestivalDetailsUpdated(T::FestivalId, T::AccountId, Vec<u8>, Vec<u8>),
       FestivalRemoved(T::FestivalId, T::AccountId),
+      FestivalSponsored(T::FestivalId, T::AccountId, BalanceOf<T>),
+      SponsorshipsDistributed(T::FestivalId, BalanceOf<T>),
+      SponsorshipsRefunded(T::FestivalId, BalanceOf<T>),
+      FilmmakerRewarded(T::FestivalId, T::AccountId, BalanceOf<T>),
+      FestivalPassSaleConfigured(T::FestivalId, BalanceOf<T>, u32),
+      FestivalPassBought(T::FestivalId, T::AccountId, BalanceOf<T>),
+      FestivalCancelled(T::FestivalId),
+      VoterStakesRefunded(T::FestivalId, BalanceOf<T>),
   }
 
   #[pallet::error]
@@ -253,13 +424,69 @@ pub mod pallet {
     VoteValueCannotBeZero,
     InvalidBlockPeriod,
     NoClaimableTokens,
+    SponsorshipValueCannotBeZero,
+    FestivalNotAcceptingSponsors,
+    MaxSponsorsReached,
+    PassSaleNotConfigurable,
+    PassSupplyCannotBeZero,
+    InvalidSaleWindow,
+    NoPassSale,
+    PassSaleNotOpen,
+    PassesSoldOut,
+    PassAlreadyOwned,
+    PassRequiredToVote,
+    FestivalHasPassHolders,
+    MovieSuspended,
+    FestivalAlreadyEnded,
+    CancelledFestivalCannotBeReinstated,
   }
 
 //** Hooks **//
 
   #[pallet::hooks]
   impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+    // Reserves the weight of on_finalize: starting and resolving the festivals of this block
+    // and of the postponed block it catches up on, charged per voter of each festival.
+    fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+      // nothing else runs until the storage is migrated
+      if crate::migrations::is_migrating::<T>() {
+        return crate::migrations::migrate_page::<T>()
+      }
+
+      let mut weight = T::DbWeight::get().reads(1);
+      let mut blocks = vec![now];
+      if let Some(postponed_block) = PostponedAssignments::<T>::iter_keys().next() {
+        blocks.push(postponed_block);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+      }
+
+      for block in blocks {
+        weight = weight.saturating_add(T::DbWeight::get().reads(1));
+        if let Some(festivals) = BlockAssignments::<T>::get(block) {
+          for festival_id in festivals.to_end.iter() {
+            let voters = FestivalVoterCount::<T>::get(festival_id);
+            weight = weight.saturating_add(Self::do_get_resolution_weight(voters));
+          }
+          let to_start: u64 = festivals.to_start.len() as u64;
+          weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2).saturating_mul(to_start));
+        }
+      }
+      weight
+    }
+
     fn on_finalize(now: BlockNumberFor<T>){
+      if crate::migrations::is_migrating::<T>() {
+        if BlockAssignments::<T>::contains_key(now) {
+          PostponedAssignments::<T>::insert(now, ());
+        }
+        return
+      }
+
+      if let Some(postponed_block) = PostponedAssignments::<T>::iter_keys().next() {
+        PostponedAssignments::<T>::remove(postponed_block);
+        let _ = Self::hook_deactivate_festival(postponed_block);
+        let _ = Self::hook_activate_festival(postponed_block);
+      }
       Self::hook_deactivate_festival(now);
       Self::hook_activate_festival(now);
     }
@@ -529,7 +756,7 @@ pub mod pallet {
             = TryInto::try_into(Vec::new()).unwrap();
         for internal_movie in internal_movie_ids {
             temp_name = TryInto::try_into(internal_movie.as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
-            ensure!(!fes.vote_map.contains_key(&temp_name), Error::<T>::CannotRemoveFestivalWithVotes);
+            ensure!(!FestivalMovieTallies::<T>::contains_key(festival_id, &temp_name), Error::<T>::CannotRemoveFestivalWithVotes);
             validated_internal_movie_ids.try_push(temp_name);
         }
 
@@ -537,7 +764,7 @@ pub mod pallet {
             = TryInto::try_into(Vec::new()).unwrap();
         for external_movie in external_movie_ids {
             temp_name = TryInto::try_into(external_movie.as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
-            ensure!(!fes.vote_map.contains_key(&temp_name), Error::<T>::CannotRemoveFestivalWithVotes);
+            ensure!(!FestivalMovieTallies::<T>::contains_key(festival_id, &temp_name), Error::<T>::CannotRemoveFestivalWithVotes);
             validated_external_movie_ids.try_push(temp_name);
         }
 
@@ -561,7 +788,7 @@ pub mod pallet {
     }
 
     // Cast a vote for a movie included in the festival.
-    #[pallet::call_index(5)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+    #[pallet::call_index(5)]#[pallet::weight(Weight::from_parts(10_000, 0) + Self::do_get_vote_weight())]
     pub fn vote_for_movie_in_festival(
         origin: OriginFor<T>,
         festival_id: T::FestivalId,
@@ -663,10 +890,16 @@ pub mod pallet {
       );
 
       ensure!(
-        festival.vote_map.is_empty(),
+        !Self::do_festival_has_votes(festival_id),
         Error::<T>::FestivalHaveVote
       );
 
+      // the organiser's share of sold passes was already paid out
+      ensure!(
+        FestivalPassSales::<T>::get(festival_id).map_or(true, |sale| sale.sold == 0),
+        Error::<T>::FestivalHasPassHolders
+      );
+
       *festival_option = None;
 
       Self::deposit_event(Event::FestivalRemoved(festival_id, who));
@@ -674,8 +907,176 @@ pub mod pallet {
       Ok(().into())
     })?;
 
+    Self::do_refund_sponsorships(festival_id)?;
+    FestivalPassSales::<T>::remove(festival_id);
+
     Ok(().into())
   }
+
+    // Add non-refundable prize money to a festival's pool. Sponsorships are paid out
+    // separately from the voters' lockup, according to the chosen beneficiary.
+    #[pallet::call_index(9)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2,2))]
+    pub fn sponsor_festival(
+        origin: OriginFor<T>,
+        festival_id: T::FestivalId,
+        amount: BalanceOf<T>,
+        beneficiary: SponsorshipBeneficiary,
+        label: Option<String>,
+        logo_hash: Option<T::Hash>,
+    ) -> DispatchResultWithPostInfo {
+
+      let who = ensure_signed(origin)?;
+      ensure!(amount > BalanceOf::<T>::from(0u32), Error::<T>::SponsorshipValueCannotBeZero);
+
+      let festival = Festivals::<T>::get(festival_id).ok_or(Error::<T>::NonexistentFestival)?;
+      ensure!(
+        festival.status == FestivalStatus::AwaitingActivation
+        || festival.status == FestivalStatus::AwaitingStartBlock
+        || festival.status == FestivalStatus::Active,
+        Error::<T>::FestivalNotAcceptingSponsors
+      );
+
+      let bounded_label = match label {
+        Some(label_str) => Some(
+          TryInto::<BoundedVec<u8, T::NameStringLimit>>::try_into(label_str.as_bytes().to_vec())
+          .map_err(|_| Error::<T>::BadMetadata)?
+        ),
+        None => None,
+      };
+
+      let sponsorship = Sponsorship {
+        sponsor: who.clone(),
+        amount: amount,
+        label: bounded_label,
+        logo_hash: logo_hash,
+        beneficiary: beneficiary,
+      };
+
+      FestivalSponsorships::<T>::try_mutate(festival_id, |sponsorships| -> DispatchResult {
+        sponsorships.try_push(sponsorship).map_err(|_| Error::<T>::MaxSponsorsReached)?;
+        Ok(())
+      })?;
+
+      <T as kine_stat_tracker::Config>::Currency::transfer(
+        &who, &Self::account_id(),
+        amount, KeepAlive,
+      )?;
+
+      Self::deposit_event(Event::FestivalSponsored(festival_id, who, amount));
+      Ok(().into())
+    }
+
+    // Set up (or replace) the pass sale of a festival. Only possible before the festival
+    // starts and while no passes were sold.
+    #[pallet::call_index(10)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2,1))]
+    pub fn configure_festival_passes(
+        origin: OriginFor<T>,
+        festival_id: T::FestivalId,
+        price: BalanceOf<T>,
+        supply: u32,
+        sale_start: BlockNumberFor<T>,
+        sale_end: BlockNumberFor<T>,
+        pool_share: Perbill,
+        holders_only_voting: bool,
+    ) -> DispatchResultWithPostInfo {
+
+      let who = ensure_signed(origin)?;
+      ensure!(supply > 0, Error::<T>::PassSupplyCannotBeZero);
+      ensure!(sale_start < sale_end, Error::<T>::InvalidSaleWindow);
+
+      let festival = Festivals::<T>::get(festival_id).ok_or(Error::<T>::NonexistentFestival)?;
+      ensure!(festival.owner == who, Error::<T>::NoFestivalAdminAccess);
+      ensure!(
+        festival.status == FestivalStatus::AwaitingActivation
+        || festival.status == FestivalStatus::AwaitingStartBlock,
+        Error::<T>::PassSaleNotConfigurable
+      );
+      ensure!(
+        FestivalPassSales::<T>::get(festival_id).map_or(true, |sale| sale.sold == 0),
+        Error::<T>::PassSaleNotConfigurable
+      );
+
+      FestivalPassSales::<T>::insert(festival_id, PassSale {
+        price: price,
+        supply: supply,
+        sold: 0,
+        sale_start: sale_start,
+        sale_end: sale_end,
+        pool_share: pool_share,
+        pool_proceeds: BalanceOf::<T>::from(0u32),
+        holders_only_voting: holders_only_voting,
+      });
+
+      Self::deposit_event(Event::FestivalPassSaleConfigured(festival_id, price, supply));
+      Ok(().into())
+    }
+
+    #[pallet::call_index(11)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3,3))]
+    pub fn buy_festival_pass(
+        origin: OriginFor<T>,
+        festival_id: T::FestivalId,
+    ) -> DispatchResultWithPostInfo {
+
+      let who = ensure_signed(origin)?;
+      ensure!(
+        !FestivalPasses::<T>::contains_key(festival_id, who.clone()),
+        Error::<T>::PassAlreadyOwned
+      );
+
+      let now = frame_system::Pallet::<T>::block_number();
+      let (price, pool_amount) = FestivalPassSales::<T>::try_mutate(festival_id, 
+      |pass_sale| -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        let sale = pass_sale.as_mut().ok_or(Error::<T>::NoPassSale)?;
+        ensure!(
+          sale.sale_start <= now && now <= sale.sale_end,
+          Error::<T>::PassSaleNotOpen
+        );
+        ensure!(sale.sold < sale.supply, Error::<T>::PassesSoldOut);
+
+        let pool_amount = sale.pool_share.mul_floor(sale.price);
+        sale.sold = sale.sold.checked_add(1).ok_or(Error::<T>::Overflow)?;
+        sale.pool_proceeds = 
+            sale.pool_proceeds
+            .checked_add(&pool_amount)
+            .ok_or(Error::<T>::Overflow)?;
+        Ok((sale.price, pool_amount))
+      })?;
+
+      let organiser = Festivals::<T>::try_mutate(festival_id, 
+      |festival| -> Result<T::AccountId, DispatchError> {
+        let fest = festival.as_mut().ok_or(Error::<T>::NonexistentFestival)?;
+        ensure!(
+          fest.status != FestivalStatus::Finished
+          && fest.status != FestivalStatus::FinishedNotEnoughVotes
+          && fest.status != FestivalStatus::Cancelled,
+          Error::<T>::PassSaleNotOpen
+        );
+        fest.total_lockup = 
+            fest.total_lockup
+            .checked_add(&pool_amount)
+            .ok_or(Error::<T>::Overflow)?;
+        Ok(fest.owner.clone())
+      })?;
+
+      // split the payment between the prize pool and the organiser
+      let organiser_amount = 
+          price
+          .checked_sub(&pool_amount)
+          .ok_or(Error::<T>::Underflow)?;
+      <T as kine_stat_tracker::Config>::Currency::transfer(
+        &who, &Self::account_id(),
+        pool_amount, KeepAlive,
+      )?;
+      <T as kine_stat_tracker::Config>::Currency::transfer(
+        &who, &organiser,
+        organiser_amount, KeepAlive,
+      )?;
+
+      FestivalPasses::<T>::insert(festival_id, who.clone(), now);
+
+      Self::deposit_event(Event::FestivalPassBought(festival_id, who, price));
+      Ok(().into())
+    }
   }
 
   impl<T: Config> Pallet<T> {
@@ -708,12 +1109,6 @@ pub mod pallet {
       let bounded_film_list: BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInFest> =
           TryInto::try_into(Vec::new()).map_err(|_| Error::<T>::BadMetadata)?;
   
-      let bounded_vote_map: BoundedBTreeMap<
-          BoundedVec<u8, T::LinkStringLimit>,
-          BoundedVec<Vote<T::AccountId, BalanceOf<T>, BoundedVec<u8, T::NameStringLimit>>, T::MaxVotes>,
-          T::MaxVotes,
-      > = BoundedBTreeMap::new();
-  
       let zero_lockup = BalanceOf::<T>::from(0u32);
   
       let mut festival = Festival {
@@ -728,7 +1123,6 @@ pub mod pallet {
           status,
           max_entry: min_ticket_price,
           total_lockup: zero_lockup,
-          vote_map: bounded_vote_map,
           categories_and_tags: category_tag_list,
           block_start_end: (BlockNumberFor::<T>::from(0u32), BlockNumberFor::<T>::from(0u32)),
           vote_power_decrease_block: BlockNumberFor::<T>::from(0u32),
@@ -912,7 +1306,7 @@ pub mod pallet {
             // update the festival ownership status
             Self::do_active_to_finished_fest_ownership(fest.owner.clone(), festival_id.clone());
             
-            if fest.vote_map.values().len() > 0 {
+            if Self::do_festival_has_eligible_votes(festival_id.clone()) {
               fest.status = FestivalStatus::Finished;
               let (winning_voters, winning_movies) = Self::do_resolve_market(festival_id.clone())?;
               for movie_id in winning_movies.clone() {
@@ -922,6 +1316,10 @@ pub mod pallet {
             }
             else {
               fest.status = FestivalStatus::FinishedNotEnoughVotes;
+              // only votes for suspended movies may be left, those are returned
+              Self::do_refund_voter_stakes(festival_id.clone())?;
+              Self::do_refund_sponsorships(festival_id.clone())?;
+              Self::do_release_pass_pool(festival_id.clone(), fest.owner.clone())?;
               Self::deposit_event(Event::FestivalHasEndedUnsuccessfully(festival_id.clone()));
             }
 
@@ -977,9 +1375,16 @@ pub mod pallet {
             || fest.external_movies.contains(&movie_id.clone())),
             Error::<T>::MovieNotInFestival
         );
+        ensure!(!kine_movie::Pallet::<T>::is_movie_suspended(&movie_id), Error::<T>::MovieSuspended);
         // ensure!(fest.owner != who.clone(), Error::<T>::CannotVoteInOwnFestival);
         ensure!(fest.status == FestivalStatus::Active, Error::<T>::FestivalNotActive);
         ensure!(vote_amount >  BalanceOf::<T>::from(0u32), Error::<T>::VoteValueCannotBeZero);
+        if FestivalPassSales::<T>::get(festival_id).map_or(false, |sale| sale.holders_only_voting) {
+            ensure!(
+                FestivalPasses::<T>::contains_key(festival_id, who.clone()),
+                Error::<T>::PassRequiredToVote
+            );
+        }
         if fest.max_entry > BalanceOf::<T>::from(0u32) {
             ensure!(vote_amount <= fest.max_entry, Error::<T>::VoteValueTooHigh);
         }
@@ -1021,7 +1426,7 @@ pub mod pallet {
         <T as kine_stat_tracker::Config>::Currency::transfer(
             who, &Self::account_id(),
             vote_amount, AllowDeath,
-        );
+        )?;
         kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
             who.clone(), 
             kine_stat_tracker::FeatureType::Festival,
@@ -1029,24 +1434,8 @@ pub mod pallet {
             vote_amount.clone(), false
         ).unwrap();
         
-        let vote = Vote {
-            voter: who.clone(),
-            amount: vote_amount,
-            amount_after_weight: vote_weight,
-            user: user
-        };
-
         fest.total_lockup = fest.total_lockup.checked_add(&vote_amount).ok_or(Error::<T>::Overflow)?;
-        
-        if fest.vote_map.contains_key(&movie_id.clone()) {
-            fest.vote_map.get_mut(&movie_id.clone()).unwrap().try_push(vote).unwrap();
-        }
-        else {
-            let mut bounded_vote_list : BoundedVec<Vote<T::AccountId, BalanceOf<T>,  BoundedVec<u8, T::NameStringLimit>>, T::MaxVotes>
-                = TryInto::try_into(Vec::new()).map_err(|_|Error::<T>::BadMetadata)?;
-            bounded_vote_list.try_push(vote).unwrap();
-            fest.vote_map.try_insert(movie_id.clone(), bounded_vote_list).unwrap();
-        }
+        Self::do_record_vote(festival_id, who.clone(), user, movie_id, vote_amount, vote_weight)?;
 
         if !WalletFestivalData::<T>::contains_key(who.clone()) {
             let mut new_data = Self::do_create_new_wallet_data().unwrap();
@@ -1065,6 +1454,103 @@ pub mod pallet {
       })       
     }
 
+    // Adds a vote to the movie's tally and to the voter's stakes. Only touches
+    // the entries of this movie and this voter, regardless of the festival's size.
+    pub fn do_record_vote(
+        festival_id: T::FestivalId,
+        who: T::AccountId,
+        user: BoundedVec<u8, T::NameStringLimit>,
+        movie_id: BoundedVec<u8, T::LinkStringLimit>,
+        amount: BalanceOf<T>,
+        amount_after_weight: BalanceOf<T>,
+    ) -> DispatchResult {
+
+      FestivalMovieTallies::<T>::try_mutate(festival_id, movie_id.clone(), |tally| -> DispatchResult {
+        tally.total_amount = tally.total_amount.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+        tally.total_power = tally.total_power.checked_add(&amount_after_weight).ok_or(Error::<T>::Overflow)?;
+        tally.votes = tally.votes.checked_add(1).ok_or(Error::<T>::Overflow)?;
+        Ok(())
+      })?;
+
+      FestivalVoterStakes::<T>::try_mutate(festival_id, who, |voter_stake| -> DispatchResult {
+        if voter_stake.is_none() {
+          FestivalVoterCount::<T>::try_mutate(festival_id, |voters| -> DispatchResult {
+            *voters = voters.checked_add(1).ok_or(Error::<T>::Overflow)?;
+            Ok(())
+          })?;
+          *voter_stake = Some(VoterStake {
+            user: user,
+            total_amount: BalanceOf::<T>::from(0u32),
+            stakes: BoundedVec::default(),
+          });
+        }
+        let stake = voter_stake.as_mut().ok_or(Error::<T>::BadMetadata)?;
+        stake.total_amount = stake.total_amount.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+
+        match stake.stakes.iter_mut().find(|movie_stake| movie_stake.movie_id == movie_id) {
+          Some(movie_stake) => {
+            movie_stake.amount = movie_stake.amount.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+            movie_stake.amount_after_weight = 
+                movie_stake.amount_after_weight
+                .checked_add(&amount_after_weight)
+                .ok_or(Error::<T>::Overflow)?;
+            movie_stake.votes = movie_stake.votes.checked_add(1).ok_or(Error::<T>::Overflow)?;
+          },
+          None => {
+            stake.stakes.try_push(MovieStake {
+              movie_id: movie_id,
+              amount: amount,
+              amount_after_weight: amount_after_weight,
+              votes: 1,
+            }).map_err(|_| Error::<T>::Overflow)?;
+          },
+        }
+        Ok(())
+      })
+    }
+
+    // Weight of a single vote. The festival, the movie's status, the pass sale and pass, both
+    // balances of the transfer, the voter's tokens and festival data, and the movie's tally,
+    // the voter's stake and the festival's voter count kept by `do_record_vote`.
+    pub fn do_get_vote_weight() -> Weight {
+      T::DbWeight::get().reads_writes(11, 8)
+    }
+
+    pub fn do_festival_has_votes(
+        festival_id: T::FestivalId,
+    ) -> bool {
+      FestivalMovieTallies::<T>::iter_prefix(festival_id).next().is_some()
+    }
+
+    // Worst case weight of resolving or refunding a festival with this many voters. Each voter's
+    // stake is read, paid or refunded and cleared, credited its share of every sponsorship and may win
+    // reputation. Each movie's tally is read, and its uploader may be credited and win reputation.
+    pub fn do_get_resolution_weight(
+        voters: u32,
+    ) -> Weight {
+      let db = T::DbWeight::get();
+      let sponsorships: u64 = T::MaxSponsorsPerFestival::get().into();
+      let movies: u64 = T::MaxMoviesInFest::get().into();
+
+      let per_voter = db.reads_writes(sponsorships.saturating_add(4), sponsorships.saturating_add(4))
+        .saturating_add(T::Reputation::update_weight());
+      let per_movie = db.reads_writes(5, 2)
+        .saturating_add(T::Reputation::update_weight());
+
+      // the festival, its owner's data and tokens, the voter count, the pass sale and the sponsorships
+      db.reads_writes(sponsorships.saturating_mul(2).saturating_add(7), sponsorships.saturating_mul(2).saturating_add(5))
+        .saturating_add(per_voter.saturating_mul(voters.into()))
+        .saturating_add(per_movie.saturating_mul(movies))
+    }
+
+    // Votes for suspended movies don't count towards the festival's result.
+    pub fn do_festival_has_eligible_votes(
+        festival_id: T::FestivalId,
+    ) -> bool {
+      FestivalMovieTallies::<T>::iter_prefix(festival_id)
+        .any(|(movie_id, _)| !kine_movie::Pallet::<T>::is_movie_suspended(&movie_id))
+    }
+
     fn account_id() -> T::AccountId {
         <T as Config>::PalletId::get().try_into_account().unwrap()
     }
@@ -1083,6 +1569,7 @@ pub mod pallet {
       for internal_movie in internal_movie_ids {
           bounded_movie_name = TryInto::try_into(internal_movie.as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
           kine_movie::Pallet::<T>::do_ensure_internal_movie_exist(bounded_movie_name.clone())?;
+          ensure!(!kine_movie::Pallet::<T>::is_movie_suspended(&bounded_movie_name), Error::<T>::MovieSuspended);
           validated_internal_movie_ids.try_push(bounded_movie_name);
       }
 
@@ -1093,6 +1580,7 @@ pub mod pallet {
       for external_movie in external_movie_ids {
           bounded_movie_name = TryInto::try_into(external_movie.as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
           kine_movie::Pallet::<T>::do_ensure_external_movie_exists(bounded_movie_name.clone())?;
+          ensure!(!kine_movie::Pallet::<T>::is_movie_suspended(&bounded_movie_name), Error::<T>::MovieSuspended);
           validated_external_movie_ids.try_push(bounded_movie_name);
       }
       
@@ -1122,6 +1610,7 @@ pub mod pallet {
             bounded_movie_name = TryInto::try_into(internal_movie.as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
             // kine_movie::Pallet::<T>::do_ensure_internal_movie_exist(bounded_movie_name.clone())?;
             ensure!(!festival.internal_movies.contains(&bounded_movie_name), Error::<T>::NoFestivalAdminAccess);
+            ensure!(!kine_movie::Pallet::<T>::is_movie_suspended(&bounded_movie_name), Error::<T>::MovieSuspended);
             validated_internal_movie_ids.try_push(bounded_movie_name);
         }
 
@@ -1129,6 +1618,7 @@ pub mod pallet {
         for external_movie in external_movie_ids {
             bounded_movie_name = TryInto::try_into(external_movie.as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
             // kine_movie::Pallet::<T>::do_ensure_external_movie_exists(bounded_movie_name.clone())?;
+            ensure!(!kine_movie::Pallet::<T>::is_movie_suspended(&bounded_movie_name), Error::<T>::MovieSuspended);
             validated_external_movie_ids.try_push(bounded_movie_name);
         }
         Ok((validated_internal_movie_ids, validated_external_movie_ids))
@@ -1151,20 +1641,37 @@ pub mod pallet {
               
           // Get the winning movie_ids by vote power
           let festival_winners = Self::do_get_winning_options(festival_id).unwrap();
-          // Self::do_assign_wins_to_uploaders(festival_id, festival_winners).unwrap();
           
           let (owner_reward, total_lockup_after_owner) = Self::do_calculate_owner_reward(festival.owner.clone(), festival.total_lockup).unwrap();
+          let (filmmaker_reward, total_lockup_after_filmmakers) = Self::do_calculate_filmmaker_reward(festival.total_lockup, total_lockup_after_owner)?;
+
+          // credit the filmmakers' share and the win to the uploaders of the winning movies
+          let filmmaker_leftover = Self::do_assign_wins_to_uploaders(festival_id, festival_winners.clone(), filmmaker_reward)?;
           
           // get the winning voter's lockup and each of their respective winning vote lockup and the total winning votes
           let (winning_voters_lockup, winning_vote_map) = Self::do_get_winners_total_lockup(festival_id, festival_winners.clone()).unwrap();
           
-          let remaining_lockup = Self::do_calculate_voters_reward(total_lockup_after_owner, winning_voters_lockup, winning_vote_map.clone()).unwrap();
+          let mut remaining_lockup = Self::do_calculate_voters_reward(total_lockup_after_filmmakers, winning_voters_lockup, winning_vote_map.clone()).unwrap();
+          remaining_lockup = 
+              remaining_lockup
+              .checked_add(&filmmaker_leftover)
+              .ok_or(Error::<T>::Overflow)?;
 
           Self::do_handle_owner_reward( festival.owner.clone(), owner_reward, remaining_lockup).unwrap();
 
+          // sponsorships are split on their own, without touching the voters' lockup
+          Self::do_distribute_sponsorships(
+            festival_id, festival.owner.clone(), 
+            festival_winners.clone(), winning_voters_lockup, winning_vote_map.clone(),
+          )?;
+
           Ok((winning_vote_map.into_keys().collect(), festival_winners.clone()))
       })?;
 
+      for voter in winning_voters.iter() {
+          T::Reputation::update_reputation(voter, ReputationDomain::Curation, WINNING_VOTER_REPUTATION, false);
+      }
+
       Ok((winning_voters, winning_movies))
     }
 
@@ -1174,13 +1681,13 @@ pub mod pallet {
     
       let mut vote_power_map = BTreeMap::new(); // map of movie_id: total_voting_power
 
-      // iterate the festival's votes and calculate the total voting power of each
-      let fes_votes = Festivals::<T>::try_get(festival_id).unwrap().vote_map;
-      for (movie_id, vote_list) in fes_votes {
-        for vote in vote_list {
-            let movie_power =  vote_power_map.entry(movie_id.clone()).or_insert(BalanceOf::<T>::from(0u32));
-            *movie_power += vote.amount_after_weight;
+      // the total voting power of each movie is kept up to date on every vote
+      // suspended movies can't win, their votes are lost like any other losing vote
+      for (movie_id, tally) in FestivalMovieTallies::<T>::iter_prefix(festival_id) {
+        if kine_movie::Pallet::<T>::is_movie_suspended(&movie_id) {
+          continue;
         }
+        vote_power_map.insert(movie_id, tally.total_power);
       }
 
       // determine the first winner and add it to a list
@@ -1188,7 +1695,7 @@ pub mod pallet {
           .iter()
           // .clone()
           .max_by_key(|p| p.1)
-          .unwrap();
+          .ok_or(Error::<T>::NonexistentMovie)?;
       let mut winners = vec![first_winner.0.clone()];
       
       // untie by adding all entries with the same lockup to the winner list
@@ -1213,59 +1720,92 @@ pub mod pallet {
       let mut winners_total_lockup = BalanceOf::<T>::from(0u32);
       let mut winning_vote_map = BTreeMap::new(); // map of voter_id: total_correct_tokens_voted
 
-      let fes_votes = Festivals::<T>::try_get(festival_id).unwrap().vote_map; // movie_id: vote_list
-      for movie_id in winning_movies {
-          
-        let winning_votes = fes_votes.get(&movie_id.clone()).unwrap();
-        for vote in winning_votes {
+      for (voter, voter_stake) in FestivalVoterStakes::<T>::iter_prefix(festival_id) {
+        for movie_stake in voter_stake.stakes.iter().filter(|stake| winning_movies.contains(&stake.movie_id)) {
 
           winners_total_lockup = 
               winners_total_lockup
-              .checked_add(&vote.amount.clone())
+              .checked_add(&movie_stake.amount.clone())
               .ok_or(Error::<T>::Overflow)?;
 
           let (token_earning_total, winning_vote_total) =
               winning_vote_map
-              .entry(vote.voter.clone())
+              .entry(voter.clone())
               .or_insert((
                   BalanceOf::<T>::from(0u32),
                   0u32
               ));
 
-          *token_earning_total += vote.amount;
-          *winning_vote_total += 1u32;
+          *token_earning_total += movie_stake.amount;
+          *winning_vote_total += movie_stake.votes;
         }
       }
   
       Ok((winners_total_lockup, winning_vote_map))
     }
 
+    // Splits the filmmakers' share evenly among the winning movies and records the win
+    // for each uploader. Returns whatever could not be assigned (rounding or missing movies).
     fn do_assign_wins_to_uploaders(
         festival_id: T::FestivalId,
-        winners: Vec<BoundedVec<u8, T::LinkStringLimit>>,
-    ) -> DispatchResultWithPostInfo {
+        winners: BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInFest>,
+        filmmaker_reward: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
         
+      let mut remaining_reward = filmmaker_reward;
+      let total_winners = winners.len() as u32;
+      if total_winners == 0 {
+          return Ok(remaining_reward);
+      }
+
+      let reward_per_winner =
+          filmmaker_reward
+          .checked_div(&BalanceOf::<T>::from(total_winners))
+          .ok_or(Error::<T>::Underflow)?;
+
       // verify if movies still exist, and assign the win to the uploader
-      for movie_id in winners.clone() {
-          let uploader = kine_movie::Pallet::<T>
-              ::get_movie_uploader(movie_id)?;
+      for movie_id in winners {
+          let uploader = match kine_movie::Pallet::<T>::get_movie_uploader(movie_id) {
+              Ok(uploader) => uploader,
+              Err(_) => continue,
+          };
+
           // assign wins to the uploaders of the winning movies
           if !WalletFestivalData::<T>::contains_key(uploader.clone()) {
-            let mut new_data = Self::do_create_new_wallet_data().unwrap();
-            new_data.won_festivals.try_push(festival_id).unwrap();
+            let mut new_data = Self::do_create_new_wallet_data()?;
+            new_data.won_festivals.try_push(festival_id).map_err(|_| Error::<T>::Overflow)?;
             WalletFestivalData::<T>::insert(uploader.clone(), new_data);
           }
           else {
             WalletFestivalData::<T>::try_mutate_exists( uploader.clone(), |festival_data| -> DispatchResult{
               let fes_data = festival_data.as_mut().ok_or(Error::<T>::NonexistentFestival)?;
-              fes_data.won_festivals.try_push(festival_id).unwrap();
+              if !fes_data.won_festivals.contains(&festival_id) {
+                fes_data.won_festivals.try_push(festival_id).map_err(|_| Error::<T>::Overflow)?;
+              }
               
               Ok(())
             })?;
           }
+          T::Reputation::update_reputation(&uploader, ReputationDomain::Creation, WINNING_UPLOADER_REPUTATION, false);
+
+          if reward_per_winner > BalanceOf::<T>::from(0u32) {
+            remaining_reward =
+                remaining_reward
+                .checked_sub(&reward_per_winner)
+                .ok_or(Error::<T>::Underflow)?;
+
+            // claimable festival tokens also add up to the uploader's total_tokens_won_festival
+            kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+                uploader.clone(), 
+                kine_stat_tracker::FeatureType::Festival,
+                kine_stat_tracker::TokenType::Claimable,
+                reward_per_winner, false,
+            )?;
+            Self::deposit_event(Event::FilmmakerRewarded(festival_id, uploader, reward_per_winner));
+          }
       }
 
-      Ok(().into())
+      Ok(remaining_reward)
     }
 
     fn do_calculate_voters_reward(
@@ -1319,6 +1859,20 @@ pub mod pallet {
       Ok((owner_reward, remaining_lockup))
     }
 
+    // Returns the filmmakers' share of the whole pool and what is left for the voters.
+    fn do_calculate_filmmaker_reward(
+        total_lockup: BalanceOf<T>,
+        remaining_lockup: BalanceOf<T>,
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+
+      let filmmaker_reward = T::FilmmakerShare::get().mul_floor(total_lockup);
+      let remaining_lockup =
+          remaining_lockup
+          .checked_sub(&filmmaker_reward)
+          .ok_or(Error::<T>::Underflow)?;
+      Ok((filmmaker_reward, remaining_lockup))
+    }
+
     fn do_handle_owner_reward(
         owner_id: T::AccountId,
         owner_share: BalanceOf<T>,
@@ -1340,6 +1894,260 @@ pub mod pallet {
       Ok(().into())
     }
     
+    // Pays out every sponsorship of a resolved festival. The voters' part is split by
+    // each winning voter's share of the winning lockup, the filmmakers' part is split
+    // evenly among the uploaders of the winning movies. Rounding leftovers go to the owner.
+    fn do_distribute_sponsorships(
+        festival_id: T::FestivalId,
+        owner_id: T::AccountId,
+        winning_movies: BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInFest>,
+        winners_lockup: BalanceOf<T>,
+        winning_vote_map: BTreeMap<T::AccountId, (BalanceOf<T>, u32)>,
+    ) -> DispatchResult {
+
+      let sponsorships = FestivalSponsorships::<T>::take(festival_id);
+      if sponsorships.is_empty() {
+        return Ok(());
+      }
+
+      let zero_balance = BalanceOf::<T>::from(0u32);
+      let mut voters_pool = zero_balance;
+      let mut filmmakers_pool = zero_balance;
+      for sponsorship in sponsorships.iter() {
+        let voters_share = match sponsorship.beneficiary {
+          SponsorshipBeneficiary::TopVoters => sponsorship.amount,
+          SponsorshipBeneficiary::Filmmakers => zero_balance,
+          SponsorshipBeneficiary::Split(voters_part) => voters_part.mul_floor(sponsorship.amount),
+        };
+        let filmmakers_share = 
+            sponsorship.amount
+            .checked_sub(&voters_share)
+            .ok_or(Error::<T>::Underflow)?;
+
+        voters_pool = voters_pool.checked_add(&voters_share).ok_or(Error::<T>::Overflow)?;
+        filmmakers_pool = filmmakers_pool.checked_add(&filmmakers_share).ok_or(Error::<T>::Overflow)?;
+      }
+
+      let total_sponsorship = 
+          voters_pool
+          .checked_add(&filmmakers_pool)
+          .ok_or(Error::<T>::Overflow)?;
+      let mut remaining_sponsorship = total_sponsorship;
+
+      // top voters, weighted by their winning lockup
+      if voters_pool > zero_balance && winners_lockup > zero_balance {
+        for (voter, (user_winning_votes_lockup, _)) in winning_vote_map.iter() {
+          let voter_reward = 
+              Perbill::from_rational(*user_winning_votes_lockup, winners_lockup)
+              .mul_floor(voters_pool);
+          remaining_sponsorship = 
+              remaining_sponsorship
+              .checked_sub(&voter_reward)
+              .ok_or(Error::<T>::Underflow)?;
+
+          kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+              voter.clone(), 
+              kine_stat_tracker::FeatureType::Festival,
+              kine_stat_tracker::TokenType::Claimable,
+              voter_reward, false,
+          )?;
+        }
+      }
+
+      // filmmakers of the winning movies, split evenly among ties
+      let total_winners = winning_movies.len() as u32;
+      if filmmakers_pool > zero_balance && total_winners > 0 {
+        let filmmaker_reward = 
+            filmmakers_pool
+            .checked_div(&BalanceOf::<T>::from(total_winners))
+            .ok_or(Error::<T>::Underflow)?;
+
+        for movie_id in winning_movies {
+          if let Ok(uploader) = kine_movie::Pallet::<T>::get_movie_uploader(movie_id) {
+            remaining_sponsorship = 
+                remaining_sponsorship
+                .checked_sub(&filmmaker_reward)
+                .ok_or(Error::<T>::Underflow)?;
+
+            kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+                uploader, 
+                kine_stat_tracker::FeatureType::Festival,
+                kine_stat_tracker::TokenType::Claimable,
+                filmmaker_reward, false,
+            )?;
+          }
+        }
+      }
+
+      if remaining_sponsorship > zero_balance {
+        kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+            owner_id, 
+            kine_stat_tracker::FeatureType::Festival,
+            kine_stat_tracker::TokenType::Claimable,
+            remaining_sponsorship, false,
+        )?;
+      }
+
+      Self::deposit_event(Event::SponsorshipsDistributed(festival_id, total_sponsorship));
+      Ok(())
+    }
+
+    // Returns the sponsorships of a festival that ended without a winner or was removed.
+    fn do_refund_sponsorships(
+        festival_id: T::FestivalId,
+    ) -> DispatchResult {
+
+      let sponsorships = FestivalSponsorships::<T>::take(festival_id);
+      if sponsorships.is_empty() {
+        return Ok(());
+      }
+
+      let mut total_refunded = BalanceOf::<T>::from(0u32);
+      for sponsorship in sponsorships {
+        <T as kine_stat_tracker::Config>::Currency::transfer(
+          &Self::account_id(), &sponsorship.sponsor,
+          sponsorship.amount, AllowDeath,
+        )?;
+        total_refunded = total_refunded.saturating_add(sponsorship.amount);
+      }
+
+      Self::deposit_event(Event::SponsorshipsRefunded(festival_id, total_refunded));
+      Ok(())
+    }
+
+    // Returns every voter's stake in a festival that won't be resolved, then drops the stakes.
+    fn do_refund_voter_stakes(
+        festival_id: T::FestivalId,
+    ) -> DispatchResult {
+
+      let mut total_refunded = BalanceOf::<T>::from(0u32);
+      for (voter, voter_stake) in FestivalVoterStakes::<T>::iter_prefix(festival_id) {
+        <T as kine_stat_tracker::Config>::Currency::transfer(
+          &Self::account_id(), &voter,
+          voter_stake.total_amount, AllowDeath,
+        )?;
+        kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+          voter, 
+          kine_stat_tracker::FeatureType::Festival,
+          kine_stat_tracker::TokenType::Locked,
+          voter_stake.total_amount, true,
+        )?;
+        total_refunded = total_refunded.saturating_add(voter_stake.total_amount);
+      }
+
+      // every voter was counted when they first voted, which bounds the stakes to clear
+      let voters = FestivalVoterCount::<T>::take(festival_id);
+      let _ = FestivalVoterStakes::<T>::clear_prefix(festival_id, voters, None);
+
+      if total_refunded > BalanceOf::<T>::from(0u32) {
+        Self::deposit_event(Event::VoterStakesRefunded(festival_id, total_refunded));
+      }
+      Ok(())
+    }
+
+    // Cancels a festival that hasn't ended yet. Voters and sponsors are refunded,
+    // and pass holders get back the share of their pass that went to the pool.
+    pub fn do_cancel_festival(
+        festival_id: T::FestivalId,
+    ) -> DispatchResult {
+
+      let owner = Festivals::<T>::try_mutate(festival_id, |festival| -> Result<T::AccountId, DispatchError> {
+        let fest = festival.as_mut().ok_or(Error::<T>::NonexistentFestival)?;
+        ensure!(
+          fest.status == FestivalStatus::AwaitingActivation
+          || fest.status == FestivalStatus::AwaitingStartBlock
+          || fest.status == FestivalStatus::Active,
+          Error::<T>::FestivalAlreadyEnded
+        );
+        fest.status = FestivalStatus::Cancelled;
+        fest.total_lockup = BalanceOf::<T>::from(0u32);
+        Ok(fest.owner.clone())
+      })?;
+
+      WalletFestivalData::<T>::try_mutate_exists(owner, |wal_data| -> DispatchResult {
+        if let Some(wallet_data) = wal_data.as_mut() {
+          wallet_data.awaiting_activation_festivals.retain(|fes_id| fes_id != &festival_id);
+          wallet_data.awaiting_start_festivals.retain(|fes_id| fes_id != &festival_id);
+          wallet_data.active_festivals.retain(|fes_id| fes_id != &festival_id);
+          wallet_data.finished_festivals.try_push(festival_id).map_err(|_| Error::<T>::Overflow)?;
+        }
+        Ok(())
+      })?;
+
+      Self::do_refund_voter_stakes(festival_id)?;
+      Self::do_refund_sponsorships(festival_id)?;
+
+      if let Some(sale) = FestivalPassSales::<T>::get(festival_id) {
+        let pool_amount = sale.pool_share.mul_floor(sale.price);
+        if pool_amount > BalanceOf::<T>::from(0u32) {
+          for (holder, _) in FestivalPasses::<T>::iter_prefix(festival_id) {
+            <T as kine_stat_tracker::Config>::Currency::transfer(
+              &Self::account_id(), &holder,
+              pool_amount, AllowDeath,
+            )?;
+          }
+        }
+      }
+
+      Self::deposit_event(Event::FestivalCancelled(festival_id));
+      Ok(())
+    }
+
+    // Without votes there is no market to resolve, so the passes' share
+    // of the pool is handed to the organiser.
+    fn do_release_pass_pool(
+        festival_id: T::FestivalId,
+        owner: T::AccountId,
+    ) -> DispatchResult {
+
+      if let Some(sale) = FestivalPassSales::<T>::get(festival_id) {
+        if sale.pool_proceeds > BalanceOf::<T>::from(0u32) {
+          <T as kine_stat_tracker::Config>::Currency::transfer(
+            &Self::account_id(), &owner,
+            sale.pool_proceeds, AllowDeath,
+          )?;
+        }
+      }
+      Ok(())
+    }
+
+    // Used by the runtime API.
+    pub fn has_valid_pass(
+        festival_id: T::FestivalId,
+        who: T::AccountId,
+    ) -> bool {
+      
+      match Festivals::<T>::get(festival_id) {
+        Some(festival) => 
+          festival.status == FestivalStatus::Active
+          && FestivalPasses::<T>::contains_key(festival_id, who),
+        None => false,
+      }
+    }
+
+    // Used by the runtime API. Pass holders may watch the festival's internal movies
+    // while it is running, the organiser always can.
+    pub fn can_access_movie(
+        festival_id: T::FestivalId,
+        who: T::AccountId,
+        movie_id: Vec<u8>,
+    ) -> bool {
+
+      let festival = match Festivals::<T>::get(festival_id) {
+        Some(festival) => festival,
+        None => return false,
+      };
+      let bounded_movie_id: BoundedVec<u8, T::LinkStringLimit> = match TryInto::try_into(movie_id) {
+        Ok(movie_id) => movie_id,
+        Err(_) => return false,
+      };
+      if !festival.internal_movies.contains(&bounded_movie_id) {
+        return false;
+      }
+
+      festival.owner == who || Self::has_valid_pass(festival_id, who)
+    }
+
     fn do_create_new_wallet_data(
     ) -> Result<WalletData<BoundedVec<T::FestivalId, T::MaxOwnedFestivals>>, DispatchError> {
 
@@ -1363,4 +2171,28 @@ pub mod pallet {
     }
   }
 
+  impl<T: Config> ContentOwnership<T::FestivalId, T::AccountId> for Pallet<T> {
+    fn content_owner(
+        festival_id: &T::FestivalId,
+    ) -> Result<Option<T::AccountId>, DispatchError> {
+      let festival = Festivals::<T>::get(festival_id).ok_or(Error::<T>::NonexistentFestival)?;
+      Ok(Some(festival.owner))
+    }
+  }
+
+  impl<T: Config> ContentModeration<T::FestivalId> for Pallet<T> {
+    fn suspend_content(
+        festival_id: &T::FestivalId,
+    ) -> DispatchResult {
+      Self::do_cancel_festival(*festival_id)
+    }
+
+    // The festival's pool was refunded when it was cancelled, so it can't be resumed.
+    fn reinstate_content(
+        _festival_id: &T::FestivalId,
+    ) -> DispatchResult {
+      Err(Error::<T>::CancelledFestivalCannotBeReinstated.into())
+    }
+  }
+
 }
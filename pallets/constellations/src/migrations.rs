@@ -0,0 +1,216 @@
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::{bounded_btree_map::BoundedBTreeMap, IterableStorageMap},
+	traits::{
+		Currency, ExistenceRequirement::AllowDeath, GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
+	},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use kine_tags::{CategoryId, TagId};
+use sp_runtime::traits::{AccountIdConversion, Saturating};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+type BalanceOf<T> = <<T as kine_stat_tracker::Config>::Currency as frame_support::traits::Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+// Migrations are paged: the runtime upgrade translates the first page and stores a cursor,
+// `on_initialize` then translates a page per block until the cursor is gone. Festivals due
+// to start or end meanwhile are postponed, calls are refused by the runtime.
+pub const MIGRATION_PAGE_SIZE: u32 = 10;
+
+// The raw key of the last translated festival, an empty key when nothing is translated yet.
+#[frame_support::storage_alias]
+pub type MigrationCursor<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+
+// The festivals can't be touched while they, or the wallet tokens they update, are migrated.
+pub fn is_migrating<T: Config>() -> bool {
+	MigrationCursor::<T>::exists() || kine_stat_tracker::migrations::is_migrating::<T>()
+}
+
+pub fn migrate_page<T: Config>() -> Weight {
+	let cursor = match MigrationCursor::<T>::get() {
+		Some(cursor) => cursor,
+		None => return T::DbWeight::get().reads(1),
+	};
+	// refunded votes update wallet tokens, which must be translated first
+	if kine_stat_tracker::migrations::is_migrating::<T>() {
+		return T::DbWeight::get().reads(2)
+	}
+	let previous_key = if cursor.is_empty() { None } else { Some(cursor) };
+
+	let (next_key, reads_writes) = if Pallet::<T>::on_chain_storage_version() == 0 {
+		v1::migrate_page::<T>(previous_key, MIGRATION_PAGE_SIZE)
+	} else {
+		(None, 0)
+	};
+
+	match next_key {
+		Some(key) => MigrationCursor::<T>::put(key),
+		None => {
+			if Pallet::<T>::on_chain_storage_version() == 0 {
+				StorageVersion::new(1).put::<Pallet<T>>();
+			}
+			MigrationCursor::<T>::kill();
+		},
+	}
+	T::DbWeight::get().reads_writes(reads_writes.saturating_add(4), reads_writes.saturating_add(2))
+}
+
+// Moves the festival votes out of `Festival.vote_map` and into
+// `FestivalMovieTallies` / `FestivalVoterStakes`.
+pub mod v1 {
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	pub struct OldFestival<
+		FestivalId,
+		AccountId,
+		BoundedOwnerNameString,
+		BoundedNameString,
+		BoundedDescString,
+		FestivalStatus,
+		BalanceOf,
+		VoteMap,
+		CategoryTagList,
+		MoviesInFest,
+		BlockStartEnd,
+		BlockNumber,
+	> {
+		pub id: FestivalId,
+		pub owner: AccountId,
+		pub owner_id: BoundedOwnerNameString,
+		pub name: BoundedNameString,
+		pub description: BoundedDescString,
+		pub status: FestivalStatus,
+		pub max_entry: BalanceOf,
+		pub total_lockup: BalanceOf,
+		pub vote_map: VoteMap,
+		pub categories_and_tags: CategoryTagList,
+		pub internal_movies: MoviesInFest,
+		pub external_movies: MoviesInFest,
+		pub winners: MoviesInFest,
+		pub block_start_end: BlockStartEnd,
+		pub vote_power_decrease_block: BlockNumber,
+	}
+
+	pub type OldFestivalOf<T> = OldFestival<
+		<T as Config>::FestivalId,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<u8, <T as kine_stat_tracker::Config>::NameStringLimit>,
+		BoundedVec<u8, <T as kine_stat_tracker::Config>::NameStringLimit>,
+		BoundedVec<u8, <T as Config>::DescStringLimit>,
+		FestivalStatus,
+		BalanceOf<T>,
+		BoundedBTreeMap<
+			BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>,
+			BoundedVec<
+				Vote<
+					<T as frame_system::Config>::AccountId,
+					BalanceOf<T>,
+					BoundedVec<u8, <T as kine_stat_tracker::Config>::NameStringLimit>,
+				>,
+				<T as Config>::MaxVotes,
+			>,
+			<T as Config>::MaxVotes,
+		>,
+		BoundedVec<(CategoryId<T>, TagId<T>), <T as kine_tags::Config>::MaxTags>,
+		BoundedVec<BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, <T as Config>::MaxMoviesInFest>,
+		(BlockNumberFor<T>, BlockNumberFor<T>),
+		BlockNumberFor<T>,
+	>;
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 0 || MigrationCursor::<T>::exists() {
+				return T::DbWeight::get().reads(2)
+			}
+
+			MigrationCursor::<T>::put(Vec::<u8>::new());
+			super::migrate_page::<T>()
+		}
+	}
+
+	// Translates up to `limit` festivals after `previous_key`, returning the key to resume
+	// from (`None` once every festival is translated) and the reads and writes made.
+	pub fn migrate_page<T: Config>(mut previous_key: Option<Vec<u8>>, limit: u32) -> (Option<Vec<u8>>, u64) {
+		let vault: T::AccountId = <T as Config>::PalletId::get().into_account_truncating();
+		let mut reads_writes: u64 = 0;
+		let mut translated: u32 = 0;
+		while translated < limit {
+			previous_key = Festivals::<T>::translate_next::<OldFestivalOf<T>, _>(previous_key, |festival_id, old| {
+				reads_writes = reads_writes.saturating_add(1);
+				let mut total_lockup = old.total_lockup;
+
+				for (movie_id, votes) in old.vote_map.into_iter() {
+					for vote in votes.into_iter() {
+						// each vote updates one tally and one voter entry
+						reads_writes = reads_writes.saturating_add(2);
+						let recorded = frame_support::storage::with_storage_layer(|| {
+							Pallet::<T>::do_record_vote(
+								festival_id,
+								vote.voter.clone(),
+								vote.user,
+								movie_id.clone(),
+								vote.amount,
+								vote.amount_after_weight,
+							)
+						});
+						if recorded.is_ok() {
+							continue
+						}
+
+						// votes that no longer fit are returned to the voter
+						reads_writes = reads_writes.saturating_add(2);
+						let refunded = frame_support::storage::with_storage_layer(|| -> DispatchResult {
+							<T as kine_stat_tracker::Config>::Currency::transfer(
+								&vault, &vote.voter,
+								vote.amount, AllowDeath,
+							)?;
+							kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+								vote.voter.clone(),
+								kine_stat_tracker::FeatureType::Festival,
+								kine_stat_tracker::TokenType::Locked,
+								vote.amount, true,
+							)
+						});
+						match refunded {
+							Ok(()) => total_lockup = total_lockup.saturating_sub(vote.amount),
+							Err(e) => frame_support::log::error!(
+								target: "runtime::constellations",
+								"couldn't record nor refund the vote of {:?} in festival {:?}: {:?}",
+								vote.voter, festival_id, e,
+							),
+						}
+					}
+				}
+
+				Some(Festival {
+					id: old.id,
+					owner: old.owner,
+					owner_id: old.owner_id,
+					name: old.name,
+					description: old.description,
+					status: old.status,
+					max_entry: old.max_entry,
+					total_lockup: total_lockup,
+					categories_and_tags: old.categories_and_tags,
+					internal_movies: old.internal_movies,
+					external_movies: old.external_movies,
+					winners: old.winners,
+					block_start_end: old.block_start_end,
+					vote_power_decrease_block: old.vote_power_decrease_block,
+				})
+			});
+			if previous_key.is_none() {
+				break
+			}
+			translated = translated.saturating_add(1);
+		}
+		(previous_key, reads_writes)
+	}
+}
@@ -7,7 +7,7 @@ use frame_support::{
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Perbill, Percent,
 	testing::Header,
 };
 
@@ -78,7 +78,8 @@ impl kine_tags::Config for Test {
 
 // Stat Tracker
 parameter_types! {
-	pub const DefaultReputation: u32 = 15;
+	pub const ReputationDecay: Percent = Percent::from_percent(10);
+	pub const ReputationDecayPeriod: u64 = 100;
 	pub const WalletNameStringLimit: u32 = 50;
 	pub const PalletStatTrackerId : PalletId = PalletId(*b"kine/trk");
 }
@@ -86,9 +87,10 @@ parameter_types! {
 impl kine_stat_tracker::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type DefaultReputation = DefaultReputation;
 	type NameStringLimit = WalletNameStringLimit;
 	type PalletId = PalletStatTrackerId;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ReputationDecayPeriod;
 }
 
 
@@ -104,6 +106,7 @@ impl kine_movie::Config for Test {
     type StringLimit = MovieStringLimit;
     type LinkStringLimit = LinkStringLimit;
     type MovieCollateral = MovieCollateral;
+    type OnMovieTagged = ();
 }
 
 
@@ -137,6 +140,8 @@ parameter_types! {
 	pub const FestBlockSafetyMargin: u32 = 1; // prev 10
 	pub const MaxFestivalsPerBlock: u32 = 500;
 	pub const MaxVotes: u32 = 100000;
+	pub const MaxSponsorsPerFestival: u32 = 100;
+	pub const FestivalFilmmakerShare: Perbill = Perbill::from_percent(10);
 	pub const PalletFestivalId: PalletId = PalletId(*b"FesStash");
 }
 
@@ -144,11 +149,15 @@ impl kine_festival::Config for Test {
   type RuntimeEvent = RuntimeEvent;
 	type FestivalId = u32;
 	type MaxMoviesInFest = MaxMoviesInFest;
+	type DescStringLimit = DescStringLimit;
 	type MaxOwnedFestivals = MaxOwnedFestivals;
 	type MinFesBlockDuration = MinFesBlockDuration;
 	type MaxFestivalsPerBlock = MaxFestivalsPerBlock;
 	type MaxVotes = MaxVotes;
 	type FestBlockSafetyMargin = FestBlockSafetyMargin;
+	type MaxSponsorsPerFestival = MaxSponsorsPerFestival;
+	type FilmmakerShare = FestivalFilmmakerShare;
+	type Reputation = StatTrackerModule;
 	type PalletId = PalletFestivalId;
 }
 
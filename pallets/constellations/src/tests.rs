@@ -1,5 +1,90 @@
-use crate::{Error, mock::*};
-use frame_support::{assert_ok, assert_noop};
+use crate::{
+	BlockAssignment, BlockAssignments, Error, Festival, FestivalMovieTallies, FestivalPassSales,
+	FestivalPasses, FestivalSponsorships, FestivalStatus, FestivalVoterCount, FestivalVoterStakes, Festivals, MovieTally, PostponedAssignments,
+	SponsorshipBeneficiary, Vote, WalletFestivalData, mock::*,
+	migrations::{self, v1::{MigrateToV1, OldFestival, OldFestivalOf}, MIGRATION_PAGE_SIZE},
+};
+use frame_support::{
+	assert_ok, assert_noop,
+	storage::bounded_btree_map::BoundedBTreeMap,
+	traits::{Currency, GetStorageVersion, OnFinalize, OnInitialize, OnRuntimeUpgrade},
+	BoundedVec,
+};
+use kine_movie::ExternalSource;
+use kine_tags::ContentModeration;
+use sp_runtime::{traits::AccountIdConversion, Perbill};
+
+const LINK: &str = "https://youtu.be/kinera";
+const OTHER_LINK: &str = "https://youtu.be/other";
+
+fn link(movie: &str) -> BoundedVec<u8, LinkStringLimit> {
+	BoundedVec::truncate_from(movie.as_bytes().to_vec())
+}
+
+fn vault() -> u64 {
+	PalletFestivalId::get().into_account_truncating()
+}
+
+fn fund(who: u64, amount: u128) {
+	Balances::make_free_balance_be(&who, amount);
+}
+
+fn claimable(who: u64) -> u128 {
+	kine_stat_tracker::WalletTokens::<Test>::get(who).map_or(0, |tokens| tokens.claimable_tokens_festival)
+}
+
+// An active festival from block 1 to 100 showing both links, voting power
+// decreases from block 50 onwards.
+fn setup_festival(festival_id: u32, owner: u64) {
+	insert_festival(festival_id, owner, FestivalStatus::Active);
+	Festivals::<Test>::mutate(festival_id, |festival| {
+		let festival = festival.as_mut().unwrap();
+		festival.external_movies = BoundedVec::truncate_from(vec![link(LINK), link(OTHER_LINK)]);
+		festival.block_start_end = (1, 100);
+		festival.vote_power_decrease_block = 50;
+	});
+	BlockAssignments::<Test>::insert(100, BlockAssignment {
+		to_start: BoundedVec::default(),
+		to_end: BoundedVec::truncate_from(vec![festival_id]),
+	});
+	fund(vault(), ExistentialDeposit::get());
+}
+
+fn vote(who: u64, festival_id: u32, movie: &str, amount: u128) {
+	assert_ok!(FestivalModule::vote_for_movie_in_festival(
+		RuntimeOrigin::signed(who), festival_id, movie.into(), amount, "user".into(),
+	));
+}
+
+// Opens a sale of 10 passes at 100 from block 1 to 100 for a festival set up by
+// `setup_festival`, the organiser can be paid its share.
+fn open_pass_sale(festival_id: u32, owner: u64, pool_share: Perbill, holders_only_voting: bool) {
+	Festivals::<Test>::mutate(festival_id, |festival| festival.as_mut().unwrap().status = FestivalStatus::AwaitingStartBlock);
+	assert_ok!(FestivalModule::configure_festival_passes(
+		RuntimeOrigin::signed(owner), festival_id, 100, 10, 1, 100, pool_share, holders_only_voting,
+	));
+	Festivals::<Test>::mutate(festival_id, |festival| festival.as_mut().unwrap().status = FestivalStatus::Active);
+	fund(owner, 10_000);
+}
+
+fn insert_festival(festival_id: u32, owner: u64, status: FestivalStatus) {
+	Festivals::<Test>::insert(festival_id, Festival {
+		id: festival_id,
+		owner: owner,
+		owner_id: BoundedVec::default(),
+		name: BoundedVec::default(),
+		description: BoundedVec::default(),
+		status: status,
+		max_entry: 100,
+		total_lockup: 0,
+		categories_and_tags: BoundedVec::default(),
+		internal_movies: BoundedVec::default(),
+		external_movies: BoundedVec::default(),
+		winners: BoundedVec::default(),
+		block_start_end: (1, 10),
+		vote_power_decrease_block: 5,
+	});
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -19,6 +104,362 @@ fn correct_error_for_none_value() {
 	});
 }
 
+#[test]
+fn suspended_festivals_are_cancelled_for_good() {
+	new_test_ext().execute_with(|| {
+		insert_festival(0, 1, FestivalStatus::Active);
+
+		assert_ok!(<FestivalModule as ContentModeration<u32>>::suspend_content(&0));
+		assert_eq!(Festivals::<Test>::get(0).unwrap().status, FestivalStatus::Cancelled);
+
+		assert_noop!(
+			<FestivalModule as ContentModeration<u32>>::reinstate_content(&0),
+			Error::<Test>::CancelledFestivalCannotBeReinstated
+		);
+		assert_noop!(
+			<FestivalModule as ContentModeration<u32>>::suspend_content(&0),
+			Error::<Test>::FestivalAlreadyEnded
+		);
+		assert_noop!(
+			<FestivalModule as ContentModeration<u32>>::suspend_content(&1),
+			Error::<Test>::NonexistentFestival
+		);
+	});
+}
+
+
+// vote_for_movie_in_festival
+
+#[test]
+fn votes_are_kept_in_per_movie_tallies_and_per_voter_stakes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_festival(0, 9);
+		fund(1, 10_000);
+		fund(2, 10_000);
+
+		vote(1, 0, LINK, 100);
+		vote(1, 0, LINK, 50);
+		vote(1, 0, OTHER_LINK, 30);
+
+		// past the decrease block, a vote halfway to the end keeps half its power
+		System::set_block_number(75);
+		vote(2, 0, LINK, 100);
+
+		assert_eq!(
+			FestivalMovieTallies::<Test>::get(0, link(LINK)),
+			MovieTally { total_amount: 250, total_power: 200, votes: 3 }
+		);
+		assert_eq!(
+			FestivalMovieTallies::<Test>::get(0, link(OTHER_LINK)),
+			MovieTally { total_amount: 30, total_power: 30, votes: 1 }
+		);
+
+		let stake = FestivalVoterStakes::<Test>::get(0, 1).unwrap();
+		assert_eq!(stake.total_amount, 180);
+		assert_eq!(
+			stake.stakes.iter().map(|s| (s.movie_id.clone(), s.amount, s.votes)).collect::<Vec<_>>(),
+			vec![(link(LINK), 150, 2), (link(OTHER_LINK), 30, 1)]
+		);
+		assert_eq!(FestivalVoterStakes::<Test>::get(0, 2).unwrap().stakes[0].amount_after_weight, 50);
+
+		assert_eq!(Festivals::<Test>::get(0).unwrap().total_lockup, 280);
+		assert_eq!(Balances::free_balance(vault()), ExistentialDeposit::get() + 280);
+		// its resolution is charged for both voters
+		assert_eq!(FestivalVoterCount::<Test>::get(0), 2);
+	});
+}
+
+#[test]
+fn votes_are_only_taken_for_movies_in_an_active_festival() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_festival(0, 9);
+		fund(1, 10_000);
+
+		assert_noop!(
+			FestivalModule::vote_for_movie_in_festival(RuntimeOrigin::signed(1), 0, "missing".into(), 100, "user".into()),
+			Error::<Test>::MovieNotInFestival
+		);
+		assert_noop!(
+			FestivalModule::vote_for_movie_in_festival(RuntimeOrigin::signed(1), 0, LINK.into(), 0, "user".into()),
+			Error::<Test>::VoteValueCannotBeZero
+		);
+		assert_noop!(
+			FestivalModule::vote_for_movie_in_festival(RuntimeOrigin::signed(1), 0, LINK.into(), 101, "user".into()),
+			Error::<Test>::VoteValueTooHigh
+		);
+	});
+}
+
+
+// sponsor_festival / do_resolve_market
+
+#[test]
+fn resolved_festivals_pay_the_winning_voters_filmmakers_and_sponsorships() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_festival(0, 9);
+		fund(3, 10_000);
+		assert_ok!(MovieModule::create_external_movie(RuntimeOrigin::signed(3), ExternalSource::Youtube, LINK.into(), BoundedVec::default()));
+		fund(1, 10_000);
+		fund(2, 10_000);
+		fund(4, 10_000);
+
+		vote(1, 0, LINK, 100);
+		vote(2, 0, OTHER_LINK, 50);
+		assert_ok!(FestivalModule::sponsor_festival(
+			RuntimeOrigin::signed(4), 0, 1_000, SponsorshipBeneficiary::Split(Perbill::from_percent(50)), Some("Kinera".into()), None,
+		));
+		assert_eq!(FestivalModule::get_festival_sponsorships(0).len(), 1);
+		// sponsorships stay out of the voters' lockup
+		assert_eq!(Festivals::<Test>::get(0).unwrap().total_lockup, 150);
+
+		FestivalModule::on_finalize(100);
+
+		let festival = Festivals::<Test>::get(0).unwrap();
+		assert_eq!(festival.status, FestivalStatus::Finished);
+		assert_eq!(festival.winners.into_inner(), vec![link(LINK)]);
+		assert!(FestivalSponsorships::<Test>::get(0).is_empty());
+
+		// the pool of 150 pays 2% to the owner and 10% to the uploader of the winner,
+		// the rest goes to the winning voter, the sponsorship is split in half between them
+		assert_eq!(claimable(9), 3);
+		assert_eq!(claimable(3), 15 + 500);
+		assert_eq!(claimable(1), 132 + 500);
+		assert_eq!(claimable(2), 0);
+		assert!(WalletFestivalData::<Test>::get(3).unwrap().won_festivals.contains(&0));
+	});
+}
+
+#[test]
+fn cancelled_festivals_refund_voters_and_sponsors() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_festival(0, 9);
+		fund(1, 10_000);
+		fund(4, 10_000);
+
+		vote(1, 0, LINK, 100);
+		assert_ok!(FestivalModule::sponsor_festival(
+			RuntimeOrigin::signed(4), 0, 1_000, SponsorshipBeneficiary::TopVoters, None, None,
+		));
+		assert_eq!(Balances::free_balance(1), 9_900);
+		assert_eq!(Balances::free_balance(4), 9_000);
+
+		assert_ok!(FestivalModule::do_cancel_festival(0));
+		assert_eq!(Balances::free_balance(1), 10_000);
+		assert_eq!(Balances::free_balance(4), 10_000);
+		assert_eq!(Balances::free_balance(vault()), ExistentialDeposit::get());
+		assert_eq!(kine_stat_tracker::WalletTokens::<Test>::get(1).unwrap().locked_tokens_festival, 0);
+		assert!(FestivalVoterStakes::<Test>::get(0, 1).is_none());
+		assert_eq!(FestivalVoterCount::<Test>::get(0), 0);
+
+		// nothing can be added to a cancelled festival
+		assert_noop!(
+			FestivalModule::sponsor_festival(RuntimeOrigin::signed(4), 0, 1_000, SponsorshipBeneficiary::TopVoters, None, None),
+			Error::<Test>::FestivalNotAcceptingSponsors
+		);
+	});
+}
+
+
+// configure_festival_passes / buy_festival_pass
+
+#[test]
+fn pass_sales_are_configured_by_the_organiser_before_the_festival_starts() {
+	new_test_ext().execute_with(|| {
+		insert_festival(0, 9, FestivalStatus::AwaitingStartBlock);
+		insert_festival(1, 9, FestivalStatus::Active);
+		let share = Perbill::from_percent(40);
+
+		assert_noop!(
+			FestivalModule::configure_festival_passes(RuntimeOrigin::signed(1), 0, 100, 2, 1, 10, share, false),
+			Error::<Test>::NoFestivalAdminAccess
+		);
+		assert_noop!(
+			FestivalModule::configure_festival_passes(RuntimeOrigin::signed(9), 0, 100, 0, 1, 10, share, false),
+			Error::<Test>::PassSupplyCannotBeZero
+		);
+		assert_noop!(
+			FestivalModule::configure_festival_passes(RuntimeOrigin::signed(9), 0, 100, 2, 10, 10, share, false),
+			Error::<Test>::InvalidSaleWindow
+		);
+		assert_noop!(
+			FestivalModule::configure_festival_passes(RuntimeOrigin::signed(9), 1, 100, 2, 1, 10, share, false),
+			Error::<Test>::PassSaleNotConfigurable
+		);
+
+		assert_ok!(FestivalModule::configure_festival_passes(RuntimeOrigin::signed(9), 0, 100, 2, 1, 10, share, true));
+		let sale = FestivalPassSales::<Test>::get(0).unwrap();
+		assert_eq!((sale.price, sale.supply, sale.sold), (100, 2, 0));
+		assert_eq!((sale.sale_start, sale.sale_end), (1, 10));
+		assert!(sale.holders_only_voting);
+
+		// it may be replaced until a pass is sold
+		assert_ok!(FestivalModule::configure_festival_passes(RuntimeOrigin::signed(9), 0, 200, 3, 1, 10, share, false));
+		assert_eq!(FestivalPassSales::<Test>::get(0).unwrap().price, 200);
+	});
+}
+
+#[test]
+fn passes_are_sold_within_their_window_and_supply() {
+	new_test_ext().execute_with(|| {
+		insert_festival(0, 9, FestivalStatus::AwaitingStartBlock);
+		insert_festival(1, 9, FestivalStatus::AwaitingStartBlock);
+		fund(vault(), ExistentialDeposit::get());
+		fund(9, 10_000);
+		for buyer in 1..=4 {
+			fund(buyer, 10_000);
+		}
+		assert_ok!(FestivalModule::configure_festival_passes(
+			RuntimeOrigin::signed(9), 0, 100, 3, 5, 10, Perbill::from_percent(40), false,
+		));
+
+		System::set_block_number(4);
+		assert_noop!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(1), 0), Error::<Test>::PassSaleNotOpen);
+		assert_noop!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(1), 1), Error::<Test>::NoPassSale);
+
+		System::set_block_number(5);
+		assert_ok!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(1), 0));
+		assert_eq!(FestivalPasses::<Test>::get(0, 1), Some(5));
+		assert_noop!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(1), 0), Error::<Test>::PassAlreadyOwned);
+		assert_noop!(
+			FestivalModule::configure_festival_passes(RuntimeOrigin::signed(9), 0, 50, 3, 5, 10, Perbill::from_percent(40), false),
+			Error::<Test>::PassSaleNotConfigurable
+		);
+
+		// the pool share is locked up in the festival, the rest is paid to the organiser
+		assert_eq!(Balances::free_balance(1), 9_900);
+		assert_eq!(Balances::free_balance(9), 10_060);
+		assert_eq!(Balances::free_balance(vault()), ExistentialDeposit::get() + 40);
+		assert_eq!(Festivals::<Test>::get(0).unwrap().total_lockup, 40);
+
+		assert_ok!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(2), 0));
+		System::set_block_number(11);
+		assert_noop!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(3), 0), Error::<Test>::PassSaleNotOpen);
+		System::set_block_number(10);
+		assert_ok!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(3), 0));
+		assert_noop!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(4), 0), Error::<Test>::PassesSoldOut);
+
+		let sale = FestivalPassSales::<Test>::get(0).unwrap();
+		assert_eq!((sale.sold, sale.pool_proceeds), (3, 120));
+	});
+}
+
+#[test]
+fn only_pass_holders_vote_in_holders_only_festivals() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_festival(0, 9);
+		open_pass_sale(0, 9, Perbill::from_percent(40), true);
+		fund(1, 10_000);
+		fund(2, 10_000);
+
+		assert_ok!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(1), 0));
+		assert!(FestivalModule::has_valid_pass(0, 1));
+		assert!(!FestivalModule::has_valid_pass(0, 2));
+
+		vote(1, 0, LINK, 100);
+		assert_noop!(
+			FestivalModule::vote_for_movie_in_festival(RuntimeOrigin::signed(2), 0, LINK.into(), 100, "user".into()),
+			Error::<Test>::PassRequiredToVote
+		);
+
+		// passes are only valid while the festival runs
+		Festivals::<Test>::mutate(0, |festival| festival.as_mut().unwrap().status = FestivalStatus::Finished);
+		assert!(!FestivalModule::has_valid_pass(0, 1));
+	});
+}
+
+#[test]
+fn the_pool_share_of_passes_is_won_by_the_voters() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_festival(0, 9);
+		open_pass_sale(0, 9, Perbill::from_percent(50), false);
+		fund(3, 10_000);
+		assert_ok!(MovieModule::create_external_movie(RuntimeOrigin::signed(3), ExternalSource::Youtube, LINK.into(), BoundedVec::default()));
+		fund(1, 10_000);
+		fund(2, 10_000);
+
+		assert_ok!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(2), 0));
+		vote(1, 0, LINK, 100);
+		assert_eq!(Balances::free_balance(9), 10_050);
+		assert_eq!(Festivals::<Test>::get(0).unwrap().total_lockup, 150);
+
+		FestivalModule::on_finalize(100);
+
+		// the same pool of 150 as if it had all been voted
+		assert_eq!(claimable(9), 3);
+		assert_eq!(claimable(3), 15);
+		assert_eq!(claimable(1), 132);
+	});
+}
+
+#[test]
+fn cancelled_festivals_refund_the_pool_share_of_passes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_festival(0, 9);
+		open_pass_sale(0, 9, Perbill::from_percent(40), false);
+		fund(1, 10_000);
+		fund(2, 10_000);
+
+		assert_ok!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(1), 0));
+		assert_ok!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(2), 0));
+		vote(1, 0, LINK, 100);
+
+		assert_ok!(FestivalModule::do_cancel_festival(0));
+		// the organiser keeps its share of the passes
+		assert_eq!(Balances::free_balance(1), 10_000 - 60);
+		assert_eq!(Balances::free_balance(2), 10_000 - 60);
+		assert_eq!(Balances::free_balance(9), 10_120);
+		assert_eq!(Balances::free_balance(vault()), ExistentialDeposit::get());
+		assert!(!FestivalModule::has_valid_pass(0, 1));
+	});
+}
+
+#[test]
+fn festivals_without_votes_release_the_pass_pool_to_the_organiser() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_festival(0, 9);
+		open_pass_sale(0, 9, Perbill::from_percent(40), false);
+		fund(1, 10_000);
+
+		assert_ok!(FestivalModule::buy_festival_pass(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::free_balance(9), 10_060);
+
+		FestivalModule::on_finalize(100);
+
+		assert_eq!(Festivals::<Test>::get(0).unwrap().status, FestivalStatus::FinishedNotEnoughVotes);
+		assert_eq!(Balances::free_balance(9), 10_100);
+		assert_eq!(Balances::free_balance(vault()), ExistentialDeposit::get());
+	});
+}
+
+#[test]
+fn festivals_left_with_votes_for_suspended_movies_refund_and_drop_the_stakes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_festival(0, 9);
+		fund(1, 10_000);
+		fund(3, 10_000);
+		assert_ok!(MovieModule::create_external_movie(RuntimeOrigin::signed(3), ExternalSource::Youtube, LINK.into(), BoundedVec::default()));
+
+		vote(1, 0, LINK, 100);
+		assert_ok!(<MovieModule as ContentModeration<BoundedVec<u8, LinkStringLimit>>>::suspend_content(&link(LINK)));
+
+		FestivalModule::on_finalize(100);
+
+		assert_eq!(Festivals::<Test>::get(0).unwrap().status, FestivalStatus::FinishedNotEnoughVotes);
+		assert_eq!(Balances::free_balance(1), 10_000);
+		assert!(FestivalVoterStakes::<Test>::get(0, 1).is_none());
+		assert_eq!(FestivalVoterCount::<Test>::get(0), 0);
+	});
+}
+
 
 // create_festival
 
@@ -61,4 +502,87 @@ fn correct_error_for_none_value() {
 
 // do_get_winners_total_lockup
 
-// do_calculate_simple_reward
\ No newline at end of file
+// do_calculate_simple_reward
+
+
+// migrations
+
+// Inserts an active festival in the layout before v1, each voter voting once for LINK.
+fn put_legacy_festival(festival_id: u32, voters: &[(u64, u128)]) {
+	let votes = voters.iter().map(|(voter, amount)| Vote {
+		voter: *voter,
+		amount: *amount,
+		amount_after_weight: *amount,
+		user: BoundedVec::default(),
+	}).collect::<Vec<_>>();
+	let mut vote_map = BoundedBTreeMap::new();
+	assert_ok!(vote_map.try_insert(link(LINK), BoundedVec::truncate_from(votes)));
+
+	let festival: OldFestivalOf<Test> = OldFestival {
+		id: festival_id,
+		owner: 9,
+		owner_id: BoundedVec::default(),
+		name: BoundedVec::default(),
+		description: BoundedVec::default(),
+		status: FestivalStatus::Active,
+		max_entry: 100,
+		total_lockup: voters.iter().map(|(_, amount)| amount).sum(),
+		vote_map: vote_map,
+		categories_and_tags: BoundedVec::default(),
+		internal_movies: BoundedVec::default(),
+		external_movies: BoundedVec::truncate_from(vec![link(LINK)]),
+		winners: BoundedVec::default(),
+		block_start_end: (1, 100),
+		vote_power_decrease_block: 50,
+	};
+	frame_support::storage::unhashed::put(&Festivals::<Test>::hashed_key_for(festival_id), &festival);
+}
+
+#[test]
+fn festivals_are_migrated_a_page_per_block_and_their_hooks_postponed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let festivals = MIGRATION_PAGE_SIZE + 1;
+		for festival_id in 0..festivals {
+			put_legacy_festival(festival_id, &[(1, 100), (2, 50)]);
+		}
+		fund(vault(), ExistentialDeposit::get() + 150 * festivals as u128);
+		fund(3, 10_000);
+		assert_ok!(MovieModule::create_external_movie(RuntimeOrigin::signed(3), ExternalSource::Youtube, LINK.into(), BoundedVec::default()));
+		for (voter, amount) in [(1, 100), (2, 50)] {
+			assert_ok!(StatTrackerModule::do_update_wallet_tokens(
+				voter, kine_stat_tracker::FeatureType::Festival, kine_stat_tracker::TokenType::Locked,
+				amount * festivals as u128, false,
+			));
+		}
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert!(migrations::is_migrating::<Test>());
+		assert_eq!(FestivalModule::on_chain_storage_version(), 0);
+
+		// the festival ending meanwhile waits for the migration
+		BlockAssignments::<Test>::insert(2, BlockAssignment {
+			to_start: BoundedVec::default(),
+			to_end: BoundedVec::truncate_from(vec![0]),
+		});
+		FestivalModule::on_finalize(2);
+		assert!(PostponedAssignments::<Test>::contains_key(2));
+
+		FestivalModule::on_initialize(3);
+		assert!(!migrations::is_migrating::<Test>());
+		assert_eq!(FestivalModule::on_chain_storage_version(), 1);
+		for festival_id in 0..festivals {
+			assert_eq!(
+				FestivalMovieTallies::<Test>::get(festival_id, link(LINK)),
+				MovieTally { total_amount: 150, total_power: 150, votes: 2 }
+			);
+			assert_eq!(FestivalVoterStakes::<Test>::get(festival_id, 1).unwrap().total_amount, 100);
+			assert_eq!(Festivals::<Test>::get(festival_id).unwrap().total_lockup, 150);
+		}
+
+		System::set_block_number(3);
+		FestivalModule::on_finalize(3);
+		assert!(!PostponedAssignments::<Test>::contains_key(2));
+		assert_eq!(Festivals::<Test>::get(0).unwrap().status, FestivalStatus::Finished);
+	});
+}
@@ -1,26 +1,22 @@
 //** About **//
 	// This pallet handles the moderation process. It manages all interactions related to assigning moderators, 
 	// drafting them for reports, handling voting consensus and reward splitting.
-	// A reported content's id is matched to unique report structures that return its specific information,
-	// or information regarding each moderation "tier" or court.
+	// Each report gets its own id, matched to unique report structures that return its specific information,
+	// or information regarding each moderation "tier" or court. A content can be reported again once its
+	// previous reports are closed, while one is open new reports co-sign it.
+	// Reports point at the content through a ContentRef, and are filed against the content's owner.
+	// Above the last tier sits a final appeal court (the FinalAppealOrigin), which can override closed
+	// verdicts and take content down without a vote; each of its decisions is kept on-chain.
 	//TODO-0 remove the claimable amount from this pallet (already in stat_tracker)
 	//TODO-1 check T::Currency::unreserve(&reportee, collateral);
-	//TODO-2 implement MaxReportsByTier dynamically with ModeratorLimitByTier, through a switch case
 	//TODO-3 make a new storage for unallocated_moderators, or find another solution
-	//TODO-4 update veredicts after time limit if no appeal or vote is made
 	//TODO-5 call stat tracker and check if enough reputation when apllying for moderator
-	//TODO-6 automatically retrieve the reportee_id when creating a report
-	//TODO-7 check if report is already ongoing for that content when creating a new report
-	//TODO-8 suspend content after a successful report
-	//TODO-9 optimize moderator drafting
-	//TODO-10 check if the justification is not empty when creating a report
 	//TODO-11 optimize do_get_current_report_tier_data
 	//TODO-12 check iter_key_prefix
 	//TODO-13 add ok_or to do_create_vote
 	//TODO-14 use arithmetic in do_calculate_vote_consensus
 	//TODO-15 use drain_filter (currently unstable) instead of retain
 	//TODO-16 to report content you need at least the minimum reputation to become a moderator
-	//TODO-17 add a direct reference to the content being reported in a report (ie. a movie_id of an innapropriate movie title in a festival)
 
 
 
@@ -37,6 +33,8 @@
 	
 	#[cfg(feature = "runtime-benchmarks")]
 	mod benchmarking;
+
+	pub mod migrations;
 	
 	
 #[frame_support::pallet]
@@ -51,6 +49,7 @@ pub mod pallet {
 				pallet_prelude::*,
 				traits::{
 					Currency,
+					Randomness,
 					ReservableCurrency,
 					ExistenceRequirement::{
 						AllowDeath, 
@@ -63,14 +62,18 @@ pub mod pallet {
 			use codec::{Decode, Encode, MaxEncodedLen};
 			use sp_runtime::{
 				RuntimeDebug, 
+				Percent,
+				Perbill,
 				traits::{
 					AtLeast32BitUnsigned, 
 					CheckedAdd, 
 					CheckedSub, 
 					One,
+					Zero,
 					AccountIdConversion,
 					CheckedDiv, 
 					Saturating, 
+					Hash as HashT,
 				},
 			};
 			use scale_info::{
@@ -86,12 +89,22 @@ pub mod pallet {
 			use kine_tags::{
 				CategoryId as CategoryId,
 				TagId as TagId,
+				ContentModeration,
+				ContentOwnership,
+			};
+
+			use kine_stat_tracker::{
+				ReputationDomain,
+				ReputationHandler,
 			};
 
 
 		//* Config *//
 
+			const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 			#[pallet::pallet]
+			#[pallet::storage_version(STORAGE_VERSION)]
 			pub struct Pallet<T>(_);
 
 			#[pallet::config]
@@ -102,26 +115,125 @@ pub mod pallet {
 				type JustificationLimit: Get<u32>;
 
 				type ContentId: Member + Parameter + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+				// reported movies are referenced by their link, the runtime sets it to the movie pallet's LinkStringLimit
+				type MovieIdLimit: Get<u32>;
 				type MaxReportsByModerator: Get<u32>;
+				// every draft reads all the moderators, so their number is capped
+				type MaxModerators: Get<u32>;
 				type TotalTierOneModerators: Get<u32>;
 				type MaxReportsByTier: Get<u32>;
+				// reports filed against a content that already has an open report
+				// are added to it, up to this many
+				type MaxCoSigners: Get<u32>;
+				type MaxInfringementTypes: Get<u32>;
+
+				// evidence the reporter and reportee can attach to a report while it is voted on
+				type MaxEvidencePerReport: Get<u32>;
+				type EvidenceCidLimit: Get<u32>;
 			
+				// the total reputation is needed to moderate and report,
+				// the moderation reputation to become a senior moderator
+				type Reputation: ReputationHandler<Self::AccountId>;
 				type MinimumReputationForModeration: Get<u32>; 
 				type MinimumReputationForSeniorship: Get<u32>; 
 				type MinimumTokensForModeration: Get<BalanceOf<Self>>; 
+				// bonded on top of MinimumTokensForModeration to become a senior moderator
+				type MinimumTokensForSeniority: Get<BalanceOf<Self>>; 
+				// blocks a departing moderator's bond stays locked, and slashable, before it can be withdrawn
+				type UnbondingPeriod: Get<BlockNumberFor<Self>>;
 				type MovieCollateral: Get<BalanceOf<Self>>; 
 
+				// source of the randomness used to draft moderators
+				type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+				// blocks the drafted moderators have to vote, and blocks the reporter and reportee
+				// have to appeal a verdict before it is accepted on their behalf
+				type VotingPeriod: Get<BlockNumberFor<Self>>;
+				type AppealWindow: Get<BlockNumberFor<Self>>;
+				// blocks the moderators have to reveal their sealed votes once the voting period is over
+				type RevealPeriod: Get<BlockNumberFor<Self>>;
+				type MaxDeadlinesPerBlock: Get<u32>;
+
+				// hides the reported content once a report is accepted, and restores it
+				// when the FinalAppealOrigin overrides the verdict
+				type ContentModerator: ContentModeration<ContentRefOf<Self>>;
+				// checks the reported content exists and finds its owner, who becomes the reportee
+				type ContentOwners: ContentOwnership<ContentRefOf<Self>, Self::AccountId>;
+				// moderators with a stake in the reported content aren't drafted for its report
+				type ConflictsOfInterest: ConflictOfInterest<Self::AccountId, ContentRefOf<Self>>;
+
+				// a moderator's majority agreement is only judged once it handled this many reports,
+				// below MinimumMajorityAgreement seniors are demoted and juniors removed
+				type MetricsMinimumReports: Get<u32>;
+				type MinimumMajorityAgreement: Get<Percent>;
+				// moderators are removed once they let this many voting or reveal deadlines pass
+				type MaxMissedDeadlines: Get<u32>;
+				// how many of the latest response times the median response time is taken from
+				type MaxResponseTimes: Get<u32>;
+
+				// the court above the last tier: it can override closed verdicts and take content down
+				type FinalAppealOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+				// sets how many moderators are drafted in each tier
+				type ModerationAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 				// type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
 				type PalletId: Get<PalletId>;
 			}
 
+			// Tells whether a moderator has a stake in a report, e.g. a community shared with the
+			// reportee or a vote in the reported festival. `check_weight` is the cost of one check.
+			pub trait ConflictOfInterest<AccountId, ContentRef> {
+				fn has_conflict(moderator_id: &AccountId, reportee_id: &AccountId, content: &ContentRef) -> bool;
+				fn check_weight() -> Weight;
+			}
+
+			impl<AccountId, ContentRef> ConflictOfInterest<AccountId, ContentRef> for () {
+				fn has_conflict(_moderator_id: &AccountId, _reportee_id: &AccountId, _content: &ContentRef) -> bool { false }
+				fn check_weight() -> Weight { Weight::zero() }
+			}
+
 	//** Types **//	
 	
 		//* Types *//
 
 			type BalanceOf<T> = <<T as kine_stat_tracker::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+			pub type ReportId = u32;
+
+			// index of an evidence entry in its report's ReportEvidence list
+			pub type EvidenceId = u32;
+
+			pub type DecisionId = u32;
+
+			pub type ContentRefOf<T> = ContentRef<
+				<T as Config>::ContentId,
+				BoundedVec<u8, <T as Config>::MovieIdLimit>,
+				(kine_tags::CategoryType<T>, CategoryId<T>),
+				TagId<T>,
+			>;
+
+			pub type ReportOf<T> = Report<
+				<T as frame_system::Config>::AccountId,
+				ContentRefOf<T>,
+				ReportStatus,
+				BoundedVec<u8, <T as Config>::JustificationLimit>,
+				BoundedVec<(CategoryId<T>, TagId<T>), <T as kine_tags::Config>::MaxTags>,
+				BoundedVec<InfringimentType, <T as Config>::MaxInfringementTypes>,
+				BoundedVec<
+					CoSigner<
+						<T as frame_system::Config>::AccountId,
+						BalanceOf<T>,
+						BoundedVec<u8, <T as Config>::JustificationLimit>,
+					>,
+					<T as Config>::MaxCoSigners,
+				>,
+			>;
 			
 		//* Constants *//
+
+			// how many blocks a deadline can be pushed back when its block is full
+			const MAX_DEADLINE_DELAY: u32 = 100;
 		//* Enums *//
 
 			#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -139,18 +251,20 @@ pub mod pallet {
 				Categorization,
 			}
 
-			#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-			pub enum ContentType {
-				Festival,
-				Movie,
-				Tag,
-				RankingList,
-				SocialSpace,
+			// The content a report is about. Movies are referenced by their id, tags by their category.
+			#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+			pub enum ContentRef<ContentId, MovieId, CategoryId, TagId> {
+				Movie(MovieId),
+				Festival(ContentId),
+				RankingList(ContentId),
+				Community(ContentId),
+				Tag(CategoryId, TagId),
 			}
 
 			#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 			pub enum ReportStatus {
 				InResolution,
+				AwaitingReveal,
 				MajorityVotedFor,
 				MajorityVotedAgainst,
 				AppealedByReporter,
@@ -173,21 +287,45 @@ pub mod pallet {
 				Abstinence,
 			}
 
-			#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+			#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 			pub enum ModeratorRank {
 				Junior,
 				Senior,
 			}
 
+			#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+			pub enum FinalAction {
+				// report, previous status, new status
+				VerdictOverridden(ReportId, ReportStatus, ReportStatus),
+				EmergencyTakedown,
+			}
+
+			#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+			pub enum ModeratorStatus<BlockNumber> {
+				Active,
+				// not drafted anymore, the bond can be withdrawn from this block
+				Unbonding(BlockNumber),
+			}
+
 		//* Structs *//
 
 			#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-			pub struct Report <AccountId, ReportStatus, BoundedString, CategoryTagList> {
+			pub struct Report <AccountId, ContentRef, ReportStatus, BoundedString, CategoryTagList, InfringementList, CoSignerList> {
+				pub content: ContentRef,
 				pub	reporter_id: AccountId,
 				pub reportee_id: AccountId,
 				pub justification: BoundedString,
 				pub status: ReportStatus,
 				pub categories_and_tags: CategoryTagList,
+				pub infringement_types: InfringementList,
+				pub co_signers: CoSignerList,
+			}
+
+			#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+			pub struct CoSigner <AccountId, BalanceOf, BoundedString> {
+				pub co_signer_id: AccountId,
+				pub staked_tokens: BalanceOf,
+				pub justification: BoundedString,
 			}
 
 			#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -199,15 +337,46 @@ pub mod pallet {
 			}
 
 			#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-			pub struct Moderator<ReportList, ModeratorRank> {
+			pub struct Moderator<ReportList, ModeratorRank, ModeratorStatus, BalanceOf> {
 				pub assigned_reports: ReportList,
 				pub rank: ModeratorRank,
+				pub status: ModeratorStatus,
+				pub bonded_tokens: BalanceOf,
+			}
+
+			// How a moderator performed in the reports it was drafted for.
+			#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+			pub struct ModeratorMetrics<BlockNumber, ResponseTimes> {
+				pub reports_handled: u32,
+				pub majority_votes: u32,
+				pub missed_deadlines: u32,
+				// blocks between being drafted and committing a vote, oldest first
+				pub response_times: ResponseTimes,
+				pub median_response_time: BlockNumber,
 			}
 
 			#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-			pub struct Vote <AccountId> {
+			pub struct Vote <AccountId, EvidenceList> {
 				pub voter: AccountId,
 				pub is_for: VoteChoice,
+				pub cited_evidence: EvidenceList,
+			}
+
+			#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+			pub struct FinalDecision <ContentRef, BlockNumber, BoundedString> {
+				pub action: FinalAction,
+				pub content: ContentRef,
+				pub justification: BoundedString,
+				pub decided_at: BlockNumber,
+			}
+
+			#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+			pub struct Evidence <AccountId, BoundedCid, BoundedString> {
+				pub submitter: AccountId,
+				pub cid: BoundedCid,
+				// seconds into the movie the evidence refers to, if any
+				pub movie_timestamp: Option<u32>,
+				pub note: BoundedString,
 			}
 
 
@@ -216,19 +385,23 @@ pub mod pallet {
 
 		//* Reports *//
 
+			// Store the ID of the next report
+			#[pallet::storage]
+			#[pallet::getter(fn next_report_id)]
+			pub(super) type NextReportId<T: Config> = StorageValue<
+				_, 
+				ReportId,
+				ValueQuery
+			>;
+
 			// matches a report id to the report data
 			#[pallet::storage]
 			#[pallet::getter(fn report)]
 			pub type Reports<T: Config> =
 				StorageMap<
 					_, 
-					Blake2_128Concat, (T::ContentId, ContentType), 
-					Report<
-						T::AccountId, 
-						ReportStatus, 
-						BoundedVec<u8, T::JustificationLimit>,
-						BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
-					>,
+					Blake2_128Concat, ReportId, 
+					ReportOf<T>,
 				>;
 
 			// matches a report id to its current status
@@ -237,9 +410,52 @@ pub mod pallet {
 			pub type ReportVerdicts<T: Config> =
 				StorageDoubleMap<
 					_,
-					Blake2_128Concat, (T::ContentId, ContentType), 
+					Blake2_128Concat, ReportId, 
 					Blake2_128Concat, Tiers,
-					ReportOutcome<BoundedVec<Vote<T::AccountId>, T::MaxReportsByTier>, BalanceOf<T>>,
+					ReportOutcome<BoundedVec<Vote<T::AccountId, BoundedVec<EvidenceId, T::MaxEvidencePerReport>>, T::MaxReportsByTier>, BalanceOf<T>>,
+					OptionQuery,
+				>;
+
+			// The sealed votes of a report's current tier, until they are revealed.
+			#[pallet::storage]
+			#[pallet::getter(fn vote_commitment)]
+			pub type VoteCommitments<T: Config> =
+				StorageDoubleMap<
+					_,
+					Blake2_128Concat, ReportId,
+					Blake2_128Concat, T::AccountId,
+					T::Hash,
+					OptionQuery,
+				>;
+
+			// The evidence attached to each report, in submission order.
+			#[pallet::storage]
+			#[pallet::getter(fn report_evidence)]
+			pub type ReportEvidence<T: Config> =
+				StorageMap<
+					_,
+					Blake2_128Concat, ReportId,
+					BoundedVec<
+						Evidence<
+							T::AccountId,
+							BoundedVec<u8, T::EvidenceCidLimit>,
+							BoundedVec<u8, T::JustificationLimit>,
+						>,
+						T::MaxEvidencePerReport,
+					>,
+					ValueQuery,
+				>;
+
+			// Every report filed against a content, with its current status. Only one of
+			// them can be open at a time.
+			#[pallet::storage]
+			#[pallet::getter(fn content_reports)]
+			pub type ContentReports<T: Config> =
+				StorageDoubleMap<
+					_,
+					Blake2_128Concat, ContentRefOf<T>, 
+					Blake2_128Concat, ReportId,
+					ReportStatus,
 					OptionQuery,
 				>;
 
@@ -255,30 +471,123 @@ pub mod pallet {
 					_, 
 					Blake2_128Concat, T::AccountId, 
 					Moderator<
-						BoundedVec<T::ContentId, T::MaxReportsByModerator>, 
+						BoundedVec<ReportId, T::MaxReportsByModerator>, 
 						ModeratorRank,
+						ModeratorStatus<BlockNumberFor<T>>,
+						BalanceOf<T>,
 					>,
 					OptionQuery,
 				>;
 
-	
-	
+			// How many moderators are drafted in each tier. Tiers that were never
+			// set fall back to do_calculate_moderators_in_tier.
+			#[pallet::storage]
+			#[pallet::getter(fn moderators_per_tier)]
+			pub type ModeratorsPerTier<T: Config> = StorageMap<_, Blake2_128Concat, Tiers, u32, OptionQuery>;
+
+			#[pallet::storage]
+			#[pallet::getter(fn moderator_stats)]
+			pub type ModeratorStats<T: Config> =
+				StorageMap<
+					_,
+					Blake2_128Concat, T::AccountId,
+					ModeratorMetrics<BlockNumberFor<T>, BoundedVec<BlockNumberFor<T>, T::MaxResponseTimes>>,
+					ValueQuery,
+				>;
+
+			// The block each moderator was drafted for a report, until it commits its vote.
+			#[pallet::storage]
+			pub type DraftedAt<T: Config> =
+				StorageDoubleMap<
+					_,
+					Blake2_128Concat, ReportId,
+					Blake2_128Concat, T::AccountId,
+					BlockNumberFor<T>,
+					OptionQuery,
+				>;
+
+			// Incremented on every draft, so drafts in the same block get different seeds.
+			#[pallet::storage]
+			pub type DraftNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+
+		//* Final Appeal *//
+
+			// Store the ID of the next decision of the FinalAppealOrigin
+			#[pallet::storage]
+			#[pallet::getter(fn next_decision_id)]
+			pub(super) type NextDecisionId<T: Config> = StorageValue<
+				_, 
+				DecisionId,
+				ValueQuery
+			>;
+
+			// Every decision taken by the FinalAppealOrigin.
+			#[pallet::storage]
+			#[pallet::getter(fn final_decision)]
+			pub type FinalDecisions<T: Config> =
+				StorageMap<
+					_,
+					Blake2_128Concat, DecisionId,
+					FinalDecision<ContentRefOf<T>, BlockNumberFor<T>, BoundedVec<u8, T::JustificationLimit>>,
+					OptionQuery,
+				>;
+
+
+		//* Deadlines *//
+
+			// The reports whose voting period or appeal window ends at each block.
+			#[pallet::storage]
+			pub type ReportDeadlines<T: Config> =
+				StorageMap<
+					_,
+					Blake2_128Concat, BlockNumberFor<T>,
+					BoundedVec<ReportId, T::MaxDeadlinesPerBlock>,
+					ValueQuery,
+				>;
+
+			// The current deadline of each report. Entries of ReportDeadlines that don't
+			// match it were superseded and are skipped.
+			#[pallet::storage]
+			#[pallet::getter(fn report_deadline)]
+			pub type ReportDeadline<T: Config> =
+				StorageMap<
+					_,
+					Blake2_128Concat, ReportId,
+					BlockNumberFor<T>,
+					OptionQuery,
+				>;
+
+
+
 	//** Events **//
 
 		#[pallet::event]
 		#[pallet::generate_deposit(pub(super) fn deposit_event)]
 		pub enum Event<T: Config> {
 			ModeratorRegistered(T::AccountId),
+			ModeratorPromoted(T::AccountId),
+			ModeratorDemoted(T::AccountId),
+			ModeratorMetricsBreached(T::AccountId),
+			ModeratorsPerTierUpdated(Tiers, u32),
 			ModerationActivitySuspended(T::AccountId),
+			ModeratorUnbonding(T::AccountId, BlockNumberFor<T>),
+			ModerationBondWithdrawn(T::AccountId, BalanceOf<T>),
+			ReportReallocated(ReportId, T::AccountId, T::AccountId),
+			ReportReallocationFailed(ReportId, T::AccountId),
 			ModerationRewardsClaimed(T::AccountId),
 
-			VoteSubmitted(),
+			VoteCommitted(ReportId, T::AccountId),
+			VoteRevealed(ReportId, T::AccountId),
+			RevealPhaseStarted(ReportId),
 			
-			ReportCreated(T::ContentId),
-			ReportClosed(T::ContentId, ReportStatus),
-			ReportAppealed(T::ContentId),
-			ReportAppealAccepted(T::ContentId),
-			ReportAppealRefused(T::ContentId),
+			ReportCreated(ReportId, ContentRefOf<T>),
+			ReportCoSigned(ReportId, T::AccountId),
+			EvidenceSubmitted(ReportId, EvidenceId, T::AccountId),
+			ReportClosed(ReportId, ReportStatus),
+			ReportAppealed(ReportId),
+			ReportAppealAccepted(ReportId),
+			ReportAppealRefused(ReportId),
 			FestivalReported,
 			MovieReported,
 			CategoryReported,
@@ -297,6 +606,20 @@ pub mod pallet {
 			JuryDraftedTierThree,
 			ModerationStartedTierThree,
 			TierThreeFinalized,
+
+			JuryRedrafted(ReportId),
+			JuryUnavailable(ReportId),
+			VotingClosedAtDeadline(ReportId, ReportStatus),
+			VerdictAutoAccepted(ReportId),
+			ReportDeadlineFailed(ReportId),
+
+			ContentSuspended(ContentRefOf<T>),
+			ContentSuspensionFailed(ContentRefOf<T>),
+			ContentReinstated(ContentRefOf<T>),
+			ContentReinstatementFailed(ContentRefOf<T>),
+
+			VerdictOverridden(DecisionId, ReportId, ReportStatus),
+			EmergencyTakedown(DecisionId, ContentRefOf<T>),
 		}
 	
 
@@ -315,6 +638,13 @@ pub mod pallet {
 			InvalidModeratorData,
 			ModeratorNotDraftedForReport,
 			NotEnoughModeratorsAvailable,
+			TooManyModerators,
+			ModeratorReportLimitReached,
+			AlreadySeniorModerator,
+			ModeratorNotActive,
+			ModeratorNotUnbonding,
+			BondStillUnbonding,
+			InvalidModeratorsPerTier,
 			
 			UserCannotAcceptVerdict,
 			
@@ -322,6 +652,26 @@ pub mod pallet {
 			ReportsAwaitingVote,
 			ReportAlreadyOngoing,
 			ReportAppealLimitReached,
+			TooManyReportDeadlines,
+			ReportNotClosed,
+			CannotReportOwnContent,
+			InvalidFinalVerdict,
+			CannotCoSignReport,
+			AlreadyCoSignedReport,
+			ReportCoSignerLimitReached,
+			EmptyJustification,
+			MissingInfringementType,
+			DuplicateInfringementType,
+			InfringementTypeLimitReached,
+			EmptyEvidence,
+			EvidenceLimitReached,
+			NonexistentEvidence,
+			ReportNotInVoting,
+			ReportNotInReveal,
+			VoteAlreadyCommitted,
+			NoVoteCommitment,
+			CommitmentMismatch,
+			NotReportParty,
 
 			InsuficientBalance,
 			WalletStatsRegistryRequired,
@@ -336,6 +686,43 @@ pub mod pallet {
 		#[pallet::hooks]
 		impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 			
+			// Closes the votes and appeal windows that end in this block. At most
+			// MaxDeadlinesPerBlock reports end in a block, each charged for its worst case.
+			fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+				
+				// reports aren't resolved until the storage is migrated
+				if crate::migrations::is_migrating::<T>() {
+					return crate::migrations::migrate_page::<T>()
+						.saturating_add(Self::do_postpone_report_deadlines(now))
+				}
+
+				let mut weight = T::DbWeight::get().reads_writes(1, 1);
+				let deadlines = ReportDeadlines::<T>::take(now);
+				if deadlines.is_empty() {
+					return weight;
+				}
+
+				let resolution_weight = Self::do_get_resolution_weight();
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+				for report_id in deadlines {
+					weight = weight.saturating_add(T::DbWeight::get().reads(1));
+					if ReportDeadline::<T>::get(report_id) != Some(now) {
+						continue;
+					}
+					ReportDeadline::<T>::remove(report_id);
+
+					// a failed resolution is rolled back, leaving the report as it was
+					let resolved = frame_support::storage::with_storage_layer(|| {
+						Self::do_resolve_expired_report(report_id)
+					});
+					if resolved.is_err() {
+						Self::deposit_event(Event::ReportDeadlineFailed(report_id));
+					}
+					weight = weight.saturating_add(resolution_weight);
+				}
+				
+				weight
+			}
 		}
 	
 
@@ -358,8 +745,7 @@ pub mod pallet {
 					Error::<T>::WalletStatsRegistryRequired,
 				);
 				ensure!(
-					kine_stat_tracker::WalletTokens::<T>::try_get(who.clone()).unwrap().reputation_moderation 
-					>= T::MinimumReputationForModeration::get(),
+					T::Reputation::total_reputation(&who) >= T::MinimumReputationForModeration::get(),
 					Error::<T>::NotEnoughReputation,
 				);
 				
@@ -378,55 +764,76 @@ pub mod pallet {
 
 			
 
-			#[pallet::call_index(1)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
-			pub fn update_reputation_senior(
+			// Promotes a junior moderator with enough reputation to senior, bonding
+			// MinimumTokensForSeniority. Only seniors are drafted in appeal tiers.
+			#[pallet::call_index(1)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2,2))]
+			pub fn apply_for_seniority(
 				origin: OriginFor<T>,
 			) -> DispatchResultWithPostInfo {
 				
 				let who = ensure_signed(origin)?;
-				Self::do_check_if_already_moderator(who.clone())?;
-				ensure!(
-					kine_stat_tracker::Pallet::<T>::do_is_wallet_registered(who.clone())?,
-					Error::<T>::WalletStatsRegistryRequired,
-				);
+				let moderator = Moderators::<T>::get(who.clone()).ok_or(Error::<T>::NonexistentModerator)?;
+				ensure!(moderator.status == ModeratorStatus::Active, Error::<T>::ModeratorNotActive);
+				ensure!(moderator.rank == ModeratorRank::Junior, Error::<T>::AlreadySeniorModerator);
 				ensure!(
-					kine_stat_tracker::WalletTokens::<T>::try_get(who.clone()).unwrap().reputation_moderation 
-					>= T::MinimumReputationForModeration::get(),
+					T::Reputation::reputation(&who, ReputationDomain::Moderation) >= T::MinimumReputationForSeniorship::get(),
 					Error::<T>::NotEnoughReputation,
 				);
 				
-				Self::do_transfer_funds_to_treasury(who.clone(), T::MinimumTokensForModeration::get())?;
+				Self::do_transfer_funds_to_treasury(who.clone(), T::MinimumTokensForSeniority::get())?;
 				kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
 					who.clone(), 
 					kine_stat_tracker::FeatureType::Moderation,
 					kine_stat_tracker::TokenType::Locked,
-					T::MinimumTokensForModeration::get(), false
+					T::MinimumTokensForSeniority::get(), false
 				)?;
-				Self::do_create_moderator(who.clone())?;
+				Moderators::<T>::try_mutate_exists(who.clone(), |mod_data| -> DispatchResult {
+					let moderator_data = mod_data.as_mut().ok_or(Error::<T>::NonexistentModerator)?;
+					moderator_data.rank = ModeratorRank::Senior;
+					moderator_data.bonded_tokens = moderator_data.bonded_tokens.saturating_add(T::MinimumTokensForSeniority::get());
+					Ok(())
+				})?;
 				
-				Self::deposit_event(Event::ModeratorRegistered(who));
+				Self::deposit_event(Event::ModeratorPromoted(who));
 				Ok(().into())
 			}	
 
 
-			#[pallet::call_index(2)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+			// Stops drafting the moderator and starts unbonding its bond. The reports it
+			// hasn't voted on yet are handed to newly drafted moderators.
+			#[pallet::call_index(2)]#[pallet::weight(
+				Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(10,10)
+				+ Pallet::<T>::do_get_draft_weight().saturating_mul(T::MaxReportsByModerator::get().into())
+			)]
 			pub fn suspend_moderation_activity(
 				origin: OriginFor<T>,
 			) -> DispatchResultWithPostInfo {
 				
 				let who = ensure_signed(origin)?;
-				ensure!(
-					Self::do_can_moderator_suspend(who.clone())?,
-					Error::<T>::ReportsAwaitingVote
-				);
+				let moderator = Moderators::<T>::get(who.clone()).ok_or(Error::<T>::NonexistentModerator)?;
+				ensure!(moderator.status == ModeratorStatus::Active, Error::<T>::ModeratorNotActive);
 				
-				Self::do_suspend_moderation(who.clone())?;
+				Self::do_start_unbonding(who.clone())?;
 
 				Self::deposit_event(Event::ModerationActivitySuspended(who));
 				Ok(().into())
 			}	
 
 
+			// Returns the bond of a moderator whose unbonding period is over.
+			#[pallet::call_index(12)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2,2))]
+			pub fn withdraw_moderation_bond(
+				origin: OriginFor<T>,
+			) -> DispatchResultWithPostInfo {
+				
+				let who = ensure_signed(origin)?;
+				let bonded_tokens = Self::do_withdraw_moderation_bond(who.clone())?;
+
+				Self::deposit_event(Event::ModerationBondWithdrawn(who, bonded_tokens));
+				Ok(().into())
+			}	
+
+
 			#[pallet::call_index(3)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
 			pub fn claim_moderation_rewards(
 				origin: OriginFor<T>,
@@ -441,14 +848,15 @@ pub mod pallet {
 
 
 			
-			#[pallet::call_index(4)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+			// Reports a content, on behalf of the content's owner. If the content already has an
+			// open report, the caller's stake and justification are added to it instead.
+			#[pallet::call_index(4)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1) + Pallet::<T>::do_get_draft_weight())]
 			pub fn create_report(
 				origin: OriginFor<T>,
-				content_id: T::ContentId,
-				content_type: ContentType,
-				reportee_id: T::AccountId,
+				content: ContentRefOf<T>,
 				justification: BoundedVec<u8, T::JustificationLimit>,
 				category_tag_list: BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
+				infringement_types: BoundedVec<InfringimentType, T::MaxInfringementTypes>,
 			) -> DispatchResultWithPostInfo {
 				
 				let who = ensure_signed(origin)?;
@@ -457,8 +865,7 @@ pub mod pallet {
 					Error::<T>::WalletStatsRegistryRequired,
 				);
 				ensure!(
-					kine_stat_tracker::WalletTokens::<T>::try_get(who.clone()).unwrap().reputation_moderation 
-					>= T::MinimumReputationForModeration::get(),
+					T::Reputation::total_reputation(&who) >= T::MinimumReputationForModeration::get(),
 					Error::<T>::NotEnoughReputation,
 				);
 
@@ -467,11 +874,20 @@ pub mod pallet {
 					= TryInto::try_into("Moderation".as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
 
 				Self::do_validate_report_data (
-					content_id, content_type, justification.clone(), 
-					category_type.clone(), category_tag_list.clone()
+					justification.clone(), 
+					category_type.clone(), category_tag_list.clone(),
+					infringement_types.clone(),
 				)?; 
 
+				let reportee_id = Self::do_get_content_owner(&content)?;
+				ensure!(reportee_id != who, Error::<T>::CannotReportOwnContent);
+
 				let reward_pool = Self::do_calculate_report_pool(T::TotalTierOneModerators::get())?;
+				let open_report = Self::do_get_open_report(&content);
+				if let Some(report_id) = open_report {
+					Self::do_add_co_signer(report_id, who.clone(), reward_pool.0, justification.clone(), infringement_types.clone())?;
+				}
+
 				Self::do_transfer_funds_to_treasury(who.clone(), reward_pool.0)?;
 				kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
 					who.clone(), 
@@ -480,15 +896,23 @@ pub mod pallet {
 					reward_pool.0, false,
 				)?;
 
-				let required_moderators = Self::do_calculate_moderators_in_tier(Tiers::TierOne)?;
-				let drafted_moderators = Self::do_draft_moderators(who.clone(), reportee_id.clone(), required_moderators)?;
+				if let Some(report_id) = open_report {
+					Self::deposit_event(Event::ReportCoSigned(report_id, who));
+					return Ok(().into());
+				}
+
+				let report_id = Self::do_create_report(
+					who, content.clone(), reportee_id, 
+					justification, category_tag_list.clone(), infringement_types,
+				)?;
+				let drafted_moderators = Self::do_draft_jury(report_id, Tiers::TierOne)?;
 
-				Self::do_create_report(who, content_id, content_type, reportee_id, justification, category_tag_list.clone())?;
-				Self::do_create_report_verdict(content_id, content_type, Tiers::TierOne, reward_pool.1)?;
-				Self::do_assign_report_to_moderators(content_id, drafted_moderators)?;
+				Self::do_create_report_verdict(report_id, Tiers::TierOne, reward_pool.1)?;
+				Self::do_assign_report_to_moderators(report_id, drafted_moderators)?;
+				Self::do_schedule_report_deadline(report_id, T::VotingPeriod::get())?;
 
-				// parse the u32 type into a BoundedVec<u8, T::ContentStringLimit
-				let encoded: Vec<u8> = content_id.encode();
+				// parse the ReportId type into a BoundedVec<u8, T::ContentStringLimit
+				let encoded: Vec<u8> = report_id.encode();
 				let bounded_content_id: BoundedVec<u8, T::ContentStringLimit> = 
 					TryInto::try_into(encoded).map_err(|_|Error::<T>::BadMetadata)?;
 
@@ -498,49 +922,53 @@ pub mod pallet {
 					bounded_content_id,
 				)?;
 
-				Self::deposit_event(Event::ReportCreated(content_id));
+				Self::deposit_event(Event::ReportCreated(report_id, content));
 				Ok(().into())
 			}
 
 
-			#[pallet::call_index(5)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
-			pub fn submit_vote(
+			// Seals a drafted moderator's vote, as returned by do_get_vote_commitment. Votes are
+			// revealed once every moderator committed or the voting period ended, so nobody can
+			// follow the emerging majority.
+			#[pallet::call_index(5)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3,1))]
+			pub fn commit_vote(
 				origin: OriginFor<T>,
-				content_id: T::ContentId,
-				content_type: ContentType,
-				vote: VoteChoice,
+				report_id: ReportId,
+				commitment: T::Hash,
 			) -> DispatchResultWithPostInfo {
 				
 				let who = ensure_signed(origin)?;
-				Self::do_can_moderator_vote(who.clone(), content_id)?;
-
-				let tier_data = Self::do_get_current_report_tier_data(content_id, content_type)?;
-				Self::do_create_vote(content_id, content_type, tier_data.0, who.clone(), vote)?;
-				Self::do_deallocate_moderator_from_report(who, content_id)?;
-				
-				if Self::do_are_all_votes_submitted(content_id, content_type, tier_data.0)? == true {
-					let consensus = Self::do_calculate_vote_consensus(content_id, content_type, tier_data.0)?;
-					Self::do_update_report_status(content_id, content_type, consensus)?;
+				Self::do_can_moderator_vote(who.clone(), report_id)?;
+				let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+				ensure!(report.status == ReportStatus::InResolution, Error::<T>::ReportNotInVoting);
+				ensure!(!VoteCommitments::<T>::contains_key(report_id, who.clone()), Error::<T>::VoteAlreadyCommitted);
+
+				VoteCommitments::<T>::insert(report_id, who.clone(), commitment);
+				Self::do_record_response_time(who.clone(), report_id)?;
+
+				let tier_data = Self::do_get_current_report_tier_data(report_id)?;
+				let verdict = ReportVerdicts::<T>::get(report_id, tier_data.0).ok_or(Error::<T>::NonexistentReport)?;
+				if VoteCommitments::<T>::iter_key_prefix(report_id).count() as u32 >= verdict.required_votes {
+					Self::do_start_reveal_phase(report_id)?;
 				}
 
-				Self::deposit_event(Event::VoteSubmitted());
+				Self::deposit_event(Event::VoteCommitted(report_id, who));
 				Ok(().into())
 			}
 
 
-			#[pallet::call_index(6)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+			#[pallet::call_index(6)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1) + Pallet::<T>::do_get_verdict_reputation_weight())]
 			pub fn submit_report_consensus_decision(
 				origin: OriginFor<T>,
-				content_id: T::ContentId,
-				content_type: ContentType,
+				report_id: ReportId,
 				decision: bool,
 			) -> DispatchResultWithPostInfo {
 				
 				let who = ensure_signed(origin)?;
-				let consensus = Self::do_check_if_report_verdict_is_acceptable(content_id, content_type)?;
-				let is_reporter = Self::do_check_if_reporter_or_reportee(who.clone(), content_id, content_type)?;
-				Self::do_can_user_accept_verdict(is_reporter, content_id, content_type)?;
-				let tier_data = Self::do_get_current_report_tier_data(content_id, content_type)?;
+				let consensus = Self::do_check_if_report_verdict_is_acceptable(report_id)?;
+				let is_reporter = Self::do_check_if_reporter_or_reportee(who.clone(), report_id)?;
+				Self::do_can_user_accept_verdict(is_reporter, report_id)?;
+				let tier_data = Self::do_get_current_report_tier_data(report_id)?;
 				let report_status : ReportStatus;
 				
 				if decision == false { // appeal verdict
@@ -555,83 +983,47 @@ pub mod pallet {
 						reward_pool.0, false,
 					)?;
 					report_status = Self::do_get_report_status_on_appeal(is_reporter)?;
-					Self::deposit_event(Event::ReportAppealed(content_id));
+					Self::deposit_event(Event::ReportAppealed(report_id));
 				}
 					
 				else { // accept verdict
-					let reporter_id = Self::do_get_reporter(content_id, content_type)?;
-					let reportee_id = Self::do_get_reportee(content_id, content_type)?;
-					report_status = Self::do_get_report_status_on_accept(is_reporter)?;
-					
-					if !is_reporter {
-						let reportee_slash = Self::do_convert_collateral_to_balance()?;
-						Self::do_grab_reportee_collateral(content_id, content_type, reportee_slash)?;
-					}
-
-					for tier in tier_data.3 { // iterate all existing tiers
-						let report_voters = Self::do_get_report_voters_by_vote(content_id, content_type, tier, consensus)?;
-						
-						let reward_pool = Self::do_get_total_moderation_pool(content_id, content_type, tier)?;
-						let majority_voter_reward = Self::do_calculate_majority_voter_reward(reward_pool, report_voters.0.len() as u32)?;
-						
-						Self::do_distribute_rewards_to_majority_voters(report_voters.0, majority_voter_reward)?;
-						Self::do_slash_minority_voters(report_voters.1)?;
-						
-						if is_reporter {
-							let reportee_reward = Self::do_calculate_reportee_reward(reward_pool)?;
-							kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
-								reportee_id.clone(), 
-								kine_stat_tracker::FeatureType::Moderation,
-								kine_stat_tracker::TokenType::Claimable,
-								reportee_reward, false,
-							)?;
-							
-						}
-						else {
-							let reporter_reward = Self::do_calculate_reporter_reward(reward_pool)?;
-							kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
-								reporter_id.clone(), 
-								kine_stat_tracker::FeatureType::Moderation,
-								kine_stat_tracker::TokenType::Claimable,
-								reporter_reward, false,
-							)?;
-							
-						}
-					} 
-					Self::deposit_event(Event::ReportClosed(content_id, report_status));
+					report_status = Self::do_accept_verdict(report_id, is_reporter, consensus)?;
 				}
-				Self::do_update_report_status(content_id, content_type, report_status)?;
-				
+				Self::do_update_report_status(report_id, report_status)?;
+				ReportDeadline::<T>::remove(report_id);
+								
 				Ok(().into())
 			}
 
 
-			#[pallet::call_index(7)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+			#[pallet::call_index(7)]#[pallet::weight(
+				Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1)
+				+ Pallet::<T>::do_get_draft_weight() + Pallet::<T>::do_get_verdict_reputation_weight()
+			)]
 			pub fn submit_report_appeal_decision(
 				origin: OriginFor<T>,
-				content_id: T::ContentId,
-				content_type: ContentType,
+				report_id: ReportId,
 				decision: bool,
 			) -> DispatchResultWithPostInfo {
 			
 				let who = ensure_signed(origin)?;
-				let consensus = Self::do_check_if_report_appeal_is_acceptable(content_id, content_type)?;
-				let is_reporter = Self::do_check_if_reporter_or_reportee(who, content_id, content_type)?;
-				Self::do_can_user_accept_appeal(is_reporter, content_id, content_type)?;
-				let tier_data = Self::do_get_current_report_tier_data(content_id, content_type)?;
+				let consensus = Self::do_check_if_report_appeal_is_acceptable(report_id)?;
+				let is_reporter = Self::do_check_if_reporter_or_reportee(who, report_id)?;
+				Self::do_can_user_accept_appeal(is_reporter, report_id)?;
+				let tier_data = Self::do_get_current_report_tier_data(report_id)?;
 
 				if decision == false {
-					let reporter_id = Self::do_get_reporter(content_id, content_type)?;
-					let reportee_id = Self::do_get_reportee(content_id, content_type)?;
+					let reporter_id = Self::do_get_reporter(report_id)?;
+					let reportee_id = Self::do_get_reportee(report_id)?;
 
 					if !is_reporter {
 						let reportee_slash = Self::do_convert_collateral_to_balance()?;
-						Self::do_grab_reportee_collateral(content_id, content_type, reportee_slash)?;
+						Self::do_grab_reportee_collateral(report_id, reportee_slash)?;
 					}
 					
 					for tier in tier_data.3 {
-						let report_voters = Self::do_get_report_voters_by_vote(content_id, content_type, tier, consensus)?;
-						let reward_pool = Self::do_get_total_moderation_pool(content_id, content_type, tier)?;
+						let report_voters = Self::do_get_report_voters_by_vote(report_id, tier, consensus)?;
+						let reward_pool = Self::do_get_total_moderation_pool(report_id, tier)?;
 						let majority_voter_reward = Self::do_calculate_majority_voter_reward(reward_pool, report_voters.0.len() as u32)?;
 						
 						Self::do_distribute_rewards_to_majority_voters(report_voters.0, majority_voter_reward)?;
@@ -645,7 +1037,7 @@ pub mod pallet {
 								kine_stat_tracker::TokenType::Claimable,
 								reportee_reward, false,
 							)?;
-							Self::do_update_report_status(content_id, content_type, ReportStatus::Refused)?;
+							Self::do_update_report_status(report_id, ReportStatus::Refused)?;
 						}
 						else {
 							let reporter_reward = Self::do_calculate_reporter_reward(reward_pool)?;
@@ -655,9 +1047,14 @@ pub mod pallet {
 								kine_stat_tracker::TokenType::Claimable,
 								reporter_reward, false,
 							)?;
-							Self::do_update_report_status(content_id, content_type, ReportStatus::Accepted)?;
+							Self::do_update_report_status(report_id, ReportStatus::Accepted)?;
 						}
 					}
+					Self::do_settle_co_signers(report_id, Self::do_get_report_status_on_accept(is_reporter)?)?;
+					if !is_reporter {
+						let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+						Self::do_suspend_reported_content(report.content);
+					}
 					
 					let appeal_fee = BalanceOf::<T>::from(Self::do_calculate_report_pool(T::TotalTierOneModerators::get())?.0);
 					if is_reporter {
@@ -676,14 +1073,14 @@ pub mod pallet {
 							appeal_fee, false,
 						)?;
 					}
-					Self::deposit_event(Event::ReportAppealRefused(content_id));
+					Self::deposit_event(Event::ReportAppealRefused(report_id));
 
 				}
 
 				else {
 					let appeal_fee = Self::do_calculate_report_pool(T::TotalTierOneModerators::get())?.0;
-					let reporter_id = Self::do_get_reporter(content_id, content_type)?;
-					let reportee_id = Self::do_get_reportee(content_id, content_type)?;
+					let reporter_id = Self::do_get_reporter(report_id)?;
+					let reportee_id = Self::do_get_reportee(report_id)?;
 					if is_reporter {
 						Self::do_transfer_funds_to_treasury(reporter_id.clone(), appeal_fee)?;
 						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
@@ -703,20 +1100,163 @@ pub mod pallet {
 						)?;
 					}
 
-					let required_moderators = Self::do_calculate_moderators_in_tier(tier_data.1)?;
-					let drafted_moderators = Self::do_draft_moderators(reporter_id, reportee_id, required_moderators)?;
+					let drafted_moderators = Self::do_draft_jury(report_id, tier_data.1)?;
 					let reward_pool = Self::do_calculate_report_pool(T::TotalTierOneModerators::get())?;
 
-					Self::do_create_report_verdict(content_id, content_type, tier_data.1, reward_pool.1)?;
-					Self::do_assign_report_to_moderators(content_id, drafted_moderators)?;
-					Self::do_update_report_status(content_id, content_type, ReportStatus::InResolution)?;
-					Self::deposit_event(Event::ReportAppealAccepted(content_id));
+					Self::do_create_report_verdict(report_id, tier_data.1, reward_pool.1)?;
+					Self::do_assign_report_to_moderators(report_id, drafted_moderators)?;
+					Self::do_update_report_status(report_id, ReportStatus::InResolution)?;
+					Self::do_schedule_report_deadline(report_id, T::VotingPeriod::get())?;
+					Self::deposit_event(Event::ReportAppealAccepted(report_id));
 				}
 				
 				Ok(().into())
 			}
 
 
+			// Replaces the verdict of a closed report, for verdicts that are obviously wrong. The payouts
+			// of the report are reversed and the content is suspended or restored to match the new verdict.
+			#[pallet::call_index(8)]#[pallet::weight(
+				Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(50,50)
+				+ Pallet::<T>::do_get_verdict_reputation_weight().saturating_mul(2)
+			)]
+			pub fn override_verdict(
+				origin: OriginFor<T>,
+				report_id: ReportId,
+				verdict: ReportStatus,
+				justification: BoundedVec<u8, T::JustificationLimit>,
+			) -> DispatchResultWithPostInfo {
+			
+				T::FinalAppealOrigin::ensure_origin(origin)?;
+				ensure!(!justification.is_empty(), Error::<T>::EmptyJustification);
+				let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+				ensure!(
+					report.status == ReportStatus::Accepted || report.status == ReportStatus::Refused, 
+					Error::<T>::ReportNotClosed
+				);
+				ensure!(
+					(verdict == ReportStatus::Accepted || verdict == ReportStatus::Refused) && verdict != report.status, 
+					Error::<T>::InvalidFinalVerdict
+				);
+
+				Self::do_reverse_report_payouts(report_id, report.status)?;
+				Self::do_update_report_status(report_id, verdict)?;
+				if verdict == ReportStatus::Accepted {
+					Self::do_suspend_reported_content(report.content.clone());
+				}
+				else {
+					Self::do_reinstate_reported_content(report.content.clone());
+				}
+
+				let decision_id = Self::do_record_final_decision(
+					FinalAction::VerdictOverridden(report_id, report.status, verdict),
+					report.content, justification,
+				)?;
+				Self::deposit_event(Event::VerdictOverridden(decision_id, report_id, verdict));
+				Self::deposit_event(Event::ReportClosed(report_id, verdict));
+				Ok(().into())
+			}
+
+
+			// Suspends a content right away, without a report or a vote, e.g. for legal takedowns.
+			#[pallet::call_index(13)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3,3))]
+			pub fn emergency_takedown(
+				origin: OriginFor<T>,
+				content: ContentRefOf<T>,
+				justification: BoundedVec<u8, T::JustificationLimit>,
+			) -> DispatchResultWithPostInfo {
+			
+				T::FinalAppealOrigin::ensure_origin(origin)?;
+				ensure!(!justification.is_empty(), Error::<T>::EmptyJustification);
+
+				// unlike a verdict, a takedown that can't be applied fails
+				T::ContentModerator::suspend_content(&content)?;
+				Self::deposit_event(Event::ContentSuspended(content.clone()));
+
+				let decision_id = Self::do_record_final_decision(
+					FinalAction::EmergencyTakedown, 
+					content.clone(), justification,
+				)?;
+				Self::deposit_event(Event::EmergencyTakedown(decision_id, content));
+				Ok(().into())
+			}
+
+
+			// Sets the panel size of a tier, at most MaxReportsByTier moderators.
+			#[pallet::call_index(11)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+			pub fn set_moderators_per_tier(
+				origin: OriginFor<T>,
+				tier: Tiers,
+				total_moderators: u32,
+			) -> DispatchResultWithPostInfo {
+			
+				T::ModerationAdminOrigin::ensure_origin(origin)?;
+				ensure!(
+					total_moderators > 0 && total_moderators <= T::MaxReportsByTier::get(),
+					Error::<T>::InvalidModeratorsPerTier
+				);
+
+				ModeratorsPerTier::<T>::insert(tier, total_moderators);
+
+				Self::deposit_event(Event::ModeratorsPerTierUpdated(tier, total_moderators));
+				Ok(().into())
+			}
+
+
+			// Opens a sealed vote. Consensus is computed once every committed vote
+			// is revealed, or when the reveal period ends.
+			#[pallet::call_index(10)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4,3))]
+			pub fn reveal_vote(
+				origin: OriginFor<T>,
+				report_id: ReportId,
+				vote: VoteChoice,
+				cited_evidence: BoundedVec<EvidenceId, T::MaxEvidencePerReport>,
+				salt: [u8; 32],
+			) -> DispatchResultWithPostInfo {
+			
+				let who = ensure_signed(origin)?;
+				let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+				ensure!(report.status == ReportStatus::AwaitingReveal, Error::<T>::ReportNotInReveal);
+				let commitment = VoteCommitments::<T>::get(report_id, who.clone()).ok_or(Error::<T>::NoVoteCommitment)?;
+				ensure!(
+					commitment == Self::do_get_vote_commitment(who.clone(), report_id, vote, cited_evidence.clone(), salt),
+					Error::<T>::CommitmentMismatch
+				);
+				Self::do_validate_cited_evidence(report_id, cited_evidence.clone())?;
+
+				let tier_data = Self::do_get_current_report_tier_data(report_id)?;
+				Self::do_create_vote(report_id, tier_data.0, who.clone(), vote, cited_evidence)?;
+				VoteCommitments::<T>::remove(report_id, who.clone());
+				Self::do_deallocate_moderator_from_report(who.clone(), report_id)?;
+
+				if VoteCommitments::<T>::iter_key_prefix(report_id).next().is_none() {
+					Self::do_close_voting(report_id, tier_data.0)?;
+				}
+
+				Self::deposit_event(Event::VoteRevealed(report_id, who));
+				Ok(().into())
+			}
+
+
+			// Attaches evidence to a report while its moderators are voting.
+			// Only the parties of the report can submit it.
+			#[pallet::call_index(9)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2,1))]
+			pub fn submit_evidence(
+				origin: OriginFor<T>,
+				report_id: ReportId,
+				cid: BoundedVec<u8, T::EvidenceCidLimit>,
+				movie_timestamp: Option<u32>,
+				note: BoundedVec<u8, T::JustificationLimit>,
+			) -> DispatchResultWithPostInfo {
+			
+				let who = ensure_signed(origin)?;
+				let evidence_id = Self::do_add_evidence(report_id, who.clone(), cid, movie_timestamp, note)?;
+
+				Self::deposit_event(Event::EvidenceSubmitted(report_id, evidence_id, who));
+				Ok(().into())
+			}
+
+
 		}
 	
 	
@@ -741,12 +1281,15 @@ pub mod pallet {
 					who: T::AccountId,
 				) -> Result<(), DispatchError> {
 					
-					let empty_bounded_reports: BoundedVec<T::ContentId, T::MaxReportsByModerator>
+					ensure!(Moderators::<T>::count() < T::MaxModerators::get(), Error::<T>::TooManyModerators);
+					let empty_bounded_reports: BoundedVec<ReportId, T::MaxReportsByModerator>
 						= TryInto::try_into(Vec::new()).map_err(|_|Error::<T>::BadMetadata)?;
 					
 					let moderator = Moderator {	
 						assigned_reports: empty_bounded_reports,
 						rank: ModeratorRank::Junior,
+						status: ModeratorStatus::Active,
+						bonded_tokens: T::MinimumTokensForModeration::get(),
 					};
 					
 					Moderators::<T>::insert(who, moderator.clone());
@@ -755,43 +1298,122 @@ pub mod pallet {
 				} 
 
 
+				// Drafts the panel of a report's tier. Appeal tiers are judged by seniors only.
+				pub fn do_draft_jury(
+					report_id: ReportId,
+					tier: Tiers,
+				) -> Result<Vec<T::AccountId>, DispatchError> {
+
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+					Self::do_draft_moderators(
+						Self::do_get_report_parties(report_id)?,
+						Self::do_get_moderators_in_tier(tier)?,
+						Self::do_get_minimum_rank(tier),
+						Some(&report),
+					)
+				}
+
+
+				pub fn do_get_minimum_rank(
+					tier: Tiers,
+				) -> ModeratorRank {
+
+					match tier {
+						Tiers::TierOne => ModeratorRank::Junior,
+						Tiers::TierTwo | Tiers::TierThree => ModeratorRank::Senior,
+					}
+				}
+
+
+				// Drafts moderators, leaving out the excluded accounts. Conflicts of interest with the report
+				// are only checked for the drawn moderators, a conflicted one is dropped and another is drawn.
 				pub fn do_draft_moderators(
-					reporter_id: T::AccountId,
-					reportee_id: T::AccountId,
+					excluded_accounts: Vec<T::AccountId>,
 					required_moderators: u32,
+					minimum_rank: ModeratorRank,
+					report: Option<&ReportOf<T>>,
 				) -> Result<Vec<T::AccountId>, DispatchError> { 
 					
-					let mut moderator_data: Vec<T::AccountId> = 
-						Moderators::<T>::iter().map(|(x, _)| x).collect(); 
-					// add iter keys, this version does not support them (https://github.com/paritytech/substrate/pull/9238))
+					// Moderators that can take another report, weighted by how many more they can take so the
+					// least busy ones are the most likely to be drafted. The parties of a report can't judge
+					// it, and unbonding moderators aren't drafted anymore.
+					let max_reports = T::MaxReportsByModerator::get();
+					let mut candidates: Vec<(T::AccountId, u32)> = 
+						Moderators::<T>::iter()
+						.filter(|(moderator_id, moderator)| 
+							!excluded_accounts.contains(moderator_id) 
+							&& moderator.rank >= minimum_rank
+							&& moderator.status == ModeratorStatus::Active
+						)
+						.filter_map(|(moderator_id, moderator)| {
+							let free_slots = max_reports.saturating_sub(moderator.assigned_reports.len() as u32);
+							if free_slots > 0 { Some((moderator_id, free_slots)) } else { None }
+						})
+						.collect();
 					
-					moderator_data.retain(|x| x != &reporter_id && x != &reportee_id);
-					//(x != reporter_id && x != reportee_id)
-					// majority_votes.retain(|vote| vote.is_for == VoteChoice::For); 
-					
-					ensure!(moderator_data.len() as u32 >= required_moderators, Error::<T>::NotEnoughModeratorsAvailable);
-
-					let drafted_moderators = moderator_data.drain(0..required_moderators as usize).collect();
-					// ensure!(moderator_data.len() >= T::TotalTierOneModerators::get() as usize, Error::<T>::NotEnoughModeratorsAvailable);
-					// let btree = kine_stat_tracker::Pallet::<T>::create_moderator_btree(drafted_moderators).unwrap();
+					ensure!(candidates.len() as u32 >= required_moderators, Error::<T>::NotEnoughModeratorsAvailable);
+
+					// draw without replacement, each pick hashing the draft's seed with its index
+					let seed = Self::do_get_draft_seed();
+					let mut drafted_moderators = Vec::new();
+					let mut draft_index: u32 = 0;
+					while (drafted_moderators.len() as u32) < required_moderators {
+						ensure!(!candidates.is_empty(), Error::<T>::NotEnoughModeratorsAvailable);
+						let total_weight: u64 = candidates.iter().map(|(_, free_slots)| *free_slots as u64).sum();
+						let random_hash = T::Hashing::hash_of(&(seed, draft_index));
+						draft_index = draft_index.saturating_add(1);
+						let mut pick = 
+							u64::decode(&mut random_hash.as_ref())
+							.map_err(|_| Error::<T>::BadMetadata)?
+							% total_weight;
+
+						let drafted_index = candidates.iter().position(|(_, free_slots)| {
+							if pick < *free_slots as u64 {
+								return true;
+							}
+							pick -= *free_slots as u64;
+							false
+						}).ok_or(Error::<T>::NotEnoughModeratorsAvailable)?;
+						let (moderator_id, _) = candidates.swap_remove(drafted_index);
+						let has_conflict = report.map_or(false, |report|
+							T::ConflictsOfInterest::has_conflict(&moderator_id, &report.reportee_id, &report.content)
+						);
+						if has_conflict {
+							continue;
+						}
+						drafted_moderators.push(moderator_id);
+					}
 
 					Ok(drafted_moderators)
 				} 	
+
+
+				// A new seed from the randomness source for each draft.
+				fn do_get_draft_seed() -> T::Hash {
+					
+					let nonce = DraftNonce::<T>::mutate(|nonce| {
+						*nonce = nonce.wrapping_add(1);
+						*nonce
+					});
+					let (seed, _) = T::Randomness::random(&(b"kine/mod", nonce).encode());
+					seed
+				}
+				
 				
-				
 
 				pub fn do_assign_report_to_moderators(
-					content_id: T::ContentId,
+					report_id: ReportId,
 					moderators: Vec<T::AccountId>,
 				) -> Result<(), DispatchError> {
 					
 					for moderator_id in moderators.iter() { // assign the report to each selected moderator
 						Moderators::<T>::try_mutate_exists(moderator_id, |moderator_data| -> DispatchResult {
 							let moderator  = moderator_data.as_mut().ok_or(Error::<T>::NonexistentModerator)?;
-							moderator.assigned_reports.try_push(content_id).unwrap();
+							moderator.assigned_reports.try_push(report_id).map_err(|_| Error::<T>::ModeratorReportLimitReached)?;
 							
 							Ok(())
 						})?;
+						DraftedAt::<T>::insert(report_id, moderator_id, <frame_system::Pallet<T>>::block_number());
 					}
 					
 					Ok(())
@@ -800,76 +1422,110 @@ pub mod pallet {
 
 				pub fn do_deallocate_moderator_from_report(
 					moderator_id: T::AccountId,
-					content_id: T::ContentId,
+					report_id: ReportId,
 				) -> Result<(), DispatchError> {
 					
-					Moderators::<T>::try_mutate_exists(moderator_id, |moderator_data| -> DispatchResult {
+					Moderators::<T>::try_mutate_exists(moderator_id.clone(), |moderator_data| -> DispatchResult {
 						let mod_data = moderator_data.as_mut().ok_or(Error::<T>::NonexistentModerator)?;
-						mod_data.assigned_reports.retain(|assigned_id| *assigned_id != content_id);
+						mod_data.assigned_reports.retain(|assigned_id| *assigned_id != report_id);
 						Ok(())
 					})?;
+					DraftedAt::<T>::remove(report_id, moderator_id);
 					
 					Ok(())
 				} 
 
 
-				pub fn do_can_moderator_suspend(
+				// Moves a moderator to unbonding, whether it left or was removed. Its bond can
+				// still be slashed, for the votes it already cast, until the period is over.
+				pub fn do_start_unbonding(
 					who: T::AccountId,
-				) -> Result<bool, DispatchError> {
-					
-					let moderator = Moderators::<T>::try_get(who).unwrap();
-					let mut can_suspend = true;
-					if moderator.assigned_reports.len() == 0 {
-						can_suspend = false;
-					}
+				) -> Result<(), DispatchError> {
 
-					Ok(can_suspend)
-				} 
-				
+					let unbonding_at = <frame_system::Pallet<T>>::block_number().saturating_add(T::UnbondingPeriod::get());
+					Moderators::<T>::try_mutate_exists(who.clone(), |mod_data| -> DispatchResult {
+						let moderator_data = mod_data.as_mut().ok_or(Error::<T>::NonexistentModerator)?;
+						moderator_data.status = ModeratorStatus::Unbonding(unbonding_at);
+						Ok(())
+					})?;
+					Self::do_reallocate_moderator_reports(who.clone())?;
 
-				pub fn do_suspend_moderation(
+					Self::deposit_event(Event::ModeratorUnbonding(who, unbonding_at));
+					Ok(())
+				}
+
+
+				// Hands the reports a moderator hasn't committed a vote on to newly drafted moderators.
+				// Committed votes stay with the moderator, who still has to reveal them.
+				pub fn do_reallocate_moderator_reports(
 					who: T::AccountId,
 				) -> Result<(), DispatchError> {
-					
-					let moderator = Moderators::<T>::try_get(who.clone()).unwrap();
-					
-					let tokens_moderation = 
-						kine_stat_tracker::Pallet::<T>::
-						get_wallet_tokens(who.clone()).unwrap();
-					
-					Self::do_transfer_funds_from_treasury(who.clone(), tokens_moderation.locked_tokens_moderation)?;
-					
-					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
-						who.clone(), 
-						kine_stat_tracker::FeatureType::Moderation,
-						kine_stat_tracker::TokenType::Locked,
-						tokens_moderation.locked_tokens_moderation, true,
-					).unwrap();
+
+					let moderator = Moderators::<T>::get(who.clone()).ok_or(Error::<T>::NonexistentModerator)?;
+					for report_id in moderator.assigned_reports {
+						if VoteCommitments::<T>::contains_key(report_id, who.clone()) {
+							continue;
+						}
+						Self::do_deallocate_moderator_from_report(who.clone(), report_id)?;
+
+						let tier = Self::do_get_current_report_tier_data(report_id)?.0;
+						let mut excluded_accounts = Self::do_get_report_parties(report_id)?;
+						excluded_accounts.push(who.clone());
+						// the rest of the jury, whether it committed already or not
+						excluded_accounts.extend(DraftedAt::<T>::iter_key_prefix(report_id));
+						excluded_accounts.extend(VoteCommitments::<T>::iter_key_prefix(report_id));
+						
+						let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+						match Self::do_draft_moderators(excluded_accounts, 1, Self::do_get_minimum_rank(tier), Some(&report)) {
+							Ok(drafted_moderators) => {
+								let replacement = drafted_moderators[0].clone();
+								Self::do_assign_report_to_moderators(report_id, drafted_moderators)?;
+								Self::deposit_event(Event::ReportReallocated(report_id, who.clone(), replacement));
+							},
+							// the report is decided by the rest of its panel
+							Err(_) => Self::deposit_event(Event::ReportReallocationFailed(report_id, who.clone())),
+						}
+					}
+
+					Ok(())
+				}
 
 
-					Self::do_transfer_funds_from_treasury(who.clone(), tokens_moderation.claimable_tokens_moderation)?;
+				pub fn do_withdraw_moderation_bond(
+					who: T::AccountId,
+				) -> Result<BalanceOf<T>, DispatchError> {
 					
+					let moderator = Moderators::<T>::get(who.clone()).ok_or(Error::<T>::NonexistentModerator)?;
+					match moderator.status {
+						ModeratorStatus::Unbonding(unbonding_at) => ensure!(
+							<frame_system::Pallet<T>>::block_number() >= unbonding_at,
+							Error::<T>::BondStillUnbonding
+						),
+						ModeratorStatus::Active => return Err(Error::<T>::ModeratorNotUnbonding.into()),
+					}
+					// committed votes must be revealed, or expire, first
+					ensure!(moderator.assigned_reports.is_empty(), Error::<T>::ReportsAwaitingVote);
+
+					Self::do_transfer_funds_from_treasury(who.clone(), moderator.bonded_tokens)?;
 					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
 						who.clone(), 
 						kine_stat_tracker::FeatureType::Moderation,
-						kine_stat_tracker::TokenType::Claimable,
-						tokens_moderation.claimable_tokens_moderation, true,
-					).unwrap();
-					
-					
-					
+						kine_stat_tracker::TokenType::Locked,
+						moderator.bonded_tokens, true,
+					)?;
+
 					Moderators::<T>::remove(who);
-					Ok(())
+					Ok(moderator.bonded_tokens)
 				} 
 
 
 				pub fn do_can_moderator_vote(
 					who: T::AccountId,
-					content_id: T::ContentId,
+					report_id: ReportId,
 				) -> Result<(), DispatchError> {
 					
-					let assigned_reports = Moderators::<T>::try_get(who).unwrap().assigned_reports;
-					ensure!(assigned_reports.contains(&content_id), Error::<T>::ModeratorNotDraftedForReport);
+					let assigned_reports = Moderators::<T>::get(who).ok_or(Error::<T>::NonexistentModerator)?.assigned_reports;
+					ensure!(assigned_reports.contains(&report_id), Error::<T>::ModeratorNotDraftedForReport);
 
 					Ok(())
 				} 
@@ -879,30 +1535,184 @@ pub mod pallet {
 			//* Report *//
 
 				pub fn do_validate_report_data(
-					content_id: T::ContentId,
-					content_type: ContentType,
-					_justification: BoundedVec<u8, T::JustificationLimit>,
+					justification: BoundedVec<u8, T::JustificationLimit>,
 					category_type: kine_tags::CategoryType<T>,
 					category_tag_list: BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
+					infringement_types: BoundedVec<InfringimentType, T::MaxInfringementTypes>,
 				) -> Result<(), DispatchError> {
 					
+					ensure!(!justification.is_empty(), Error::<T>::EmptyJustification);
+					ensure!(!infringement_types.is_empty(), Error::<T>::MissingInfringementType);
+					for (index, infringement_type) in infringement_types.iter().enumerate() {
+						ensure!(
+							!infringement_types[..index].contains(infringement_type), 
+							Error::<T>::DuplicateInfringementType
+						);
+					}
+
+
                     kine_tags::Pallet::<T>::do_validate_tag_data(
                         category_type, 
                         category_tag_list.clone()
                     )?;
-
-					ensure!(!Reports::<T>::contains_key((content_id, content_type)), Error::<T>::ReportAlreadyOngoing);
 					
 					Ok(())
 				} 
+
+
+				// The report of a content that hasn't been accepted or refused yet, if any.
+				pub fn do_get_open_report(
+					content: &ContentRefOf<T>,
+				) -> Option<ReportId> {
+
+					ContentReports::<T>::iter_prefix(content)
+					.find(|(_, status)| *status != ReportStatus::Accepted && *status != ReportStatus::Refused)
+					.map(|(report_id, _)| report_id)
+				}
+
+
+				pub fn do_add_co_signer(
+					report_id: ReportId,
+					who: T::AccountId,
+					staked_tokens: BalanceOf<T>,
+					justification: BoundedVec<u8, T::JustificationLimit>,
+					infringement_types: BoundedVec<InfringimentType, T::MaxInfringementTypes>,
+				) -> Result<(), DispatchError> {
+
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+					ensure!(
+						report.reporter_id != who && report.reportee_id != who,
+						Error::<T>::CannotCoSignReport
+					);
+					ensure!(
+						!report.co_signers.iter().any(|co_signer| co_signer.co_signer_id == who),
+						Error::<T>::AlreadyCoSignedReport
+					);
+					// the moderators judging the report can't back it
+					ensure!(!Self::do_is_moderator_on_report(who.clone(), report_id), Error::<T>::CannotCoSignReport);
+
+					Reports::<T>::try_mutate_exists(report_id, |report| -> DispatchResult {
+						let rep = report.as_mut().ok_or(Error::<T>::NonexistentReport)?;
+						rep.co_signers.try_push(CoSigner {
+							co_signer_id: who,
+							staked_tokens: staked_tokens,
+							justification: justification,
+						}).map_err(|_| Error::<T>::ReportCoSignerLimitReached)?;
+
+						// co-signers can report other infringements of the same content
+						for infringement_type in infringement_types {
+							if !rep.infringement_types.contains(&infringement_type) {
+								rep.infringement_types.try_push(infringement_type)
+								.map_err(|_| Error::<T>::InfringementTypeLimitReached)?;
+							}
+						}
+						Ok(())
+					})
+				} 
+
+
+				// The reporter, the reportee and the co-signers of a report.
+				pub fn do_get_report_parties(
+					report_id: ReportId,
+				) -> Result<Vec<T::AccountId>, DispatchError> {
+
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+					let mut parties = vec![report.reporter_id, report.reportee_id];
+					parties.extend(report.co_signers.into_iter().map(|co_signer| co_signer.co_signer_id));
+					Ok(parties)
+				}
+
+
+				// Whether the moderator is drafted for the report, or voted on it in any tier.
+				pub fn do_is_moderator_on_report(
+					who: T::AccountId,
+					report_id: ReportId,
+				) -> bool {
+
+					let is_drafted = 
+						Moderators::<T>::get(who.clone())
+						.map_or(false, |moderator| moderator.assigned_reports.contains(&report_id));
+					is_drafted || ReportVerdicts::<T>::iter_prefix_values(report_id)
+						.any(|verdict| verdict.votes.iter().any(|vote| vote.voter == who))
+				}
+
+
+				// Co-signers get their stake back when the report is accepted.
+				// When it is refused, their stakes go to the reportee.
+				pub fn do_settle_co_signers(
+					report_id: ReportId,
+					report_status: ReportStatus,
+				) -> Result<(), DispatchError> {
+
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+					for co_signer in report.co_signers {
+						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+							co_signer.co_signer_id.clone(), 
+							kine_stat_tracker::FeatureType::Moderation,
+							kine_stat_tracker::TokenType::Locked,
+							co_signer.staked_tokens, true,
+						)?;
+
+						let receiver = 
+							if report_status == ReportStatus::Accepted { co_signer.co_signer_id }
+							else { report.reportee_id.clone() };
+						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+							receiver, 
+							kine_stat_tracker::FeatureType::Moderation,
+							kine_stat_tracker::TokenType::Claimable,
+							co_signer.staked_tokens, false,
+						)?;
+					}
+
+					Ok(())
+				}
 				
 
+				pub fn do_add_evidence(
+					report_id: ReportId,
+					who: T::AccountId,
+					cid: BoundedVec<u8, T::EvidenceCidLimit>,
+					movie_timestamp: Option<u32>,
+					note: BoundedVec<u8, T::JustificationLimit>,
+				) -> Result<EvidenceId, DispatchError> {
+
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+					ensure!(report.status == ReportStatus::InResolution, Error::<T>::ReportNotInVoting);
+					ensure!(Self::do_get_report_parties(report_id)?.contains(&who), Error::<T>::NotReportParty);
+					ensure!(!cid.is_empty(), Error::<T>::EmptyEvidence);
+
+					ReportEvidence::<T>::try_mutate(report_id, |evidence_list| -> Result<EvidenceId, DispatchError> {
+						let evidence_id = evidence_list.len() as EvidenceId;
+						evidence_list.try_push(Evidence {
+							submitter: who,
+							cid: cid,
+							movie_timestamp: movie_timestamp,
+							note: note,
+						}).map_err(|_| Error::<T>::EvidenceLimitReached)?;
+						Ok(evidence_id)
+					})
+				}
+
+
+				pub fn do_validate_cited_evidence(
+					report_id: ReportId,
+					cited_evidence: BoundedVec<EvidenceId, T::MaxEvidencePerReport>,
+				) -> Result<(), DispatchError> {
+
+					let total_evidence = ReportEvidence::<T>::decode_len(report_id).unwrap_or(0) as EvidenceId;
+					ensure!(
+						cited_evidence.iter().all(|evidence_id| *evidence_id < total_evidence),
+						Error::<T>::NonexistentEvidence
+					);
+					Ok(())
+				}
+
+
 				pub fn do_check_if_report_verdict_is_acceptable(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 				) -> Result<ReportStatus, DispatchError> {
 					
-					let report = Reports::<T>::try_get((content_id.clone(), content_type)).unwrap();
+					let report = Reports::<T>::try_get(report_id).unwrap();
 					ensure!(
 						(report.status == ReportStatus::MajorityVotedFor || report.status == ReportStatus::MajorityVotedAgainst), 
 						Error::<T>::NonexistentReport
@@ -912,11 +1722,10 @@ pub mod pallet {
 				
 				
 				pub fn do_check_if_report_appeal_is_acceptable(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 				) -> Result<ReportStatus, DispatchError> {
 					
-					let report = Reports::<T>::try_get((content_id.clone(), content_type)).unwrap();
+					let report = Reports::<T>::try_get(report_id).unwrap();
 					ensure!(
 						(report.status == ReportStatus::AppealedByReporter || report.status == ReportStatus::AppealedByReportee), 
 						Error::<T>::NonexistentReport
@@ -927,65 +1736,76 @@ pub mod pallet {
 					
 				pub fn do_create_report(
 					who: T::AccountId,
-					content_id: T::ContentId,
-					content_type: ContentType,
+					content: ContentRefOf<T>,
 					reportee_id: T::AccountId,
 					justification:BoundedVec<u8, T::JustificationLimit>,
 					category_tag_list: BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
-				) -> Result<(), DispatchError> {
+					infringement_types: BoundedVec<InfringimentType, T::MaxInfringementTypes>,
+				) -> Result<ReportId, DispatchError> {
+
+					let report_id =
+						NextReportId::<T>::try_mutate(|id| -> Result<ReportId, DispatchError> {
+							let current_id = *id;
+							*id = id
+								.checked_add(One::one())
+								.ok_or(Error::<T>::Overflow)?;
+							Ok(current_id)
+						})?;
 
 					let report = Report {
+							content: content.clone(),
 							reporter_id: who.clone(),
 							reportee_id: reportee_id.clone(),
 							justification: justification.clone(),
 							status: ReportStatus::InResolution,
 							categories_and_tags: category_tag_list,
+							infringement_types: infringement_types,
+							co_signers: BoundedVec::default(),
 						};
-					Reports::<T>::insert((content_id.clone(), content_type), report.clone());
+					Reports::<T>::insert(report_id, report.clone());
+					ContentReports::<T>::insert(content, report_id, ReportStatus::InResolution);
 			
-					Ok(())
+					Ok(report_id)
 				} 
 				
 					
 				pub fn do_create_report_verdict(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 					tier: Tiers,
 					reward_pool: BalanceOf<T>,
 				) -> Result<(), DispatchError> {
 					
-					let empty_bounded_votes: BoundedVec<Vote<T::AccountId>, T::MaxReportsByTier>
+					let empty_bounded_votes: BoundedVec<Vote<T::AccountId, BoundedVec<EvidenceId, T::MaxEvidencePerReport>>, T::MaxReportsByTier>
 						= TryInto::try_into(Vec::new()).map_err(|_|Error::<T>::BadMetadata)?; // new empty BoundedVec
 					let report_outcome = ReportOutcome {
 						staked_tokens: reward_pool,
-						required_votes: T::TotalTierOneModerators::get(),
+						required_votes: Self::do_get_moderators_in_tier(tier)?,
 						votes_for: 0,
 						votes: empty_bounded_votes,
 					};
-					ReportVerdicts::<T>::insert((content_id.clone(), content_type), tier, report_outcome);
+					ReportVerdicts::<T>::insert(report_id, tier, report_outcome);
 			
 					Ok(())
 				} 
 			
 
 				pub fn do_get_current_report_tier_data(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 				) -> Result<(Tiers, Tiers, bool, Vec<Tiers>), DispatchError> {
 					
-					ensure!(ReportVerdicts::<T>::contains_key((content_id.clone(), content_type), Tiers::TierOne), Error::<T>::NonexistentReport);
+					ensure!(ReportVerdicts::<T>::contains_key(report_id, Tiers::TierOne), Error::<T>::NonexistentReport);
 					let mut current_tier = Tiers::TierOne;
 					let mut next_tier = Tiers::TierTwo;
 					let mut is_appealable = true;
 					let mut all_tiers : Vec<Tiers> = vec![Tiers::TierOne];
 
-					if ReportVerdicts::<T>::contains_key((content_id.clone(), content_type), Tiers::TierThree) {
+					if ReportVerdicts::<T>::contains_key(report_id, Tiers::TierThree) {
 						current_tier = Tiers::TierThree; 
 						next_tier = Tiers::TierThree;
 						all_tiers.append(&mut vec![Tiers::TierTwo, Tiers::TierThree]);
 						is_appealable = false
 					}
-					else if ReportVerdicts::<T>::contains_key((content_id.clone(), content_type), Tiers::TierTwo) {
+					else if ReportVerdicts::<T>::contains_key(report_id, Tiers::TierTwo) {
 						current_tier = Tiers::TierTwo; 
 						next_tier = Tiers::TierThree;
 						all_tiers.append(&mut vec![Tiers::TierTwo]);
@@ -997,14 +1817,14 @@ pub mod pallet {
 			
 				
 				pub fn do_update_report_status(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 					report_status: ReportStatus,
 				) -> Result<(), DispatchError> {
 					
-					Reports::<T>::try_mutate_exists((content_id.clone(), content_type), |report| -> DispatchResult {
+					Reports::<T>::try_mutate_exists(report_id, |report| -> DispatchResult {
 						let mut rep = report.as_mut().ok_or(Error::<T>::NonexistentReport)?;
 						rep.status = report_status;
+						ContentReports::<T>::insert(rep.content.clone(), report_id, report_status);
 						Ok(())
 					})
 				} 
@@ -1012,13 +1832,12 @@ pub mod pallet {
 			
 				pub fn do_check_if_reporter_or_reportee(
 					who: T::AccountId,
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 				) -> Result<bool, DispatchError> {
 					
 					let mut is_reporter = true;
 
-					let report = Reports::<T>::try_get((content_id.clone(), content_type)).unwrap();
+					let report = Reports::<T>::try_get(report_id).unwrap();
 					if report.reportee_id == who {
 						is_reporter = false;
 					}
@@ -1030,11 +1849,10 @@ pub mod pallet {
 			
 				pub fn do_can_user_accept_verdict(
 					is_reporter: bool,
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 				) -> Result<(), DispatchError> {
 					
-					let report = Reports::<T>::try_get((content_id.clone(), content_type)).unwrap();
+					let report = Reports::<T>::try_get(report_id).unwrap();
 					if report.status == ReportStatus::MajorityVotedFor {
 						ensure!(!is_reporter, Error::<T>::UserCannotAcceptVerdict);
 					} else if report.status == ReportStatus::MajorityVotedAgainst {
@@ -1047,11 +1865,10 @@ pub mod pallet {
 			
 				pub fn do_can_user_accept_appeal(
 					is_reporter: bool,
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 				) -> Result<(), DispatchError> {
 					
-					let report = Reports::<T>::try_get((content_id.clone(), content_type)).unwrap();
+					let report = Reports::<T>::try_get(report_id).unwrap();
 					if report.status == ReportStatus::AppealedByReporter {
 						ensure!(!is_reporter, Error::<T>::UserCannotAcceptVerdict);
 					} else if report.status == ReportStatus::AppealedByReportee {
@@ -1063,21 +1880,19 @@ pub mod pallet {
 				
 
 				pub fn do_get_reporter(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 				) -> Result<T::AccountId, DispatchError> {
 
-					let report = Reports::<T>::get((content_id.clone(), content_type)).ok_or(Error::<T>::NonexistentReport)?;
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
 					Ok(report.reporter_id) // return (reporter/reportee's reward, majority voter rewards per capita)
 				}
 
 				
 				pub fn do_get_reportee(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 				) -> Result<T::AccountId, DispatchError> {
 
-					let report = Reports::<T>::get((content_id.clone(), content_type)).ok_or(Error::<T>::NonexistentReport)?;
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
 					Ok(report.reportee_id) // return (reporter/reportee's reward, majority voter rewards per capita)
 				}
 
@@ -1114,23 +1929,550 @@ pub mod pallet {
 				}
 
 
+				// Closes a report after its verdict was accepted by the reporter (`is_reporter`) or the
+				// reportee, paying the majority voters, the winning party and slashing the minority.
+				pub fn do_accept_verdict(
+					report_id: ReportId,
+					is_reporter: bool,
+					consensus: ReportStatus,
+				) -> Result<ReportStatus, DispatchError> {
+
+					let tier_data = Self::do_get_current_report_tier_data(report_id)?;
+					let reporter_id = Self::do_get_reporter(report_id)?;
+					let reportee_id = Self::do_get_reportee(report_id)?;
+					let report_status = Self::do_get_report_status_on_accept(is_reporter)?;
+					
+					if !is_reporter {
+						let reportee_slash = Self::do_convert_collateral_to_balance()?;
+						Self::do_grab_reportee_collateral(report_id, reportee_slash)?;
+					}
+
+					for tier in tier_data.3 { // iterate all existing tiers
+						let report_voters = Self::do_get_report_voters_by_vote(report_id, tier, consensus)?;
+						
+						let reward_pool = Self::do_get_total_moderation_pool(report_id, tier)?;
+						// a verdict reached at the deadline may have no majority voters to pay
+						if !report_voters.0.is_empty() {
+							let majority_voter_reward = Self::do_calculate_majority_voter_reward(reward_pool, report_voters.0.len() as u32)?;
+							Self::do_distribute_rewards_to_majority_voters(report_voters.0, majority_voter_reward)?;
+						}
+						Self::do_slash_minority_voters(report_voters.1)?;
+						
+						if is_reporter {
+							let reportee_reward = Self::do_calculate_reportee_reward(reward_pool)?;
+							kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+								reportee_id.clone(), 
+								kine_stat_tracker::FeatureType::Moderation,
+								kine_stat_tracker::TokenType::Claimable,
+								reportee_reward, false,
+							)?;
+							
+						}
+						else {
+							let reporter_reward = Self::do_calculate_reporter_reward(reward_pool)?;
+							kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+								reporter_id.clone(), 
+								kine_stat_tracker::FeatureType::Moderation,
+								kine_stat_tracker::TokenType::Claimable,
+								reporter_reward, false,
+							)?;
+							
+						}
+					} 
+					Self::do_settle_co_signers(report_id, report_status)?;
+					if report_status == ReportStatus::Accepted {
+						let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+						Self::do_suspend_reported_content(report.content);
+					}
+					Self::deposit_event(Event::ReportClosed(report_id, report_status));
+					Ok(report_status)
+				}
+
+
+
+			//* Content *//
+
+				// Hides the content of an accepted report. The verdict stands even if the
+				// content is gone or can't be suspended, in which case nothing is changed.
+				pub fn do_suspend_reported_content(
+					content: ContentRefOf<T>,
+				) {
+
+					let suspended = frame_support::storage::with_storage_layer(|| {
+						T::ContentModerator::suspend_content(&content)
+					});
+					match suspended {
+						Ok(()) => Self::deposit_event(Event::ContentSuspended(content)),
+						Err(_) => Self::deposit_event(Event::ContentSuspensionFailed(content)),
+					}
+				}
+
+
+				// Restores the content of a reversed report, on the same terms as its suspension.
+				pub fn do_reinstate_reported_content(
+					content: ContentRefOf<T>,
+				) {
+
+					let reinstated = frame_support::storage::with_storage_layer(|| {
+						T::ContentModerator::reinstate_content(&content)
+					});
+					match reinstated {
+						Ok(()) => Self::deposit_event(Event::ContentReinstated(content)),
+						Err(_) => Self::deposit_event(Event::ContentReinstatementFailed(content)),
+					}
+				}
+
+
+				// The reportee of a content. Content without an owner, like tags or the
+				// ranking lists from the genesis, is reported against the pallet itself.
+				pub fn do_get_content_owner(
+					content: &ContentRefOf<T>,
+				) -> Result<T::AccountId, DispatchError> {
+
+					let owner = T::ContentOwners::content_owner(content)?;
+					Ok(owner.unwrap_or_else(|| Self::account_id()))
+				}
+
+
+
+			//* Final Appeal *//
+
+				pub fn do_record_final_decision(
+					action: FinalAction,
+					content: ContentRefOf<T>,
+					justification: BoundedVec<u8, T::JustificationLimit>,
+				) -> Result<DecisionId, DispatchError> {
+
+					let decision_id =
+						NextDecisionId::<T>::try_mutate(|id| -> Result<DecisionId, DispatchError> {
+							let current_id = *id;
+							*id = id
+								.checked_add(One::one())
+								.ok_or(Error::<T>::Overflow)?;
+							Ok(current_id)
+						})?;
+
+					FinalDecisions::<T>::insert(decision_id, FinalDecision {
+						action: action,
+						content: content,
+						justification: justification,
+						decided_at: <frame_system::Pallet<T>>::block_number(),
+					});
+					Ok(decision_id)
+				}
+
+
+				// Takes back what a closed report paid out and pays it out again for the opposite verdict:
+				// voters, the winning party, the reportee's collateral and the co-signers' stakes.
+				// Rewards that were already claimed can't be taken back, so the new winners are only
+				// paid what was actually recovered. Appeal fees stay where they were paid.
+				pub fn do_reverse_report_payouts(
+					report_id: ReportId,
+					previous_status: ReportStatus,
+				) -> DispatchResult {
+
+					let reporter_id = Self::do_get_reporter(report_id)?;
+					let reportee_id = Self::do_get_reportee(report_id)?;
+					let was_accepted = previous_status == ReportStatus::Accepted;
+					let previous_consensus = 
+						if was_accepted { ReportStatus::MajorityVotedFor } 
+						else { ReportStatus::MajorityVotedAgainst };
+
+					for tier in Self::do_get_current_report_tier_data(report_id)?.3 {
+						let reward_pool = Self::do_get_total_moderation_pool(report_id, tier)?;
+						let (previous_majority, previous_minority) = 
+							Self::do_get_report_voters_by_vote(report_id, tier, previous_consensus)?;
+						let (previous_winner, new_winner, previous_winner_reward, new_winner_reward) =
+							if was_accepted { 
+								(reporter_id.clone(), reportee_id.clone(), 
+								Self::do_calculate_reporter_reward(reward_pool)?, Self::do_calculate_reportee_reward(reward_pool)?)
+							}
+							else {
+								(reportee_id.clone(), reporter_id.clone(), 
+								Self::do_calculate_reportee_reward(reward_pool)?, Self::do_calculate_reporter_reward(reward_pool)?)
+							};
+
+						// undo the previous outcome, keeping track of what could be recovered
+						let mut recovered = BalanceOf::<T>::from(0u32);
+						if !previous_majority.is_empty() {
+							let majority_voter_reward = Self::do_calculate_majority_voter_reward(reward_pool, previous_majority.len() as u32)?;
+							for moderator_id in previous_majority.iter() {
+								recovered = recovered.saturating_add(Self::do_claw_back_claimable(moderator_id.clone(), majority_voter_reward)?);
+								T::Reputation::update_reputation(moderator_id, ReputationDomain::Moderation, 3u32, true);
+							}
+						}
+						recovered = recovered.saturating_add(Self::do_claw_back_claimable(previous_winner, previous_winner_reward)?);
+						Self::do_refund_slashed_moderators(previous_minority.clone())?;
+
+						// the previous minority is now the majority, every new reward is
+						// scaled down by the share of the previous ones that was recovered
+						let new_majority_voter_reward = 
+							if previous_minority.is_empty() { BalanceOf::<T>::from(0u32) }
+							else { Self::do_calculate_majority_voter_reward(reward_pool, previous_minority.len() as u32)? };
+						let new_rewards = 
+							new_majority_voter_reward
+							.saturating_mul((previous_minority.len() as u32).into())
+							.saturating_add(new_winner_reward);
+						let recovered_share = Perbill::from_rational(recovered, new_rewards);
+
+						if !previous_minority.is_empty() {
+							Self::do_distribute_rewards_to_majority_voters(previous_minority, recovered_share.mul_floor(new_majority_voter_reward))?;
+						}
+						Self::do_slash_minority_voters(previous_majority)?;
+
+						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+							new_winner, 
+							kine_stat_tracker::FeatureType::Moderation,
+							kine_stat_tracker::TokenType::Claimable,
+							recovered_share.mul_floor(new_winner_reward), false,
+						)?;
+					}
+
+					// the reportee's collateral follows the verdict
+					let collateral = Self::do_convert_collateral_to_balance()?;
+					if was_accepted {
+						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+							reportee_id.clone(), 
+							kine_stat_tracker::FeatureType::Moderation,
+							kine_stat_tracker::TokenType::Claimable,
+							collateral, false,
+						)?;
+					}
+					else {
+						Self::do_grab_reportee_collateral(report_id, collateral)?;
+					}
+
+					// and so do the co-signers' stakes, as far as they are still claimable
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+					for co_signer in report.co_signers {
+						let (previous_receiver, new_receiver) = 
+							if was_accepted { (co_signer.co_signer_id, reportee_id.clone()) }
+							else { (reportee_id.clone(), co_signer.co_signer_id) };
+						let recovered_stake = Self::do_claw_back_claimable(previous_receiver, co_signer.staked_tokens)?;
+						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+							new_receiver, 
+							kine_stat_tracker::FeatureType::Moderation,
+							kine_stat_tracker::TokenType::Claimable,
+							recovered_stake, false,
+						)?;
+					}
+
+					Ok(())
+				}
+
+
+				// Removes up to `amount` from the claimable moderation tokens of an account.
+				// Returns the amount that was actually removed.
+				pub fn do_claw_back_claimable(
+					who: T::AccountId,
+					amount: BalanceOf<T>,
+				) -> Result<BalanceOf<T>, DispatchError> {
+
+					let claimable_tokens = 
+						kine_stat_tracker::WalletTokens::<T>::get(who.clone())
+						.map(|wallet_tokens| wallet_tokens.claimable_tokens_moderation)
+						.unwrap_or_default();
+					let recovered = amount.min(claimable_tokens);
+					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+						who, 
+						kine_stat_tracker::FeatureType::Moderation,
+						kine_stat_tracker::TokenType::Claimable,
+						recovered, true,
+					)?;
+					Ok(recovered)
+				}
+
+
+				// Gives back the fee and reputation slashed from moderators. Those who
+				// already withdrew their bond get the fee as claimable tokens.
+				pub fn do_refund_slashed_moderators(
+					moderators: Vec<T::AccountId>,
+				) -> DispatchResult {
+
+					let moderator_fee = Self::do_calculate_moderator_fee()?;
+					for moderator_id in moderators {
+						let token_type = 
+							if Moderators::<T>::contains_key(moderator_id.clone()) {
+								Moderators::<T>::try_mutate_exists(moderator_id.clone(), |mod_data| -> DispatchResult {
+									let moderator_data = mod_data.as_mut().ok_or(Error::<T>::NonexistentModerator)?;
+									moderator_data.bonded_tokens = moderator_data.bonded_tokens.saturating_add(moderator_fee);
+									Ok(())
+								})?;
+								kine_stat_tracker::TokenType::Locked
+							}
+							else { kine_stat_tracker::TokenType::Claimable };
+
+						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+							moderator_id.clone(), 
+							kine_stat_tracker::FeatureType::Moderation,
+							token_type,
+							moderator_fee, false,
+						)?;
+						T::Reputation::update_reputation(&moderator_id, ReputationDomain::Moderation, 3u32, false);
+					}
+
+					Ok(())
+				}
+
+
+
+			//* Deadlines *//
+
+				// Upper bound of resolving one report at its deadline. A tier's jury holds at most
+				// MaxReportsByTier moderators, each read and written a few times, and a new jury
+				// may be drafted.
+				pub fn do_get_resolution_weight() -> Weight {
+					let jury = T::MaxReportsByTier::get() as u64;
+					T::DbWeight::get().reads_writes(
+						15u64.saturating_add(jury.saturating_mul(6)),
+						15u64.saturating_add(jury.saturating_mul(6)),
+					)
+					.saturating_add(Self::do_get_draft_weight())
+					.saturating_add(Self::do_get_verdict_reputation_weight())
+				}
+
+
+				// Upper bound of the reputation updates of a verdict: every moderator of every
+				// tier's jury is either rewarded or slashed once.
+				pub fn do_get_verdict_reputation_weight() -> Weight {
+					let tiers = 3u64;
+					let jurors = tiers.saturating_mul(T::MaxReportsByTier::get() as u64);
+					T::Reputation::update_weight().saturating_mul(jurors)
+				}
+
+
+				// Upper bound of a draft: the report, its parties and every moderator are read, and in
+				// the worst case each moderator is drawn and checked for a conflict of interest.
+				pub fn do_get_draft_weight() -> Weight {
+					let moderators = T::MaxModerators::get() as u64;
+					T::DbWeight::get().reads_writes(moderators.saturating_add(3), 1)
+						.saturating_add(T::ConflictsOfInterest::check_weight().saturating_mul(moderators))
+				}
+
+
+				// Sets the report's deadline `delay` blocks from now, pushed back
+				// block by block while the deadline queue is full.
+				pub fn do_schedule_report_deadline(
+					report_id: ReportId,
+					delay: BlockNumberFor<T>,
+				) -> Result<(), DispatchError> {
+
+					let mut deadline = <frame_system::Pallet<T>>::block_number().saturating_add(delay);
+					for _ in 0..MAX_DEADLINE_DELAY {
+						let is_scheduled = ReportDeadlines::<T>::mutate(deadline, |deadlines| {
+							deadlines.try_push(report_id).is_ok()
+						});
+						if is_scheduled {
+							ReportDeadline::<T>::insert(report_id, deadline);
+							return Ok(());
+						}
+						deadline = deadline.saturating_add(One::one());
+					}
+
+					Err(Error::<T>::TooManyReportDeadlines.into())
+				}
+
+
+				// Moves the reports due in this block to the next one, while they can't be resolved.
+				pub fn do_postpone_report_deadlines(
+					now: BlockNumberFor<T>,
+				) -> Weight {
+
+					let mut weight = T::DbWeight::get().reads_writes(1, 1);
+					for report_id in ReportDeadlines::<T>::take(now) {
+						weight = weight.saturating_add(T::DbWeight::get().reads(1));
+						if ReportDeadline::<T>::get(report_id) != Some(now) {
+							continue;
+						}
+						if Self::do_schedule_report_deadline(report_id, One::one()).is_err() {
+							Self::deposit_event(Event::ReportDeadlineFailed(report_id));
+						}
+						weight = weight.saturating_add(
+							T::DbWeight::get().reads_writes(MAX_DEADLINE_DELAY.into(), 2)
+						);
+					}
+					weight
+				}
+
+
+				// A report reached its deadline: either the voting period of its current tier ended,
+				// or nobody appealed its verdict in time.
+				pub fn do_resolve_expired_report(
+					report_id: ReportId,
+				) -> DispatchResult {
+
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
+					match report.status {
+						ReportStatus::InResolution => Self::do_close_expired_voting(report_id),
+						ReportStatus::AwaitingReveal => Self::do_close_expired_reveal(report_id),
+						ReportStatus::MajorityVotedFor | ReportStatus::MajorityVotedAgainst => {
+							// accepted on behalf of the party the verdict went against
+							let is_reporter = report.status == ReportStatus::MajorityVotedAgainst;
+							let report_status = Self::do_accept_verdict(report_id, is_reporter, report.status)?;
+							Self::do_update_report_status(report_id, report_status)?;
+							Self::deposit_event(Event::VerdictAutoAccepted(report_id));
+							Ok(())
+						},
+						_ => Ok(()),
+					}
+				}
+
+
+				// Penalises the drafted moderators who didn't commit a vote, like a minority voter, and
+				// starts revealing the committed ones. If nobody committed, a new jury is drafted.
+				pub fn do_close_expired_voting(
+					report_id: ReportId,
+				) -> DispatchResult {
+
+					let tier = Self::do_get_current_report_tier_data(report_id)?.0;
+
+					let absent_moderators = Self::do_get_uncommitted_moderators(report_id);
+					for moderator_id in absent_moderators.iter() {
+						Self::do_deallocate_moderator_from_report(moderator_id.clone(), report_id)?;
+					}
+					Self::do_slash_minority_voters(absent_moderators.clone())?;
+					Self::do_record_missed_deadlines(absent_moderators)?;
+
+					if VoteCommitments::<T>::iter_key_prefix(report_id).next().is_some() {
+						return Self::do_start_reveal_phase(report_id);
+					}
+					Self::do_redraft_jury(report_id, tier)
+				}
+
+
+				// Commitments that were never revealed count as absent votes, and the report is
+				// decided with the revealed ones. If nobody revealed, a new jury is drafted.
+				pub fn do_close_expired_reveal(
+					report_id: ReportId,
+				) -> DispatchResult {
+
+					let tier = Self::do_get_current_report_tier_data(report_id)?.0;
+
+					// moderators removed since they committed can't be penalised again
+					let absent_moderators: Vec<T::AccountId> =
+						VoteCommitments::<T>::drain_prefix(report_id)
+						.map(|(moderator_id, _)| moderator_id)
+						.filter(|moderator_id| Moderators::<T>::contains_key(moderator_id))
+						.collect();
+					for moderator_id in absent_moderators.iter() {
+						Self::do_deallocate_moderator_from_report(moderator_id.clone(), report_id)?;
+					}
+					Self::do_slash_minority_voters(absent_moderators.clone())?;
+					Self::do_record_missed_deadlines(absent_moderators)?;
+
+					let verdict = ReportVerdicts::<T>::get(report_id, tier).ok_or(Error::<T>::NonexistentReport)?;
+					if verdict.votes.is_empty() {
+						return Self::do_redraft_jury(report_id, tier);
+					}
+
+					let consensus = Self::do_close_voting(report_id, tier)?;
+					Self::deposit_event(Event::VotingClosedAtDeadline(report_id, consensus));
+					Ok(())
+				}
+
+
+				// Drafts a new jury for a tier nobody voted in. A tier without votes is never decided:
+				// if there aren't enough moderators available, the report stays open and the draft is
+				// retried at the end of another voting period.
+				pub fn do_redraft_jury(
+					report_id: ReportId,
+					tier: Tiers,
+				) -> DispatchResult {
+
+					Self::do_update_report_status(report_id, ReportStatus::InResolution)?;
+					Self::do_schedule_report_deadline(report_id, T::VotingPeriod::get())?;
+					match Self::do_draft_jury(report_id, tier) {
+						Ok(drafted_moderators) => {
+							Self::do_assign_report_to_moderators(report_id, drafted_moderators)?;
+							Self::deposit_event(Event::JuryRedrafted(report_id));
+						},
+						Err(_) => Self::deposit_event(Event::JuryUnavailable(report_id)),
+					}
+					Ok(())
+				}
+
+
 
 			//* Vote *//
+
+				// The hash a moderator commits to. It includes the moderator and the report,
+				// so a commitment can't be copied from another moderator or report.
+				pub fn do_get_vote_commitment(
+					who: T::AccountId,
+					report_id: ReportId,
+					vote: VoteChoice,
+					cited_evidence: BoundedVec<EvidenceId, T::MaxEvidencePerReport>,
+					salt: [u8; 32],
+				) -> T::Hash {
+
+					T::Hashing::hash_of(&(who, report_id, vote, cited_evidence, salt))
+				}
+
+
+				// The drafted moderators of a report that didn't commit a vote yet. Only the
+				// report's jury is read, DraftedAt drops each moderator once it commits.
+				pub fn do_get_uncommitted_moderators(
+					report_id: ReportId,
+				) -> Vec<T::AccountId> {
+
+					DraftedAt::<T>::iter_key_prefix(report_id)
+					.filter(|moderator_id| !VoteCommitments::<T>::contains_key(report_id, moderator_id))
+					.collect()
+				}
+
+
+				// Moves a report to its reveal phase. Drafted moderators who didn't commit
+				// are released from the report, their votes are no longer expected.
+				pub fn do_start_reveal_phase(
+					report_id: ReportId,
+				) -> DispatchResult {
+
+					for moderator_id in Self::do_get_uncommitted_moderators(report_id) {
+						Self::do_deallocate_moderator_from_report(moderator_id, report_id)?;
+					}
+					Self::do_update_report_status(report_id, ReportStatus::AwaitingReveal)?;
+					Self::do_schedule_report_deadline(report_id, T::RevealPeriod::get())?;
+
+					Self::deposit_event(Event::RevealPhaseStarted(report_id));
+					Ok(())
+				}
+
+
+				// Decides the tier with the revealed votes and opens its appeal window.
+				pub fn do_close_voting(
+					report_id: ReportId,
+					tier: Tiers,
+				) -> Result<ReportStatus, DispatchError> {
+
+					ReportVerdicts::<T>::try_mutate_exists(report_id, tier, |report_outcome| -> DispatchResult {
+						let outcome = report_outcome.as_mut().ok_or(Error::<T>::NonexistentReport)?;
+						outcome.required_votes = outcome.votes.len() as u32;
+						Ok(())
+					})?;
+					let consensus = Self::do_calculate_vote_consensus(report_id, tier)?;
+					Self::do_update_report_status(report_id, consensus)?;
+					Self::do_schedule_report_deadline(report_id, T::AppealWindow::get())?;
+					Self::do_record_voting_metrics(report_id, tier, consensus)?;
+
+					Ok(consensus)
+				}
+
 			
 				pub fn do_create_vote(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 					tier: Tiers,
 					who: T::AccountId,
 					is_for: VoteChoice,
+					cited_evidence: BoundedVec<EvidenceId, T::MaxEvidencePerReport>,
 				) -> Result<(), DispatchError> {
 
 					let vote = Vote {
 						voter: who.clone(),
 						is_for: is_for,
+						cited_evidence: cited_evidence,
 					};
 
-					ReportVerdicts::<T>::try_mutate_exists((content_id.clone(), content_type), tier, |report_outcome| -> DispatchResult {
+					ReportVerdicts::<T>::try_mutate_exists(report_id, tier, |report_outcome| -> DispatchResult {
 						let outcome = report_outcome.as_mut().ok_or(Error::<T>::NonexistentReport)?;
 						outcome.votes.try_push(vote).unwrap();
 						if is_for == VoteChoice::For { 
@@ -1145,13 +2487,12 @@ pub mod pallet {
 				
 				
 				pub fn do_are_all_votes_submitted(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 					tier: Tiers,
 				) -> Result<bool, DispatchError> {
 
 					let mut are_all_votes_submitted = false;
-					let verdict = ReportVerdicts::<T>::get((content_id.clone(), content_type), tier).ok_or(Error::<T>::NonexistentReport)?;
+					let verdict = ReportVerdicts::<T>::get(report_id, tier).ok_or(Error::<T>::NonexistentReport)?;
 					if verdict.votes.len() == (verdict.required_votes as usize) {are_all_votes_submitted = true;}
 
 					Ok(are_all_votes_submitted)
@@ -1159,13 +2500,12 @@ pub mod pallet {
 				
 
 				pub fn do_calculate_vote_consensus(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 					tier: Tiers,
 				) -> Result<ReportStatus, DispatchError> {
 					
 					let mut result = ReportStatus::MajorityVotedAgainst;
-					let vote_consensus = ReportVerdicts::<T>::get((content_id.clone(), content_type), tier).ok_or(Error::<T>::NonexistentReport)?;
+					let vote_consensus = ReportVerdicts::<T>::get(report_id, tier).ok_or(Error::<T>::NonexistentReport)?;
 					if (vote_consensus.votes_for * 1000) > ((vote_consensus.required_votes * 1000) / 2) as u32  { 
 						result = ReportStatus::MajorityVotedFor;
 					}
@@ -1175,13 +2515,12 @@ pub mod pallet {
 
 
 				pub fn do_get_report_voters_by_vote(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 					tier: Tiers,
 					consensus: ReportStatus,
 				) -> Result<(Vec<T::AccountId>, Vec<T::AccountId>), DispatchError> {
 					
-					let mut majority_votes = ReportVerdicts::<T>::try_get((content_id.clone(), content_type), tier).unwrap().votes.clone();
+					let mut majority_votes = ReportVerdicts::<T>::try_get(report_id, tier).unwrap().votes.clone();
 					let mut minority_votes = majority_votes.clone();
 
 					if consensus == ReportStatus::MajorityVotedFor || consensus == ReportStatus::AppealedByReportee {
@@ -1202,8 +2541,170 @@ pub mod pallet {
 
 
 
+			//* Metrics *//
+
+				// Called when a moderator commits its vote, keeps its median response time up to date.
+				pub fn do_record_response_time(
+					who: T::AccountId,
+					report_id: ReportId,
+				) -> DispatchResult {
+
+					let Some(drafted_at) = DraftedAt::<T>::take(report_id, who.clone()) else { return Ok(()) };
+					let response_time = <frame_system::Pallet<T>>::block_number().saturating_sub(drafted_at);
+
+					ModeratorStats::<T>::mutate(who, |stats| {
+						if stats.response_times.len() as u32 >= T::MaxResponseTimes::get() && !stats.response_times.is_empty() {
+							stats.response_times.remove(0);
+						}
+						if stats.response_times.try_push(response_time).is_err() {
+							return;
+						}
+
+						let mut sorted_times = stats.response_times.to_vec();
+						sorted_times.sort();
+						let middle = sorted_times.len() / 2;
+						stats.median_response_time = 
+							if sorted_times.len() % 2 == 1 { sorted_times[middle] }
+							else { sorted_times[middle - 1].saturating_add(sorted_times[middle]) / 2u32.into() };
+					});
+					Ok(())
+				}
+
+
+				// Counts the revealed votes of a closed tier, and whether they agreed with the majority.
+				pub fn do_record_voting_metrics(
+					report_id: ReportId,
+					tier: Tiers,
+					consensus: ReportStatus,
+				) -> DispatchResult {
+
+					let (majority_voters, minority_voters) = Self::do_get_report_voters_by_vote(report_id, tier, consensus)?;
+					for (voters, is_majority) in [(majority_voters, true), (minority_voters, false)] {
+						for moderator_id in voters {
+							ModeratorStats::<T>::mutate(moderator_id.clone(), |stats| {
+								stats.reports_handled = stats.reports_handled.saturating_add(1);
+								if is_majority {
+									stats.majority_votes = stats.majority_votes.saturating_add(1);
+								}
+							});
+							Self::do_check_moderator_metrics(moderator_id)?;
+						}
+					}
+					Ok(())
+				}
+
+
+				pub fn do_record_missed_deadlines(
+					moderators: Vec<T::AccountId>,
+				) -> DispatchResult {
+
+					for moderator_id in moderators {
+						ModeratorStats::<T>::mutate(moderator_id.clone(), |stats| {
+							stats.missed_deadlines = stats.missed_deadlines.saturating_add(1);
+						});
+						Self::do_check_moderator_metrics(moderator_id)?;
+					}
+					Ok(())
+				}
+
+
+				pub fn do_get_majority_agreement(
+					who: T::AccountId,
+				) -> Percent {
+
+					let stats = ModeratorStats::<T>::get(who);
+					Percent::from_rational(stats.majority_votes, stats.reports_handled.max(1))
+				}
+
+
+				// Removes moderators that missed too many deadlines. Those who disagree with the majority
+				// too often are demoted, or removed if they are juniors.
+				pub fn do_check_moderator_metrics(
+					who: T::AccountId,
+				) -> DispatchResult {
+
+					let Some(moderator) = Moderators::<T>::get(who.clone()) else { return Ok(()) };
+					if moderator.status != ModeratorStatus::Active {
+						return Ok(());
+					}
+
+					let stats = ModeratorStats::<T>::get(who.clone());
+					let is_missing_deadlines = stats.missed_deadlines >= T::MaxMissedDeadlines::get();
+					let is_disagreeing = 
+						stats.reports_handled >= T::MetricsMinimumReports::get()
+						&& Self::do_get_majority_agreement(who.clone()) < T::MinimumMajorityAgreement::get();
+					if !is_missing_deadlines && !is_disagreeing {
+						return Ok(());
+					}
+
+					Self::deposit_event(Event::ModeratorMetricsBreached(who.clone()));
+					if !is_missing_deadlines && moderator.rank == ModeratorRank::Senior {
+						Moderators::<T>::try_mutate_exists(who.clone(), |mod_data| -> DispatchResult {
+							let moderator_data = mod_data.as_mut().ok_or(Error::<T>::NonexistentModerator)?;
+							Self::do_demote_moderator(who.clone(), moderator_data)
+						})
+					}
+					else {
+						Self::do_start_unbonding(who)
+					}
+				}
+
+
+				pub fn do_demote_moderator(
+					who: T::AccountId,
+					moderator_data: &mut Moderator<
+						BoundedVec<ReportId, T::MaxReportsByModerator>,
+						ModeratorRank,
+						ModeratorStatus<BlockNumberFor<T>>,
+						BalanceOf<T>,
+					>,
+				) -> DispatchResult {
+
+					// seniors promoted before the seniority bond existed never bonded one,
+					// and slashes are taken from the seniority bond first
+					let seniority_bond = 
+						moderator_data.bonded_tokens
+						.saturating_sub(T::MinimumTokensForModeration::get())
+						.min(T::MinimumTokensForSeniority::get());
+
+					moderator_data.rank = ModeratorRank::Junior;
+					moderator_data.bonded_tokens = moderator_data.bonded_tokens.saturating_sub(seniority_bond);
+					Self::do_release_seniority_bond(who.clone(), seniority_bond)?;
+					Self::deposit_event(Event::ModeratorDemoted(who));
+					Ok(())
+				}
+
+
+
 			//* Treasury *//
 
+				// A demoted senior gets what is left of its seniority bond back as claimable tokens.
+				pub fn do_release_seniority_bond(
+					moderator_id: T::AccountId,
+					seniority_bond: BalanceOf<T>,
+				) -> Result<(), DispatchError> {
+
+					if seniority_bond.is_zero() {
+						return Ok(());
+					}
+
+					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+						moderator_id.clone(), 
+						kine_stat_tracker::FeatureType::Moderation,
+						kine_stat_tracker::TokenType::Locked,
+						seniority_bond, true,
+					)?;
+					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+						moderator_id, 
+						kine_stat_tracker::FeatureType::Moderation,
+						kine_stat_tracker::TokenType::Claimable,
+						seniority_bond, false,
+					)?;
+
+					Ok(())
+				}
+
+
 				pub fn do_transfer_funds_to_treasury(
 					who: T::AccountId,
 					amount: BalanceOf<T>,
@@ -1212,7 +2713,7 @@ pub mod pallet {
 					<T as kine_stat_tracker::Config>::Currency::transfer(
 						&who, &Self::account_id(),
 						amount, AllowDeath, 
-					);
+					)?;
 
 					Ok(()) 
 				}
@@ -1226,19 +2727,18 @@ pub mod pallet {
 					<T as kine_stat_tracker::Config>::Currency::transfer(
 						&Self::account_id(),  &who,
 						amount, AllowDeath, 
-					);
+					)?;
 
 					Ok(()) 
 				}
 			
 
 				pub fn do_grab_reportee_collateral(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 					collateral: BalanceOf<T>,
 				) -> Result<(), DispatchError> {
 
-					let report = Reports::<T>::get((content_id.clone(), content_type)).ok_or(Error::<T>::NonexistentReport)?;
+					let report = Reports::<T>::get(report_id).ok_or(Error::<T>::NonexistentReport)?;
 					let reportee = report.reportee_id;
 
 					let treasury = &Self::account_id();
@@ -1283,12 +2783,11 @@ pub mod pallet {
 			//* Reward Distribution *//
 
 				pub fn do_get_total_moderation_pool(
-					content_id: T::ContentId,
-					content_type: ContentType,
+					report_id: ReportId,
 					tier: Tiers,
 				) -> Result<BalanceOf<T>, DispatchError> {
 
-					let report_outcome = ReportVerdicts::<T>::get((content_id.clone(), content_type), tier).ok_or(Error::<T>::NonexistentReport)?;
+					let report_outcome = ReportVerdicts::<T>::get(report_id, tier).ok_or(Error::<T>::NonexistentReport)?;
 					Ok(report_outcome.staked_tokens) // return (reporter/reportee's reward, majority voter rewards per capita)
 				}
 			
@@ -1307,19 +2806,8 @@ pub mod pallet {
 							reward, false,
 						).unwrap();
 						
-						let new_reputation = kine_stat_tracker::Pallet::<T>::do_calculate_reputation_change(
-							moderator_id.clone(), 
-							3u32, false,
-						)?;
-						
-						if new_reputation >= T::MinimumReputationForSeniorship::get()  {
-							Moderators::<T>::try_mutate_exists(moderator_id, |mod_data| -> DispatchResult {
-								let moderator_data = mod_data.as_mut().ok_or(Error::<T>::NonexistentModerator)?;
-								moderator_data.rank = ModeratorRank::Senior;
-	
-								Ok(())
-							})?;
-						}
+						// seniority is applied for with apply_for_seniority
+						T::Reputation::update_reputation(moderator_id, ReputationDomain::Moderation, 3u32, false);
 						
 					}
 
@@ -1333,11 +2821,16 @@ pub mod pallet {
 					let moderator_fee: BalanceOf<T> = Self::do_calculate_moderator_fee()?;
 
 					for moderator_id in minority_voters.iter() {
+						// moderators that already withdrew their bond can't be slashed
+						if !Moderators::<T>::contains_key(moderator_id) {
+							continue;
+						}
 						
-						let suspend_data = Moderators::<T>::try_mutate_exists(moderator_id, |mod_data| 
-						-> Result<(bool, BoundedVec<T::ContentId, T::MaxReportsByModerator>), DispatchError> {
+						let should_remove = Moderators::<T>::try_mutate_exists(moderator_id, |mod_data| 
+						-> Result<bool, DispatchError> {
 							let moderator_data = mod_data.as_mut().ok_or(Error::<T>::NonexistentModerator)?;
 							let mut should_remove = false;
+							moderator_data.bonded_tokens = moderator_data.bonded_tokens.saturating_sub(moderator_fee);
 
 							kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
 								moderator_id.clone(), 
@@ -1346,33 +2839,26 @@ pub mod pallet {
 								moderator_fee, true
 							).unwrap();
 							
-							let new_reputation = kine_stat_tracker::Pallet::<T>::do_calculate_reputation_change(
-								moderator_id.clone(), 
-								3u32, true,
-							)?;
+							let moderation_reputation = 
+								T::Reputation::update_reputation(moderator_id, ReputationDomain::Moderation, 3u32, true);
 							
 							// suspend moderation
-							if new_reputation < T::MinimumReputationForModeration::get() {
+							if T::Reputation::total_reputation(moderator_id) < T::MinimumReputationForModeration::get() 
+							&& moderator_data.status == ModeratorStatus::Active {
 								should_remove = true;
 							}
 							// demote to junior if senior
-							else if new_reputation < T::MinimumReputationForSeniorship::get() 
+							else if moderation_reputation < T::MinimumReputationForSeniorship::get() 
 							&& moderator_data.rank == ModeratorRank::Senior {
-								moderator_data.rank = ModeratorRank::Junior;
+								Self::do_demote_moderator(moderator_id.clone(), moderator_data)?;
 							}
 
-							Ok((should_remove, moderator_data.assigned_reports.clone()))
+							Ok(should_remove)
 						})?;
 
-						if suspend_data.0 { // should_suspend
-							for report_id in suspend_data.1 {
-								//TODO reallocate all the pending reports, 
-							}
-							
-							Self::do_suspend_moderation(moderator_id.clone())?;
+						if should_remove {
+							Self::do_start_unbonding(moderator_id.clone())?;
 						}
-
-
 					}
 					Ok(())
 				}
@@ -1462,6 +2948,18 @@ pub mod pallet {
 				}
 
 
+				// The panel size of a tier, bounded by the votes a tier can hold.
+				pub fn do_get_moderators_in_tier(
+					tier: Tiers,
+				) -> Result<u32, DispatchError> {
+
+					match ModeratorsPerTier::<T>::get(tier) {
+						Some(total_moderators) => Ok(total_moderators),
+						None => Ok(Self::do_calculate_moderators_in_tier(tier)?.min(T::MaxReportsByTier::get())),
+					}
+				}
+
+
 				pub fn do_calculate_moderators_in_tier(
 					tier: Tiers,
 				) -> Result<u32, DispatchError> {
@@ -1506,7 +3004,7 @@ pub mod pallet {
 
 				// The account ID of the vault
 				fn account_id() -> T::AccountId {
-					<T as Config>::PalletId::get().try_into_account().unwrap()
+					<T as Config>::PalletId::get().into_account_truncating()
 				}
 		
 		}
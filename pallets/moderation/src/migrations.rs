@@ -0,0 +1,417 @@
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::IterableStorageMap,
+	traits::{Currency, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use kine_tags::{CategoryId, TagId};
+use sp_runtime::traits::Saturating;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+type BalanceOf<T> = <<T as kine_stat_tracker::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+// Migrations are paged: the runtime upgrade migrates the first page and stores the stage
+// it got to, `on_initialize` then migrates a page per block until the stage is gone. Report
+// deadlines due meanwhile are pushed back, calls are refused by the runtime.
+pub const MIGRATION_PAGE_SIZE: u32 = 50;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum MigrationStage {
+	// The old reports share their prefix with the new ones and are moved out of the way first.
+	MovingReports,
+	ConvertingReports,
+	// The raw key of the last translated moderator, empty before the first one.
+	ConvertingModerators(Vec<u8>),
+	// The cursor of the last clearing page, empty before the first one.
+	ClearingOpenReports(Vec<u8>),
+}
+
+#[frame_support::storage_alias]
+pub type MigrationStageOf<T: Config> = StorageValue<Pallet<T>, MigrationStage>;
+
+// Reports can't be touched while they, or the wallet tokens they update, are migrated.
+pub fn is_migrating<T: Config>() -> bool {
+	MigrationStageOf::<T>::exists() || kine_stat_tracker::migrations::is_migrating::<T>()
+}
+
+pub fn migrate_page<T: Config>() -> Weight {
+	let stage = match MigrationStageOf::<T>::get() {
+		Some(stage) => stage,
+		None => return T::DbWeight::get().reads(1),
+	};
+	// dropped reports are refunded as wallet tokens, which must be translated first
+	if kine_stat_tracker::migrations::is_migrating::<T>() {
+		return T::DbWeight::get().reads(2)
+	}
+
+	let (next_stage, weight) = v1::migrate_page::<T>(stage, MIGRATION_PAGE_SIZE);
+	match next_stage {
+		Some(stage) => MigrationStageOf::<T>::put(stage),
+		None => {
+			StorageVersion::new(1).put::<Pallet<T>>();
+			MigrationStageOf::<T>::kill();
+		},
+	}
+	weight.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+}
+
+// Layout of the reports before they had their own ids.
+pub mod v0 {
+	use super::*;
+
+	#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub enum OldContentType {
+		Festival,
+		Movie,
+		Tag,
+		RankingList,
+		SocialSpace,
+	}
+
+	// AwaitingReveal didn't exist yet
+	#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub enum OldReportStatus {
+		InResolution,
+		MajorityVotedFor,
+		MajorityVotedAgainst,
+		AppealedByReporter,
+		AppealedByReportee,
+		Accepted,
+		Refused,
+	}
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldReport<AccountId, BoundedString, CategoryTagList> {
+		pub reporter_id: AccountId,
+		pub reportee_id: AccountId,
+		pub justification: BoundedString,
+		pub status: OldReportStatus,
+		pub categories_and_tags: CategoryTagList,
+	}
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldVote<AccountId> {
+		pub voter: AccountId,
+		pub is_for: VoteChoice,
+	}
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldModerator<ReportList> {
+		pub assigned_reports: ReportList,
+		pub rank: ModeratorRank,
+	}
+
+	pub type OldReportOf<T> = OldReport<
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<u8, <T as Config>::JustificationLimit>,
+		BoundedVec<(CategoryId<T>, TagId<T>), <T as kine_tags::Config>::MaxTags>,
+	>;
+
+	pub type OldReportOutcomeOf<T> = ReportOutcome<
+		BoundedVec<OldVote<<T as frame_system::Config>::AccountId>, <T as Config>::MaxReportsByTier>,
+		BalanceOf<T>,
+	>;
+
+	pub type OldModeratorOf<T> = OldModerator<
+		BoundedVec<<T as Config>::ContentId, <T as Config>::MaxReportsByModerator>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type Reports<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat, (<T as Config>::ContentId, OldContentType),
+		OldReportOf<T>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type ReportVerdicts<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat, (<T as Config>::ContentId, OldContentType),
+		Blake2_128Concat, Tiers,
+		OldReportOutcomeOf<T>,
+	>;
+
+	impl From<OldReportStatus> for ReportStatus {
+		fn from(status: OldReportStatus) -> Self {
+			match status {
+				OldReportStatus::InResolution => ReportStatus::InResolution,
+				OldReportStatus::MajorityVotedFor => ReportStatus::MajorityVotedFor,
+				OldReportStatus::MajorityVotedAgainst => ReportStatus::MajorityVotedAgainst,
+				OldReportStatus::AppealedByReporter => ReportStatus::AppealedByReporter,
+				OldReportStatus::AppealedByReportee => ReportStatus::AppealedByReportee,
+				OldReportStatus::Accepted => ReportStatus::Accepted,
+				OldReportStatus::Refused => ReportStatus::Refused,
+			}
+		}
+	}
+}
+
+// Reports used to be keyed by (content id, content type), and moderators only
+// tracked the content ids they were drafted for. This gives every report a
+// ReportId, converts the reports, verdicts and moderators to their current
+// layout and schedules the deadlines of the reports that are still open.
+// Movies and tags can't be referenced by a content id anymore, so their
+// reports are dropped, and the reporters of the open ones get their stake back.
+pub mod v1 {
+	use super::*;
+	use v0::{OldContentType, OldModeratorOf};
+
+	fn content_ref<T: Config>(content_id: T::ContentId, content_type: OldContentType) -> Option<ContentRefOf<T>> {
+		match content_type {
+			OldContentType::Festival => Some(ContentRef::Festival(content_id)),
+			OldContentType::RankingList => Some(ContentRef::RankingList(content_id)),
+			OldContentType::SocialSpace => Some(ContentRef::Community(content_id)),
+			OldContentType::Movie | OldContentType::Tag => None,
+		}
+	}
+
+	// The old reports while they are converted, out of the way of the new ones.
+	#[frame_support::storage_alias]
+	pub type MigratingReports<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat, (<T as Config>::ContentId, OldContentType),
+		v0::OldReportOf<T>,
+	>;
+
+	// The open reports of each content id, to find the reports moderators were drafted for.
+	#[frame_support::storage_alias]
+	pub type OpenReports<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat, <T as Config>::ContentId,
+		Vec<ReportId>,
+		ValueQuery,
+	>;
+
+	// Addresses the map of the counted `Moderators`, which can't be translated a key at a time.
+	#[frame_support::storage_alias]
+	pub type Moderators<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat, <T as frame_system::Config>::AccountId,
+		Moderator<
+			BoundedVec<ReportId, <T as Config>::MaxReportsByModerator>,
+			ModeratorRank,
+			ModeratorStatus<BlockNumberFor<T>>,
+			BalanceOf<T>,
+		>,
+	>;
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 0 || MigrationStageOf::<T>::exists() {
+				return T::DbWeight::get().reads(2)
+			}
+
+			MigrationStageOf::<T>::put(MigrationStage::MovingReports);
+			super::migrate_page::<T>()
+		}
+	}
+
+	// Migrates up to `limit` reports or moderators from `stage` on. Returns the stage
+	// to resume from, `None` once everything is migrated, and the weight used.
+	pub fn migrate_page<T: Config>(mut stage: MigrationStage, limit: u32) -> (Option<MigrationStage>, Weight) {
+		let mut remaining = limit as usize;
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+
+		while remaining > 0 {
+			match stage {
+				MigrationStage::MovingReports => {
+					let mut moved = 0;
+					for (old_key, old) in v0::Reports::<T>::drain().take(remaining) {
+						MigratingReports::<T>::insert(old_key, old);
+						moved += 1;
+					}
+					reads = reads.saturating_add(moved as u64);
+					writes = writes.saturating_add(2 * moved as u64);
+					remaining = remaining.saturating_sub(moved);
+					if remaining > 0 {
+						stage = MigrationStage::ConvertingReports;
+					}
+				},
+				MigrationStage::ConvertingReports => {
+					let report_stake = Pallet::<T>::do_calculate_report_pool(T::TotalTierOneModerators::get())
+						.map(|reward_pool| reward_pool.0)
+						.unwrap_or_default();
+					reads = reads.saturating_add(1);
+
+					let mut converted = 0;
+					for ((content_id, content_type), old) in MigratingReports::<T>::drain().take(remaining) {
+						let (report_reads, report_writes) = convert_report::<T>(content_id, content_type, old, report_stake);
+						reads = reads.saturating_add(report_reads.saturating_add(1));
+						writes = writes.saturating_add(report_writes.saturating_add(1));
+						converted += 1;
+					}
+					remaining = remaining.saturating_sub(converted);
+					if remaining > 0 {
+						stage = MigrationStage::ConvertingModerators(Vec::new());
+					}
+				},
+				MigrationStage::ConvertingModerators(cursor) => {
+					let mut previous_key = if cursor.is_empty() { None } else { Some(cursor) };
+					// moderators start active, with the minimum bond they paid to register, and
+					// count as drafted now for the reports they are still assigned to
+					let now = frame_system::Pallet::<T>::block_number();
+					while remaining > 0 {
+						previous_key = Moderators::<T>::translate_next::<OldModeratorOf<T>, _>(previous_key, |moderator_id, old| {
+							reads = reads.saturating_add(1);
+							writes = writes.saturating_add(1);
+
+							let assigned_reports: BoundedVec<ReportId, T::MaxReportsByModerator> = BoundedVec::truncate_from(
+								old.assigned_reports.iter()
+								.flat_map(|content_id| OpenReports::<T>::get(content_id))
+								.collect()
+							);
+							reads = reads.saturating_add(old.assigned_reports.len() as u64);
+							for report_id in assigned_reports.iter() {
+								DraftedAt::<T>::insert(report_id, &moderator_id, now);
+								writes = writes.saturating_add(1);
+							}
+
+							Some(Moderator {
+								assigned_reports: assigned_reports,
+								rank: old.rank,
+								status: ModeratorStatus::Active,
+								bonded_tokens: T::MinimumTokensForModeration::get(),
+							})
+						});
+						if previous_key.is_none() {
+							break
+						}
+						remaining = remaining.saturating_sub(1);
+					}
+					stage = match previous_key {
+						Some(key) => MigrationStage::ConvertingModerators(key),
+						None => MigrationStage::ClearingOpenReports(Vec::new()),
+					};
+				},
+				MigrationStage::ClearingOpenReports(cursor) => {
+					let maybe_cursor = if cursor.is_empty() { None } else { Some(cursor.as_slice()) };
+					let result = OpenReports::<T>::clear(remaining as u32, maybe_cursor);
+					writes = writes.saturating_add(result.unique.into());
+					let weight = T::DbWeight::get().reads_writes(reads, writes);
+					return (result.maybe_cursor.map(MigrationStage::ClearingOpenReports), weight)
+				},
+			}
+		}
+
+		(Some(stage), T::DbWeight::get().reads_writes(reads, writes))
+	}
+
+	// Gives a report its ReportId, or drops it when its content can't be referenced by an
+	// id anymore. Returns the reads and writes made.
+	fn convert_report<T: Config>(
+		content_id: T::ContentId,
+		content_type: OldContentType,
+		old: v0::OldReportOf<T>,
+		report_stake: BalanceOf<T>,
+	) -> (u64, u64) {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let old_key = (content_id, content_type);
+		let old_verdicts = [Tiers::TierOne, Tiers::TierTwo, Tiers::TierThree]
+			.map(|tier| (tier, v0::ReportVerdicts::<T>::take(old_key, tier)));
+		reads = reads.saturating_add(3);
+		writes = writes.saturating_add(3);
+
+		let Some(content) = content_ref::<T>(content_id, content_type) else {
+			frame_support::log::warn!(
+				target: "runtime::moderation",
+				"dropping the report of {:?} {:?}, it can't be referenced by its id",
+				content_type, content_id,
+			);
+			// the verdicts were taken above, the stake of a report that wasn't
+			// settled yet is still in the pallet's account and is made claimable
+			if !matches!(old.status, v0::OldReportStatus::Accepted | v0::OldReportStatus::Refused) {
+				let refund = frame_support::storage::with_storage_layer(|| -> DispatchResult {
+					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+						old.reporter_id.clone(),
+						kine_stat_tracker::FeatureType::Moderation,
+						kine_stat_tracker::TokenType::Locked,
+						report_stake, true,
+					)?;
+					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+						old.reporter_id.clone(),
+						kine_stat_tracker::FeatureType::Moderation,
+						kine_stat_tracker::TokenType::Claimable,
+						report_stake, false,
+					)?;
+					Ok(())
+				});
+				if refund.is_err() {
+					frame_support::log::error!(
+						target: "runtime::moderation",
+						"couldn't refund the stake of the dropped report of {:?} {:?}",
+						content_type, content_id,
+					);
+				}
+				reads = reads.saturating_add(2);
+				writes = writes.saturating_add(2);
+			}
+			return (reads, writes)
+		};
+
+		let report_id = NextReportId::<T>::get();
+		NextReportId::<T>::put(report_id.saturating_add(1));
+		let status = ReportStatus::from(old.status);
+
+		for (tier, verdict) in old_verdicts {
+			let Some(verdict) = verdict else { continue };
+			let votes: Vec<_> = verdict.votes.into_iter()
+				.map(|vote| Vote {
+					voter: vote.voter,
+					is_for: vote.is_for,
+					cited_evidence: BoundedVec::default(),
+				})
+				.collect();
+			ReportVerdicts::<T>::insert(report_id, tier, ReportOutcome {
+				staked_tokens: verdict.staked_tokens,
+				required_votes: verdict.required_votes,
+				votes_for: verdict.votes_for,
+				votes: BoundedVec::truncate_from(votes),
+			});
+			writes = writes.saturating_add(1);
+		}
+
+		Reports::<T>::insert(report_id, Report {
+			content: content.clone(),
+			reporter_id: old.reporter_id,
+			reportee_id: old.reportee_id,
+			justification: old.justification,
+			status: status,
+			categories_and_tags: old.categories_and_tags,
+			infringement_types: BoundedVec::default(),
+			co_signers: BoundedVec::default(),
+		});
+		ContentReports::<T>::insert(content, report_id, status);
+		reads = reads.saturating_add(1);
+		writes = writes.saturating_add(4);
+
+		// open reports get a fresh voting period or appeal window
+		let delay = match status {
+			ReportStatus::InResolution |
+			ReportStatus::AppealedByReporter |
+			ReportStatus::AppealedByReportee => Some(T::VotingPeriod::get()),
+			ReportStatus::MajorityVotedFor |
+			ReportStatus::MajorityVotedAgainst => Some(T::AppealWindow::get()),
+			_ => None,
+		};
+		if let Some(delay) = delay {
+			OpenReports::<T>::append(content_id, report_id);
+			if Pallet::<T>::do_schedule_report_deadline(report_id, delay).is_err() {
+				frame_support::log::warn!(
+					target: "runtime::moderation",
+					"couldn't schedule the deadline of report {}", report_id,
+				);
+			}
+			reads = reads.saturating_add(1);
+			writes = writes.saturating_add(3);
+		}
+		(reads, writes)
+	}
+}
@@ -1,15 +1,19 @@
 use crate as kine_moderation;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU32, ConstU64, ConstU128},
+	traits::{ConstU16, ConstU32, ConstU64, ConstU128, Randomness},
+	weights::Weight,
 	PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Hash, IdentityLookup},
 	BuildStorage,
+	DispatchError,
+	Percent,
 	testing::Header,
 };
+use kine_moderation::{ContentRef, ContentRefOf};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 type Balance = u128;
@@ -77,7 +81,8 @@ impl kine_tags::Config for Test {
 
 // Stat Tracker
 parameter_types! {
-	pub const DefaultReputation: u32 = 15;
+	pub const ReputationDecay: Percent = Percent::from_percent(10);
+	pub const ReputationDecayPeriod: u64 = 100;
 	pub const WalletNameStringLimit: u32 = 50;
 	pub const PalletStatTrackerId : PalletId = PalletId(*b"kine/trk");
 }
@@ -85,9 +90,10 @@ parameter_types! {
 impl kine_stat_tracker::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type DefaultReputation = DefaultReputation;
 	type NameStringLimit = WalletNameStringLimit;
 	type PalletId = PalletStatTrackerId;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ReputationDecayPeriod;
 }
 
 
@@ -120,13 +126,28 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const ReportJustificationLimit: u32 = 250;
 	pub const MaxReportsByModerator: u32 = 3;
+	pub const MaxModerators: u32 = 20;
 	pub const TotalTierOneModerators: u32 = 3;
 	pub const MaxReportsByTier: u32 = 23;
+	pub const MaxReportCoSigners: u32 = 10;
+	pub const MaxInfringementTypes: u32 = 11;
+	pub const MaxEvidencePerReport: u32 = 20;
+	pub const EvidenceCidLimit: u32 = 64;
 	pub const MinimumReputationForSeniorship: u32 = 30;
 	pub const MinimumReputationForModeration: u32 = 10;
 	pub const MinimumTokensForModeration: u32 = 10000;
+	pub const MinimumTokensForSeniority: u32 = 20000;
+	pub const ModeratorUnbondingPeriod: u64 = 100;
 	pub const PalletModerationId: PalletId = PalletId(*b"ModStash");
+	pub const ReportVotingPeriod: u64 = 100;
+	pub const ReportRevealPeriod: u64 = 50;
+	pub const ReportAppealWindow: u64 = 200;
+	pub const MaxReportDeadlinesPerBlock: u32 = 50;
 	pub const MovieCollateral: u32 = 3000;
+	pub const MetricsMinimumReports: u32 = 4;
+	pub const MinimumMajorityAgreement: Percent = Percent::from_percent(50);
+	pub const MaxMissedDeadlines: u32 = 3;
+	pub const MaxResponseTimes: u32 = 5;
 }
 
 impl kine_moderation::Config for Test {
@@ -135,18 +156,86 @@ impl kine_moderation::Config for Test {
 	type JustificationLimit = ReportJustificationLimit;
     
 	type ContentId = u32;
+	type MovieIdLimit = ConstU32<100>;
 	type MaxReportsByModerator = MaxReportsByModerator;
+	type MaxModerators = MaxModerators;
 	type TotalTierOneModerators = TotalTierOneModerators;
 	type MaxReportsByTier = MaxReportsByTier;
+	type MaxCoSigners = MaxReportCoSigners;
+	type MaxInfringementTypes = MaxInfringementTypes;
+	type MaxEvidencePerReport = MaxEvidencePerReport;
+	type EvidenceCidLimit = EvidenceCidLimit;
 	
+	type Reputation = StatTrackerModule;
 	type MinimumReputationForSeniorship = MinimumReputationForSeniorship;
 	type MinimumReputationForModeration = MinimumReputationForModeration;
 	type MinimumTokensForModeration = MinimumTokensForModeration;
+	type MinimumTokensForSeniority = MinimumTokensForSeniority;
+	type UnbondingPeriod = ModeratorUnbondingPeriod;
 	type MovieCollateral = MovieCollateral;
+	type VotingPeriod = ReportVotingPeriod;
+	type AppealWindow = ReportAppealWindow;
+	type RevealPeriod = ReportRevealPeriod;
+	type MaxDeadlinesPerBlock = MaxReportDeadlinesPerBlock;
+	type Randomness = TestRandomness;
+	type ContentModerator = ();
+	type ContentOwners = TestContent;
+	type ConflictsOfInterest = TestConflicts;
+	type MetricsMinimumReports = MetricsMinimumReports;
+	type MinimumMajorityAgreement = MinimumMajorityAgreement;
+	type MaxMissedDeadlines = MaxMissedDeadlines;
+	type MaxResponseTimes = MaxResponseTimes;
+	type FinalAppealOrigin = frame_system::EnsureRoot<u64>;
+	type ModerationAdminOrigin = frame_system::EnsureRoot<u64>;
 
 	type PalletId = PalletModerationId;
 }
 
+// Hashes the subject, which is enough to spread the drafts in tests.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash(subject), System::block_number())
+	}
+}
+
+
+
+
+// Every movie belongs to CONTENT_OWNER, the other content doesn't exist.
+pub const CONTENT_OWNER: u64 = 50;
+
+pub struct TestContent;
+
+impl kine_tags::ContentOwnership<ContentRefOf<Test>, u64> for TestContent {
+	fn content_owner(content: &ContentRefOf<Test>) -> Result<Option<u64>, DispatchError> {
+		match content {
+			ContentRef::Movie(_) => Ok(Some(CONTENT_OWNER)),
+			_ => Err(DispatchError::Other("nonexistent content")),
+		}
+	}
+}
+
+pub fn movie(movie_id: u32) -> ContentRefOf<Test> {
+	ContentRef::Movie(vec![movie_id as u8].try_into().unwrap())
+}
+
+// Moderators from CONFLICTED_MODERATORS on share a community with every reportee.
+pub const CONFLICTED_MODERATORS: u64 = 90;
+
+pub struct TestConflicts;
+
+impl kine_moderation::ConflictOfInterest<u64, ContentRefOf<Test>> for TestConflicts {
+	fn has_conflict(moderator_id: &u64, _reportee_id: &u64, _content: &ContentRefOf<Test>) -> bool {
+		*moderator_id >= CONFLICTED_MODERATORS
+	}
+
+	fn check_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 
 
 
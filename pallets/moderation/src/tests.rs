@@ -1,5 +1,8 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, ContentRef, Error, FinalAction, InfringimentType, ModeratorRank, ModeratorStatus, ModeratorStats, Moderators, ReportDeadline, ReportDeadlines, ReportStatus, Reports, Tiers, VoteChoice, VoteCommitments};
+use kine_stat_tracker::{FeatureType, ReputationDomain, TokenType};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo, traits::{Currency, Hooks, OnRuntimeUpgrade}, BoundedVec};
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::collections::btree_map::BTreeMap;
 
 #[test]
 fn it_works_for_default_value() {
@@ -18,3 +21,818 @@ fn correct_error_for_none_value() {
 		// assert_noop!(TemplateModule::cause_error(Origin::signed(1)), Error::<Test>::NoneValue);
 	});
 }
+
+fn register_moderators(moderators: impl Iterator<Item = u64>) {
+	for moderator_id in moderators {
+		StatTrackerModule::do_update_reputation(moderator_id, ReputationDomain::Moderation, MinimumReputationForModeration::get() + 5, false);
+		assert_ok!(ModerationModule::do_create_moderator(moderator_id));
+	}
+}
+
+#[test]
+fn draft_skips_reporter_and_reportee() {
+	new_test_ext().execute_with(|| {
+		register_moderators(1..=5);
+
+		for _ in 0..50 {
+			let drafted = ModerationModule::do_draft_moderators(vec![1, 2], 3, ModeratorRank::Junior, None).unwrap();
+			assert_eq!(drafted.len(), 3);
+			assert!(!drafted.contains(&1) && !drafted.contains(&2));
+			assert!(drafted.iter().all(|id| drafted.iter().filter(|other| *other == id).count() == 1));
+		}
+	});
+}
+
+#[test]
+fn draft_skips_moderators_at_their_report_cap() {
+	new_test_ext().execute_with(|| {
+		register_moderators(1..=4);
+		for report_id in 0..MaxReportsByModerator::get() {
+			assert_ok!(ModerationModule::do_assign_report_to_moderators(report_id, vec![1]));
+		}
+		assert_noop!(
+			ModerationModule::do_assign_report_to_moderators(100, vec![1]),
+			Error::<Test>::ModeratorReportLimitReached
+		);
+
+		for _ in 0..50 {
+			let mut drafted = ModerationModule::do_draft_moderators(vec![100, 101], 3, ModeratorRank::Junior, None).unwrap();
+			drafted.sort();
+			assert_eq!(drafted, vec![2, 3, 4]);
+		}
+	});
+}
+
+#[test]
+fn draft_fails_without_enough_available_moderators() {
+	new_test_ext().execute_with(|| {
+		register_moderators(1..=3);
+
+		assert_noop!(
+			ModerationModule::do_draft_moderators(vec![1, 100], 3, ModeratorRank::Junior, None),
+			Error::<Test>::NotEnoughModeratorsAvailable
+		);
+	});
+}
+
+#[test]
+fn drafts_are_spread_uniformly() {
+	new_test_ext().execute_with(|| {
+		register_moderators(1..=10);
+
+		// 3000 drafts of 3 out of 10 moderators, each should be picked ~900 times
+		let mut picks: BTreeMap<u64, u32> = BTreeMap::new();
+		for _ in 0..3000 {
+			for moderator_id in ModerationModule::do_draft_moderators(vec![100, 101], 3, ModeratorRank::Junior, None).unwrap() {
+				*picks.entry(moderator_id).or_default() += 1;
+			}
+		}
+
+		assert_eq!(picks.len(), 10);
+		for (_, count) in picks {
+			assert!(count > 800 && count < 1000, "moderator drafted {} times", count);
+		}
+	});
+}
+
+#[test]
+fn busy_moderators_are_drafted_less() {
+	new_test_ext().execute_with(|| {
+		register_moderators(1..=4);
+		// moderator 1 can take one more report, the others three
+		for report_id in 0..(MaxReportsByModerator::get() - 1) {
+			assert_ok!(ModerationModule::do_assign_report_to_moderators(report_id, vec![1]));
+		}
+
+		let mut picks: BTreeMap<u64, u32> = BTreeMap::new();
+		for _ in 0..2000 {
+			for moderator_id in ModerationModule::do_draft_moderators(vec![100, 101], 1, ModeratorRank::Junior, None).unwrap() {
+				*picks.entry(moderator_id).or_default() += 1;
+			}
+		}
+
+		// expected ~200 picks for moderator 1 and ~600 for each of the others
+		let busy_picks = picks.get(&1).copied().unwrap_or_default();
+		for moderator_id in 2..=4 {
+			assert!(busy_picks * 2 < picks[&moderator_id]);
+		}
+	});
+}
+
+fn create_movie_report(reporter_id: u64, content_id: u32) -> u32 {
+	ModerationModule::do_create_report(
+		reporter_id, movie(content_id), CONTENT_OWNER,
+		Default::default(), Default::default(),
+		vec![InfringimentType::Copyright].try_into().unwrap(),
+	).unwrap()
+}
+
+#[test]
+fn open_reports_are_co_signed() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_movie_report(1, 7);
+		assert_eq!(ModerationModule::do_get_open_report(&movie(7)), Some(report_id));
+		assert_eq!(ModerationModule::do_get_open_report(&ContentRef::Festival(7)), None);
+
+		assert_ok!(ModerationModule::do_add_co_signer(report_id, 2, 100, Default::default(), Default::default()));
+		assert_noop!(
+			ModerationModule::do_add_co_signer(report_id, 2, 100, Default::default(), Default::default()),
+			Error::<Test>::AlreadyCoSignedReport
+		);
+		assert_noop!(
+			ModerationModule::do_add_co_signer(report_id, 1, 100, Default::default(), Default::default()),
+			Error::<Test>::CannotCoSignReport
+		);
+		assert_noop!(
+			ModerationModule::do_add_co_signer(report_id, CONTENT_OWNER, 100, Default::default(), Default::default()),
+			Error::<Test>::CannotCoSignReport
+		);
+
+		// co-signers don't judge the report they back
+		register_moderators(1..=5);
+		for _ in 0..20 {
+			let drafted = ModerationModule::do_draft_moderators(
+				ModerationModule::do_get_report_parties(report_id).unwrap(), 3, ModeratorRank::Junior, None,
+			).unwrap();
+			assert!(!drafted.contains(&1) && !drafted.contains(&2));
+		}
+	});
+}
+
+#[test]
+fn closed_reports_let_the_content_be_reported_again() {
+	new_test_ext().execute_with(|| {
+		let first_report_id = create_movie_report(1, 7);
+		assert_ok!(ModerationModule::do_update_report_status(first_report_id, ReportStatus::MajorityVotedAgainst));
+		assert_eq!(ModerationModule::do_get_open_report(&movie(7)), Some(first_report_id));
+
+		assert_ok!(ModerationModule::do_update_report_status(first_report_id, ReportStatus::Refused));
+		assert_eq!(ModerationModule::do_get_open_report(&movie(7)), None);
+
+		let second_report_id = create_movie_report(2, 7);
+		assert_ne!(first_report_id, second_report_id);
+		assert_eq!(ModerationModule::do_get_open_report(&movie(7)), Some(second_report_id));
+		assert_eq!(
+			ModerationModule::content_reports(movie(7), first_report_id),
+			Some(ReportStatus::Refused)
+		);
+	});
+}
+
+#[test]
+fn co_signers_add_their_infringement_types() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_movie_report(1, 7);
+		assert_ok!(ModerationModule::do_add_co_signer(
+			report_id, 2, 100, Default::default(),
+			vec![InfringimentType::Copyright, InfringimentType::Violence].try_into().unwrap(),
+		));
+
+		assert_eq!(
+			ModerationModule::report(report_id).unwrap().infringement_types.into_inner(),
+			vec![InfringimentType::Copyright, InfringimentType::Violence]
+		);
+	});
+}
+
+fn evidence_cid() -> BoundedVec<u8, EvidenceCidLimit> {
+	b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec().try_into().unwrap()
+}
+
+#[test]
+fn evidence_is_only_accepted_from_the_parties_while_voting() {
+	new_test_ext().execute_with(|| {
+		let report_id = create_movie_report(1, 7);
+
+		assert_eq!(ModerationModule::do_add_evidence(report_id, 1, evidence_cid(), Some(95), Default::default()), Ok(0));
+		assert_eq!(ModerationModule::do_add_evidence(report_id, CONTENT_OWNER, evidence_cid(), None, Default::default()), Ok(1));
+		assert_noop!(
+			ModerationModule::do_add_evidence(report_id, 3, evidence_cid(), None, Default::default()),
+			Error::<Test>::NotReportParty
+		);
+		assert_noop!(
+			ModerationModule::do_add_evidence(report_id, 1, Default::default(), None, Default::default()),
+			Error::<Test>::EmptyEvidence
+		);
+
+		assert_ok!(ModerationModule::do_validate_cited_evidence(report_id, vec![0, 1].try_into().unwrap()));
+		assert_noop!(
+			ModerationModule::do_validate_cited_evidence(report_id, vec![2].try_into().unwrap()),
+			Error::<Test>::NonexistentEvidence
+		);
+
+		assert_ok!(ModerationModule::do_update_report_status(report_id, ReportStatus::MajorityVotedFor));
+		assert_noop!(
+			ModerationModule::do_add_evidence(report_id, 1, evidence_cid(), None, Default::default()),
+			Error::<Test>::ReportNotInVoting
+		);
+	});
+}
+
+fn drafted_report(moderators: Vec<u64>) -> u32 {
+	drafted_report_with_pool(moderators, 0)
+}
+
+fn drafted_report_with_pool(moderators: Vec<u64>, reward_pool: u128) -> u32 {
+	register_moderators(moderators.iter().copied());
+	assert_ok!(ModerationModule::set_moderators_per_tier(RuntimeOrigin::root(), Tiers::TierOne, moderators.len() as u32));
+	let report_id = create_movie_report(1, 7);
+	assert_ok!(ModerationModule::do_create_report_verdict(report_id, Tiers::TierOne, reward_pool));
+	assert_ok!(ModerationModule::do_assign_report_to_moderators(report_id, moderators));
+	report_id
+}
+
+fn commit_vote(moderator_id: u64, report_id: u32, vote: VoteChoice) {
+	let commitment = ModerationModule::do_get_vote_commitment(
+		moderator_id, report_id, vote, Default::default(), [moderator_id as u8; 32],
+	);
+	assert_ok!(ModerationModule::commit_vote(RuntimeOrigin::signed(moderator_id), report_id, commitment));
+}
+
+fn reveal_vote(moderator_id: u64, report_id: u32, vote: VoteChoice) -> DispatchResultWithPostInfo {
+	ModerationModule::reveal_vote(
+		RuntimeOrigin::signed(moderator_id), report_id, vote, Default::default(), [moderator_id as u8; 32],
+	)
+}
+
+#[test]
+fn votes_stay_sealed_until_every_moderator_committed() {
+	new_test_ext().execute_with(|| {
+		let report_id = drafted_report(vec![10, 11, 12]);
+		let status = || ModerationModule::report(report_id).unwrap().status;
+
+		commit_vote(10, report_id, VoteChoice::For);
+		commit_vote(11, report_id, VoteChoice::Against);
+		assert_eq!(status(), ReportStatus::InResolution);
+		assert_noop!(reveal_vote(10, report_id, VoteChoice::For), Error::<Test>::ReportNotInReveal);
+		assert_noop!(
+			ModerationModule::commit_vote(RuntimeOrigin::signed(10), report_id, Default::default()),
+			Error::<Test>::VoteAlreadyCommitted
+		);
+		assert_noop!(
+			ModerationModule::commit_vote(RuntimeOrigin::signed(99), report_id, Default::default()),
+			Error::<Test>::NonexistentModerator
+		);
+
+		commit_vote(12, report_id, VoteChoice::For);
+		assert_eq!(status(), ReportStatus::AwaitingReveal);
+		assert!(ModerationModule::report_tier_verdict(report_id, Tiers::TierOne).unwrap().votes.is_empty());
+	});
+}
+
+#[test]
+fn consensus_is_computed_once_every_vote_is_revealed() {
+	new_test_ext().execute_with(|| {
+		let report_id = drafted_report(vec![10, 11, 12]);
+		commit_vote(10, report_id, VoteChoice::For);
+		commit_vote(11, report_id, VoteChoice::Against);
+		commit_vote(12, report_id, VoteChoice::For);
+
+		// the revealed vote must match the commitment
+		assert_noop!(reveal_vote(10, report_id, VoteChoice::Against), Error::<Test>::CommitmentMismatch);
+		assert_noop!(reveal_vote(13, report_id, VoteChoice::For), Error::<Test>::NoVoteCommitment);
+
+		assert_ok!(reveal_vote(10, report_id, VoteChoice::For));
+		assert_ok!(reveal_vote(11, report_id, VoteChoice::Against));
+		assert_eq!(ModerationModule::report(report_id).unwrap().status, ReportStatus::AwaitingReveal);
+
+		assert_ok!(reveal_vote(12, report_id, VoteChoice::For));
+		assert_eq!(ModerationModule::report(report_id).unwrap().status, ReportStatus::MajorityVotedFor);
+		assert_eq!(VoteCommitments::<Test>::iter_prefix(report_id).count(), 0);
+		assert_eq!(ModerationModule::report_tier_verdict(report_id, Tiers::TierOne).unwrap().votes_for, 2);
+	});
+}
+
+// Runs the hooks of the block the report's current deadline ends at.
+fn run_to_report_deadline(report_id: u32) {
+	let deadline = ModerationModule::report_deadline(report_id).unwrap();
+	System::set_block_number(deadline);
+	ModerationModule::on_initialize(deadline);
+}
+
+#[test]
+fn expired_votes_are_closed_without_the_absent_moderators() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let report_id = drafted_report(vec![10, 11, 12]);
+		assert_ok!(ModerationModule::do_schedule_report_deadline(report_id, ReportVotingPeriod::get()));
+		commit_vote(10, report_id, VoteChoice::For);
+		commit_vote(11, report_id, VoteChoice::For);
+
+		// 12 never committed: it is released from the report and counted as absent
+		run_to_report_deadline(report_id);
+		assert_eq!(ModerationModule::report(report_id).unwrap().status, ReportStatus::AwaitingReveal);
+		assert!(!Moderators::<Test>::get(12).unwrap().assigned_reports.contains(&report_id));
+		assert_eq!(ModeratorStats::<Test>::get(12).missed_deadlines, 1);
+		assert_eq!(ModerationModule::report_deadline(report_id), Some(System::block_number() + ReportRevealPeriod::get()));
+
+		// the report is decided by the votes that were revealed in time
+		assert_ok!(reveal_vote(10, report_id, VoteChoice::For));
+		run_to_report_deadline(report_id);
+		assert_eq!(ModerationModule::report(report_id).unwrap().status, ReportStatus::MajorityVotedFor);
+		assert_eq!(ModeratorStats::<Test>::get(11).missed_deadlines, 1);
+		let verdict = ModerationModule::report_tier_verdict(report_id, Tiers::TierOne).unwrap();
+		assert_eq!((verdict.required_votes, verdict.votes_for), (1, 1));
+	});
+}
+
+#[test]
+fn tiers_nobody_voted_in_stay_open_until_a_jury_can_be_drafted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let report_id = drafted_report(vec![10, 11, 12]);
+		assert_ok!(ModerationModule::do_schedule_report_deadline(report_id, ReportVotingPeriod::get()));
+		assert_ok!(ModerationModule::set_moderators_per_tier(RuntimeOrigin::root(), Tiers::TierOne, 4));
+
+		// nobody committed and there aren't enough moderators for a new jury
+		run_to_report_deadline(report_id);
+		assert_eq!(ModerationModule::report(report_id).unwrap().status, ReportStatus::InResolution);
+		assert_eq!(ModerationModule::report_deadline(report_id), Some(System::block_number() + ReportVotingPeriod::get()));
+		System::assert_has_event(crate::Event::<Test>::JuryUnavailable(report_id).into());
+
+		// the draft is retried at the next deadline
+		register_moderators(20..=23);
+		run_to_report_deadline(report_id);
+		assert_eq!(ModerationModule::report(report_id).unwrap().status, ReportStatus::InResolution);
+		System::assert_has_event(crate::Event::<Test>::JuryRedrafted(report_id).into());
+		let drafted = Moderators::<Test>::iter_values().filter(|moderator| moderator.assigned_reports.contains(&report_id)).count();
+		assert_eq!(drafted, 4);
+	});
+}
+
+#[test]
+fn unappealed_verdicts_are_accepted_at_the_end_of_the_appeal_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let report_id = drafted_report(vec![10, 11, 12]);
+		for moderator_id in 10..=12 {
+			commit_vote(moderator_id, report_id, VoteChoice::For);
+		}
+		for moderator_id in 10..=12 {
+			assert_ok!(reveal_vote(moderator_id, report_id, VoteChoice::For));
+		}
+		assert_eq!(ModerationModule::report(report_id).unwrap().status, ReportStatus::MajorityVotedFor);
+		assert_eq!(ModerationModule::report_deadline(report_id), Some(1 + ReportAppealWindow::get()));
+
+		run_to_report_deadline(report_id);
+		assert_eq!(ModerationModule::report(report_id).unwrap().status, ReportStatus::Accepted);
+		assert_eq!(ModerationModule::report_deadline(report_id), None);
+		System::assert_has_event(crate::Event::<Test>::VerdictAutoAccepted(report_id).into());
+	});
+}
+
+#[test]
+fn appeal_tiers_draft_only_seniors() {
+	new_test_ext().execute_with(|| {
+		register_moderators(1..=6);
+		for moderator_id in 4..=6 {
+			Moderators::<Test>::mutate(moderator_id, |moderator| moderator.as_mut().unwrap().rank = ModeratorRank::Senior);
+		}
+		assert_ok!(ModerationModule::set_moderators_per_tier(RuntimeOrigin::root(), Tiers::TierOne, 3));
+		assert_ok!(ModerationModule::set_moderators_per_tier(RuntimeOrigin::root(), Tiers::TierTwo, 3));
+		let report_id = create_movie_report(100, 7);
+
+		for _ in 0..20 {
+			let mut drafted = ModerationModule::do_draft_jury(report_id, Tiers::TierTwo).unwrap();
+			drafted.sort();
+			assert_eq!(drafted, vec![4, 5, 6]);
+			assert_eq!(ModerationModule::do_draft_jury(report_id, Tiers::TierOne).unwrap().len(), 3);
+		}
+
+		assert_ok!(ModerationModule::set_moderators_per_tier(RuntimeOrigin::root(), Tiers::TierTwo, 4));
+		assert_noop!(
+			ModerationModule::do_draft_jury(report_id, Tiers::TierTwo),
+			Error::<Test>::NotEnoughModeratorsAvailable
+		);
+	});
+}
+
+#[test]
+fn moderators_per_tier_is_bounded_by_the_votes_a_tier_holds() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ModerationModule::do_get_moderators_in_tier(Tiers::TierThree), Ok(MaxReportsByTier::get()));
+		assert_noop!(
+			ModerationModule::set_moderators_per_tier(RuntimeOrigin::root(), Tiers::TierThree, MaxReportsByTier::get() + 1),
+			Error::<Test>::InvalidModeratorsPerTier
+		);
+		assert_noop!(
+			ModerationModule::set_moderators_per_tier(RuntimeOrigin::root(), Tiers::TierOne, 0),
+			Error::<Test>::InvalidModeratorsPerTier
+		);
+		assert_noop!(
+			ModerationModule::set_moderators_per_tier(RuntimeOrigin::signed(1), Tiers::TierOne, 5),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(ModerationModule::set_moderators_per_tier(RuntimeOrigin::root(), Tiers::TierThree, 9));
+		assert_eq!(ModerationModule::do_get_moderators_in_tier(Tiers::TierThree), Ok(9));
+	});
+}
+
+#[test]
+fn departing_moderators_hand_over_their_reports_and_unbond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let report_id = drafted_report(vec![10, 11, 12]);
+		register_moderators(13..=13);
+
+		assert_ok!(ModerationModule::suspend_moderation_activity(RuntimeOrigin::signed(10)));
+		let moderator = Moderators::<Test>::get(10).unwrap();
+		assert_eq!(moderator.status, ModeratorStatus::Unbonding(1 + ModeratorUnbondingPeriod::get()));
+		assert!(moderator.assigned_reports.is_empty());
+		assert!(Moderators::<Test>::get(13).unwrap().assigned_reports.contains(&report_id));
+
+		// unbonding moderators are not drafted anymore
+		assert_noop!(
+			ModerationModule::do_draft_moderators(vec![11, 12, 13], 1, ModeratorRank::Junior, None),
+			Error::<Test>::NotEnoughModeratorsAvailable
+		);
+
+		assert_noop!(
+			ModerationModule::withdraw_moderation_bond(RuntimeOrigin::signed(10)),
+			Error::<Test>::BondStillUnbonding
+		);
+		assert_noop!(
+			ModerationModule::withdraw_moderation_bond(RuntimeOrigin::signed(11)),
+			Error::<Test>::ModeratorNotUnbonding
+		);
+
+		System::set_block_number(1 + ModeratorUnbondingPeriod::get());
+		let treasury: u64 = PalletModerationId::get().into_account_truncating();
+		Balances::make_free_balance_be(&treasury, MinimumTokensForModeration::get().into());
+		assert_ok!(ModerationModule::withdraw_moderation_bond(RuntimeOrigin::signed(10)));
+		assert_eq!(Balances::free_balance(10), MinimumTokensForModeration::get().into());
+		assert!(!Moderators::<Test>::contains_key(10));
+	});
+}
+
+#[test]
+fn final_appeal_overrides_closed_verdicts() {
+	new_test_ext().execute_with(|| {
+		let report_id = drafted_report(vec![10, 11, 12]);
+		let justification: BoundedVec<u8, ReportJustificationLimit> = b"court ruling".to_vec().try_into().unwrap();
+		assert_noop!(
+			ModerationModule::override_verdict(RuntimeOrigin::root(), report_id, ReportStatus::Refused, justification.clone()),
+			Error::<Test>::ReportNotClosed
+		);
+
+		assert_ok!(ModerationModule::do_update_report_status(report_id, ReportStatus::Accepted));
+		assert_noop!(
+			ModerationModule::override_verdict(RuntimeOrigin::signed(1), report_id, ReportStatus::Refused, justification.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ModerationModule::override_verdict(RuntimeOrigin::root(), report_id, ReportStatus::Accepted, justification.clone()),
+			Error::<Test>::InvalidFinalVerdict
+		);
+
+		assert_ok!(ModerationModule::override_verdict(RuntimeOrigin::root(), report_id, ReportStatus::Refused, justification));
+		assert_eq!(ModerationModule::report(report_id).unwrap().status, ReportStatus::Refused);
+		assert_eq!(
+			ModerationModule::final_decision(0).unwrap().action,
+			FinalAction::VerdictOverridden(report_id, ReportStatus::Accepted, ReportStatus::Refused)
+		);
+	});
+}
+
+fn claimable(who: u64) -> u128 {
+	StatTrackerModule::get_wallet_tokens(who).map(|tokens| tokens.claimable_tokens_moderation).unwrap_or_default()
+}
+
+#[test]
+fn overrides_only_pay_out_the_rewards_that_were_recovered() {
+	new_test_ext().execute_with(|| {
+		let treasury: u64 = PalletModerationId::get().into_account_truncating();
+		Balances::make_free_balance_be(&CONTENT_OWNER, 10_000);
+
+		// a pool of 9000 pays 3500 to each of the two majority voters and 4500 to the reporter
+		let report_id = drafted_report_with_pool(vec![10, 11, 12], 9_000);
+		for (moderator_id, vote) in [(10, VoteChoice::For), (11, VoteChoice::Against), (12, VoteChoice::For)] {
+			commit_vote(moderator_id, report_id, vote);
+		}
+		for (moderator_id, vote) in [(10, VoteChoice::For), (11, VoteChoice::Against), (12, VoteChoice::For)] {
+			assert_ok!(reveal_vote(moderator_id, report_id, vote));
+		}
+		assert_ok!(ModerationModule::do_accept_verdict(report_id, false, ReportStatus::MajorityVotedFor));
+
+		assert_eq!((claimable(10), claimable(11), claimable(12), claimable(1)), (3_500, 0, 3_500, 4_500));
+		assert_eq!((Balances::free_balance(CONTENT_OWNER), Balances::free_balance(treasury)), (7_000, 3_000));
+
+		// moderator 10 already claimed its reward
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(
+			10, FeatureType::Moderation, TokenType::Claimable, 3_500, true,
+		));
+
+		let justification: BoundedVec<u8, ReportJustificationLimit> = b"court ruling".to_vec().try_into().unwrap();
+		assert_ok!(ModerationModule::override_verdict(RuntimeOrigin::root(), report_id, ReportStatus::Refused, justification));
+
+		// 8000 of the 9500 owed to moderator 11 and the reportee was recovered, so they are paid 8000/9500
+		// of it, and the reportee's collateral is given back
+		assert_eq!((claimable(10), claimable(12), claimable(1)), (0, 0, 0));
+		assert_eq!(claimable(11), 6_736);
+		assert_eq!(claimable(CONTENT_OWNER), 1_263 + 3_000);
+		assert_eq!((Balances::free_balance(CONTENT_OWNER), Balances::free_balance(treasury)), (7_000, 3_000));
+	});
+}
+
+#[test]
+fn emergency_takedowns_skip_the_vote_and_are_recorded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ModerationModule::emergency_takedown(RuntimeOrigin::root(), movie(7), Default::default()),
+			Error::<Test>::EmptyJustification
+		);
+		assert_ok!(ModerationModule::emergency_takedown(
+			RuntimeOrigin::root(), movie(7), b"legal request".to_vec().try_into().unwrap(),
+		));
+
+		let decision = ModerationModule::final_decision(0).unwrap();
+		assert_eq!(decision.action, FinalAction::EmergencyTakedown);
+		assert_eq!(decision.content, movie(7));
+		assert_eq!(ModerationModule::next_decision_id(), 1);
+	});
+}
+
+#[test]
+fn reports_are_filed_against_the_content_owner() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ModerationModule::do_get_content_owner(&movie(7)), Ok(CONTENT_OWNER));
+		assert!(ModerationModule::do_get_content_owner(&ContentRef::Festival(7)).is_err());
+	});
+}
+
+#[test]
+fn moderators_with_a_conflict_of_interest_are_not_drafted() {
+	new_test_ext().execute_with(|| {
+		register_moderators(10..=12);
+		register_moderators(CONFLICTED_MODERATORS..=CONFLICTED_MODERATORS + 2);
+		let report_id = create_movie_report(1, 7);
+
+		for _ in 0..20 {
+			let drafted = ModerationModule::do_draft_moderators(
+				ModerationModule::do_get_report_parties(report_id).unwrap(), 3, ModeratorRank::Junior,
+				Reports::<Test>::get(report_id).as_ref(),
+			).unwrap();
+			assert!(drafted.iter().all(|moderator_id| *moderator_id < CONFLICTED_MODERATORS));
+		}
+	});
+}
+
+#[test]
+fn drafts_fail_when_only_conflicted_moderators_are_left() {
+	new_test_ext().execute_with(|| {
+		register_moderators(10..=11);
+		register_moderators(CONFLICTED_MODERATORS..=CONFLICTED_MODERATORS + 2);
+		let report_id = create_movie_report(1, 7);
+
+		assert_eq!(
+			ModerationModule::do_draft_moderators(
+				ModerationModule::do_get_report_parties(report_id).unwrap(), 3, ModeratorRank::Junior,
+				Reports::<Test>::get(report_id).as_ref(),
+			),
+			Err(Error::<Test>::NotEnoughModeratorsAvailable.into())
+		);
+	});
+}
+
+#[test]
+fn moderators_can_only_register_up_to_the_cap() {
+	new_test_ext().execute_with(|| {
+		register_moderators(1..=MaxModerators::get() as u64);
+		assert_noop!(
+			ModerationModule::do_create_moderator(MaxModerators::get() as u64 + 1),
+			Error::<Test>::TooManyModerators
+		);
+	});
+}
+
+#[test]
+fn moderator_metrics_follow_their_votes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let report_id = drafted_report(vec![10, 11, 12]);
+
+		System::set_block_number(3);
+		commit_vote(10, report_id, VoteChoice::For);
+		System::set_block_number(5);
+		commit_vote(11, report_id, VoteChoice::Against);
+		commit_vote(12, report_id, VoteChoice::For);
+		for moderator_id in 10..=12 {
+			assert_ok!(reveal_vote(moderator_id, report_id, match moderator_id {
+				11 => VoteChoice::Against,
+				_ => VoteChoice::For,
+			}));
+		}
+
+		let stats = ModerationModule::moderator_stats(10);
+		assert_eq!((stats.reports_handled, stats.majority_votes, stats.median_response_time), (1, 1, 2));
+		assert_eq!(ModerationModule::moderator_stats(11).majority_votes, 0);
+		assert_eq!(ModerationModule::moderator_stats(12).median_response_time, 4);
+	});
+}
+
+#[test]
+fn moderators_are_removed_past_the_metric_thresholds() {
+	new_test_ext().execute_with(|| {
+		register_moderators(10..=11);
+		Moderators::<Test>::mutate(11, |moderator| moderator.as_mut().unwrap().rank = ModeratorRank::Senior);
+
+		for _ in 0..MaxMissedDeadlines::get() {
+			assert_ok!(ModerationModule::do_record_missed_deadlines(vec![10]));
+		}
+		assert!(matches!(Moderators::<Test>::get(10).unwrap().status, ModeratorStatus::Unbonding(_)));
+
+		// a senior disagreeing with the majority is demoted first
+		ModeratorStats::<Test>::mutate(11, |stats| stats.reports_handled = MetricsMinimumReports::get());
+		assert_ok!(ModerationModule::do_check_moderator_metrics(11));
+		let moderator = Moderators::<Test>::get(11).unwrap();
+		assert_eq!((moderator.rank, moderator.status), (ModeratorRank::Junior, ModeratorStatus::Active));
+	});
+}
+
+#[test]
+fn moderation_reputation_follows_the_verdicts() {
+	new_test_ext().execute_with(|| {
+		register_moderators(10..=11);
+		Moderators::<Test>::mutate(10, |moderator| moderator.as_mut().unwrap().rank = ModeratorRank::Senior);
+		let reputation = |moderator_id| StatTrackerModule::do_get_reputation(moderator_id, ReputationDomain::Moderation);
+
+		assert_ok!(ModerationModule::do_distribute_rewards_to_majority_voters(vec![11], 0));
+		assert_eq!(reputation(11), MinimumReputationForModeration::get() + 8);
+
+		// below the seniorship threshold seniors are demoted, below the moderation one removed
+		assert_ok!(ModerationModule::do_slash_minority_voters(vec![10]));
+		assert_eq!(reputation(10), MinimumReputationForModeration::get() + 2);
+		let moderator = Moderators::<Test>::get(10).unwrap();
+		assert_eq!((moderator.rank, moderator.status), (ModeratorRank::Junior, ModeratorStatus::Active));
+
+		assert_ok!(ModerationModule::do_slash_minority_voters(vec![10]));
+		assert!(matches!(Moderators::<Test>::get(10).unwrap().status, ModeratorStatus::Unbonding(_)));
+	});
+}
+
+#[test]
+fn existing_moderators_keep_working_after_the_reputation_migration() {
+	new_test_ext().execute_with(|| {
+		// a moderator registered before reputation was split by domain
+		assert_ok!(ModerationModule::do_create_moderator(10));
+		let old_tokens = kine_stat_tracker::migrations::v1::OldTokens {
+			reputation_moderation: 15u32,
+			locked_tokens_moderation: 10_000u128,
+			claimable_tokens_moderation: 0,
+			locked_tokens_festival: 0,
+			claimable_tokens_festival: 0,
+			total_tokens_won_festival: 0,
+			locked_tokens_ranking: 0,
+			claimable_tokens_ranking: 0,
+			imbalance_tokens_ranking: (0u128, 0u128),
+			total_tokens_won_ranking: 0,
+			locked_tokens_movie: 0,
+			claimable_tokens_movie: 0,
+		};
+		frame_support::storage::unhashed::put(&kine_stat_tracker::WalletTokens::<Test>::hashed_key_for(10), &old_tokens);
+
+		kine_stat_tracker::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(StatTrackerModule::do_get_reputation(10, ReputationDomain::Moderation), 15);
+
+		// a single slash no longer expels it
+		assert_ok!(ModerationModule::do_slash_minority_voters(vec![10]));
+		assert_eq!(Moderators::<Test>::get(10).unwrap().status, ModeratorStatus::Active);
+		assert_eq!(StatTrackerModule::do_get_reputation(10, ReputationDomain::Moderation), 12);
+	});
+}
+
+#[test]
+fn demotion_only_releases_the_seniority_bond_that_was_bonded() {
+	new_test_ext().execute_with(|| {
+		register_moderators(10..=11);
+		// 10 was promoted before seniors had to bond, 11 bonded through apply_for_seniority
+		Moderators::<Test>::mutate(10, |moderator| moderator.as_mut().unwrap().rank = ModeratorRank::Senior);
+		StatTrackerModule::do_update_reputation(11, ReputationDomain::Moderation, MinimumReputationForSeniorship::get(), false);
+		Balances::make_free_balance_be(&11, 30_000);
+		assert_ok!(ModerationModule::apply_for_seniority(RuntimeOrigin::signed(11)));
+		assert_eq!(Moderators::<Test>::get(11).unwrap().bonded_tokens, 30_000);
+
+		for moderator_id in 10..=11 {
+			let mut moderator = Moderators::<Test>::get(moderator_id).unwrap();
+			assert_ok!(ModerationModule::do_demote_moderator(moderator_id, &mut moderator));
+			assert_eq!((moderator.rank, moderator.bonded_tokens), (ModeratorRank::Junior, 10_000));
+		}
+		assert_eq!((claimable(10), claimable(11)), (0, 20_000));
+	});
+}
+
+#[test]
+fn seniority_is_refused_without_the_funds_for_its_bond() {
+	new_test_ext().execute_with(|| {
+		register_moderators(11..=11);
+		StatTrackerModule::do_update_reputation(11, ReputationDomain::Moderation, MinimumReputationForSeniorship::get(), false);
+		Balances::make_free_balance_be(&11, 19_999);
+
+		assert!(ModerationModule::apply_for_seniority(RuntimeOrigin::signed(11)).is_err());
+		let moderator = Moderators::<Test>::get(11).unwrap();
+		assert_eq!((moderator.rank, moderator.bonded_tokens), (ModeratorRank::Junior, 10_000));
+		assert_eq!(StatTrackerModule::get_wallet_tokens(11).map_or(0, |tokens| tokens.locked_tokens_moderation), 0);
+		assert_eq!(Balances::free_balance(11), 19_999);
+	});
+}
+
+#[test]
+fn reports_are_migrated_to_their_own_ids() {
+	new_test_ext().execute_with(|| {
+		use crate::migrations::v0::{self, OldContentType, OldModerator, OldReport, OldReportStatus, OldVote};
+
+		let old_report = |status| OldReport {
+			reporter_id: 1u64,
+			reportee_id: CONTENT_OWNER,
+			justification: BoundedVec::<u8, ReportJustificationLimit>::truncate_from(b"spam".to_vec()),
+			status: status,
+			categories_and_tags: BoundedVec::default(),
+		};
+		v0::Reports::<Test>::insert((7u32, OldContentType::Festival), old_report(OldReportStatus::InResolution));
+		v0::Reports::<Test>::insert((8u32, OldContentType::Movie), old_report(OldReportStatus::Refused));
+		v0::Reports::<Test>::insert((9u32, OldContentType::Tag), OldReport { reporter_id: 2, ..old_report(OldReportStatus::InResolution) });
+		v0::ReportVerdicts::<Test>::insert((9u32, OldContentType::Tag), Tiers::TierOne, crate::ReportOutcome {
+			staked_tokens: 3000u128,
+			required_votes: 3,
+			votes_for: 0,
+			votes: BoundedVec::default(),
+		});
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(2, FeatureType::Moderation, TokenType::Locked, 3_000, false));
+		v0::ReportVerdicts::<Test>::insert((7u32, OldContentType::Festival), Tiers::TierOne, crate::ReportOutcome {
+			staked_tokens: 3000u128,
+			required_votes: 3,
+			votes_for: 1,
+			votes: BoundedVec::truncate_from(vec![OldVote { voter: 10u64, is_for: VoteChoice::For }]),
+		});
+		let old_moderator = OldModerator {
+			assigned_reports: BoundedVec::<u32, MaxReportsByModerator>::truncate_from(vec![7]),
+			rank: ModeratorRank::Junior,
+		};
+		frame_support::storage::unhashed::put(&Moderators::<Test>::hashed_key_for(10), &old_moderator);
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		// the movie and tag reports can't point at their content anymore and are dropped,
+		// the stake of the one that was still open is made claimable
+		assert_eq!(ModerationModule::next_report_id(), 1);
+		assert_eq!(v0::Reports::<Test>::iter_keys().count(), 0);
+		assert_eq!(v0::ReportVerdicts::<Test>::iter_keys().count(), 0);
+		let reporter_tokens = StatTrackerModule::get_wallet_tokens(2).unwrap();
+		assert_eq!((reporter_tokens.locked_tokens_moderation, reporter_tokens.claimable_tokens_moderation), (0, 3_000));
+
+		let report = ModerationModule::report(0).unwrap();
+		assert_eq!((report.content.clone(), report.status), (ContentRef::Festival(7), ReportStatus::InResolution));
+		assert_eq!(ModerationModule::content_reports(ContentRef::Festival(7), 0), Some(ReportStatus::InResolution));
+		assert_eq!(ModerationModule::report_deadline(0), Some(System::block_number() + ReportVotingPeriod::get()));
+		let verdict = ModerationModule::report_tier_verdict(0, Tiers::TierOne).unwrap();
+		assert_eq!((verdict.votes_for, verdict.votes[0].voter, verdict.votes[0].is_for), (1, 10, VoteChoice::For));
+
+		// the moderator is still drafted for the report it was voting on
+		let moderator = Moderators::<Test>::get(10).unwrap();
+		assert_eq!(moderator.assigned_reports.to_vec(), vec![0]);
+		assert_eq!(ModerationModule::do_get_uncommitted_moderators(0), vec![10]);
+		assert_eq!((moderator.status, moderator.bonded_tokens), (ModeratorStatus::Active, 10_000));
+	});
+}
+
+#[test]
+fn reports_are_migrated_a_page_per_block_and_their_deadlines_postponed() {
+	new_test_ext().execute_with(|| {
+		use crate::migrations::{self, v0::{self, OldContentType, OldReport, OldReportStatus}, v1::{MigratingReports, OpenReports}, MIGRATION_PAGE_SIZE};
+
+		let reports = MIGRATION_PAGE_SIZE + 1;
+		for content_id in 0..reports {
+			v0::Reports::<Test>::insert((content_id, OldContentType::Festival), OldReport {
+				reporter_id: 1u64,
+				reportee_id: CONTENT_OWNER,
+				justification: BoundedVec::<u8, ReportJustificationLimit>::truncate_from(b"spam".to_vec()),
+				status: OldReportStatus::InResolution,
+				categories_and_tags: BoundedVec::default(),
+			});
+		}
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert!(migrations::is_migrating::<Test>());
+		assert_eq!(ModerationModule::next_report_id(), 0);
+
+		// a report due while migrating is pushed back until the migration is done
+		assert_ok!(ReportDeadlines::<Test>::try_mutate(1, |deadlines| deadlines.try_push(99)));
+		ReportDeadline::<Test>::insert(99, 1);
+		let mut block = 1;
+		while migrations::is_migrating::<Test>() {
+			System::set_block_number(block);
+			ModerationModule::on_initialize(block);
+			block += 1;
+		}
+		assert!(block > 2);
+		assert_eq!(ReportDeadline::<Test>::get(99), Some(block));
+		assert!(ReportDeadlines::<Test>::get(block).contains(&99));
+
+		assert_eq!(ModerationModule::next_report_id(), reports);
+		assert_eq!(crate::ContentReports::<Test>::iter_keys().count(), reports as usize);
+		assert_eq!(v0::Reports::<Test>::iter_keys().count(), 0);
+		assert_eq!(MigratingReports::<Test>::iter_keys().count(), 0);
+		assert_eq!(OpenReports::<Test>::iter_keys().count(), 0);
+	});
+}
@@ -52,6 +52,8 @@
                 use kine_tags::{
                     CategoryId as CategoryId,
                     TagId as TagId,
+                    ContentModeration,
+                    ContentOwnership,
                 };
                 // use kine_stat_tracker::*;
     
@@ -73,6 +75,22 @@
                     type LinkStringLimit: Get<u32>;
     
                     type MovieCollateral: Get<u32>;
+
+                    // notified whenever a movie is created or re-tagged
+                    type OnMovieTagged: MovieTagsHandler<
+                        BoundedVec<u8, Self::LinkStringLimit>,
+                        BoundedVec<(CategoryId<Self>, TagId<Self>), Self::MaxTags>,
+                    >;
+                }
+
+                // Lets other pallets react to a movie's categories_and_tags, e.g. ranking lists
+                // that automatically include movies with a given tag.
+                pub trait MovieTagsHandler<MovieId, CategoryTagList> {
+                    fn on_movie_tagged(movie_id: &MovieId, categories_and_tags: &CategoryTagList);
+                }
+
+                impl<MovieId, CategoryTagList> MovieTagsHandler<MovieId, CategoryTagList> for () {
+                    fn on_movie_tagged(_movie_id: &MovieId, _categories_and_tags: &CategoryTagList) {}
                 }
     
         
@@ -161,6 +179,19 @@
                     BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
                 >
             >;
+
+
+            //* Moderation *//
+
+            // Movies hidden by a successful report. They can't join or be voted
+            // for in festivals and ranking lists until they are reinstated.
+            #[pallet::storage]
+            pub type SuspendedMovies<T: Config> = StorageMap<
+                _, 
+                Blake2_128Concat, BoundedVec<u8, T::LinkStringLimit>,
+                (),
+                OptionQuery
+            >;
     
     
     
@@ -174,6 +205,9 @@
             pub enum Event<T: Config> {
                 InternalMovieCreated(String, T::AccountId),
                 ExternalMovieCreated(String, T::AccountId),
+                MovieTagsUpdated(String, T::AccountId),
+                MovieSuspended(BoundedVec<u8, T::LinkStringLimit>),
+                MovieReinstated(BoundedVec<u8, T::LinkStringLimit>),
             }
        
     
@@ -187,6 +221,8 @@
                 Underflow,
                 BadMetadata,
                 WalletStatsRegistryRequired,
+                NotMovieUploader,
+                MovieNotSuspended,
             }
     
     
@@ -257,6 +293,64 @@
     
                     Ok(().into())
                 }
+
+                // Replace the categories and tags of a movie. Only the uploader can re-tag it.
+                #[pallet::call_index(2)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2,2))]
+                pub fn update_movie_tags(
+                    origin: OriginFor<T>,
+                    movie_id_str: String,
+                    category_tag_list: BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
+                ) -> DispatchResultWithPostInfo {
+                    
+                    let who = ensure_signed(origin)?;
+                    let movie_id: BoundedVec<u8, T::LinkStringLimit> =
+                        TryInto::try_into(movie_id_str.as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
+                    ensure!(Self::get_movie_uploader(movie_id.clone())? == who, Error::<T>::NotMovieUploader);
+
+                    let category_type: kine_tags::CategoryType<T>
+                        = TryInto::try_into("Movie".as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
+                    kine_tags::Pallet::<T>::do_validate_tag_data(
+                        category_type.clone(), 
+                        category_tag_list.clone()
+                    )?;
+
+                    let old_category_tag_list = 
+                        if InternalMovies::<T>::contains_key(movie_id.clone()) {
+                            InternalMovies::<T>::try_mutate(movie_id.clone(), |movie| -> Result<_, DispatchError> {
+                                let movie = movie.as_mut().ok_or(Error::<T>::NoAvailableMovieId)?;
+                                Ok(core::mem::replace(&mut movie.categories_and_tags, category_tag_list.clone()))
+                            })?
+                        }
+                        else {
+                            ExternalMovies::<T>::try_mutate(movie_id.clone(), |movie| -> Result<_, DispatchError> {
+                                let movie = movie.as_mut().ok_or(Error::<T>::NoAvailableMovieId)?;
+                                Ok(core::mem::replace(&mut movie.categories_and_tags, category_tag_list.clone()))
+                            })?
+                        };
+
+                    // keep the tag index in sync, only touching the pairs that changed
+                    let bounded_content_id: BoundedVec<u8, T::ContentStringLimit> = 
+                        TryInto::try_into(movie_id.to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
+                    let removed_tags: BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags> = TryInto::try_into(
+                        old_category_tag_list.iter()
+                        .filter(|pair| !category_tag_list.contains(pair))
+                        .cloned()
+                        .collect::<Vec<_>>()
+                    ).map_err(|_|Error::<T>::BadMetadata)?;
+                    let added_tags: BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags> = TryInto::try_into(
+                        category_tag_list.iter()
+                        .filter(|pair| !old_category_tag_list.contains(pair))
+                        .cloned()
+                        .collect::<Vec<_>>()
+                    ).map_err(|_|Error::<T>::BadMetadata)?;
+                    kine_tags::Pallet::<T>::do_remove_tag_data(category_type.clone(), removed_tags, bounded_content_id.clone())?;
+                    kine_tags::Pallet::<T>::do_update_tag_data(category_type, added_tags, bounded_content_id)?;
+
+                    T::OnMovieTagged::on_movie_tagged(&movie_id, &category_tag_list);
+
+                    Self::deposit_event(Event::MovieTagsUpdated(movie_id_str, who));
+                    Ok(().into())
+                }
     
             }
     
@@ -386,10 +480,12 @@
     
                     kine_tags::Pallet::<T>::do_update_tag_data(
                         category_type, 
-                        category_tag_list,
+                        category_tag_list.clone(),
                         bounded_content_id,
                     )?;
 
+                    T::OnMovieTagged::on_movie_tagged(&bounded_movie_id, &category_tag_list);
+
                     Self::deposit_event(Event::InternalMovieCreated(name, who.clone()));
                     Ok(().into())
                 } 
@@ -428,10 +524,11 @@
                     let movie = ExternalMovie {
                         uploader:who.clone(),
                         source: source,
-                        categories_and_tags: category_tag_list,
+                        categories_and_tags: category_tag_list.clone(),
                     };
                 
                     ExternalMovies::<T>::insert(link.clone(), movie.clone());
+                    T::OnMovieTagged::on_movie_tagged(&link, &category_tag_list);
             
                     Self::deposit_event(Event::ExternalMovieCreated(link_str, who.clone()));
                     Ok(().into())
@@ -485,17 +582,63 @@
                     movie_id : BoundedVec<u8, T::LinkStringLimit>,
                 ) -> Result<T::AccountId, DispatchError> {
                     
-                    let mut uploader;
+                    let uploader;
                     if InternalMovies::<T>::contains_key(movie_id.clone()) {
-                        uploader = InternalMovies::<T>::get(movie_id).unwrap().uploader;
+                        uploader = InternalMovies::<T>::get(movie_id).ok_or(Error::<T>::NoAvailableMovieId)?.uploader;
                     }
                     else {
-                        uploader = ExternalMovies::<T>::try_get(movie_id).unwrap().uploader;
+                        uploader = ExternalMovies::<T>::get(movie_id).ok_or(Error::<T>::NoAvailableMovieId)?.uploader;
                     }
                     
                     Ok(uploader)
                 }
+
+                pub fn is_movie_suspended(
+                    movie_id : &BoundedVec<u8, T::LinkStringLimit>,
+                ) -> bool {
+                    SuspendedMovies::<T>::contains_key(movie_id)
+                }
     
     
             }
+
+
+            impl<T: Config> ContentOwnership<BoundedVec<u8, T::LinkStringLimit>, T::AccountId> for Pallet<T> {
+
+                fn content_owner(
+                    movie_id: &BoundedVec<u8, T::LinkStringLimit>,
+                ) -> Result<Option<T::AccountId>, DispatchError> {
+
+                    Ok(Some(Self::get_movie_uploader(movie_id.clone())?))
+                }
+            }
+
+
+            impl<T: Config> ContentModeration<BoundedVec<u8, T::LinkStringLimit>> for Pallet<T> {
+
+                fn suspend_content(
+                    movie_id: &BoundedVec<u8, T::LinkStringLimit>,
+                ) -> DispatchResult {
+
+                    ensure!(
+                        InternalMovies::<T>::contains_key(movie_id) || ExternalMovies::<T>::contains_key(movie_id),
+                        Error::<T>::NoAvailableMovieId
+                    );
+                    SuspendedMovies::<T>::insert(movie_id, ());
+
+                    Self::deposit_event(Event::MovieSuspended(movie_id.clone()));
+                    Ok(())
+                }
+
+                fn reinstate_content(
+                    movie_id: &BoundedVec<u8, T::LinkStringLimit>,
+                ) -> DispatchResult {
+
+                    ensure!(SuspendedMovies::<T>::contains_key(movie_id), Error::<T>::MovieNotSuspended);
+                    SuspendedMovies::<T>::remove(movie_id);
+
+                    Self::deposit_event(Event::MovieReinstated(movie_id.clone()));
+                    Ok(())
+                }
+            }
     }
\ No newline at end of file
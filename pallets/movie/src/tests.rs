@@ -1,5 +1,12 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, Error, ExternalSource};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use kine_tags::ContentModeration;
+
+const LINK: &str = "https://youtu.be/kinera";
+
+fn movie_id() -> BoundedVec<u8, LinkStringLimit> {
+	BoundedVec::truncate_from(LINK.as_bytes().to_vec())
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -21,6 +28,24 @@ fn correct_error_for_none_value() {
 // create_external_movie
 
 
+// suspend_content
+
+#[test]
+fn movies_are_suspended_and_reinstated() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(MovieModule::suspend_content(&movie_id()), Error::<Test>::NoAvailableMovieId);
+		assert_ok!(MovieModule::create_external_movie(RuntimeOrigin::signed(1), ExternalSource::Youtube, LINK.into(), BoundedVec::default()));
+
+		assert_ok!(MovieModule::suspend_content(&movie_id()));
+		assert!(MovieModule::is_movie_suspended(&movie_id()));
+
+		assert_ok!(MovieModule::reinstate_content(&movie_id()));
+		assert!(!MovieModule::is_movie_suspended(&movie_id()));
+		assert_noop!(MovieModule::reinstate_content(&movie_id()), Error::<Test>::MovieNotSuspended);
+	});
+}
+
+
 
 
 // do_create_internal_movie
@@ -0,0 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	// Ranking history kept in the RankingSnapshots storage.
+	pub trait RankingHistoryApi<BlockNumber, Balance> where
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		// (period index, block of the snapshot, 1-based rank, voting power) of each
+		// retained snapshot where the movie was ranked
+		fn movie_rank_history(list_id: u32, movie_id: Vec<u8>) -> Vec<(u32, BlockNumber, u32, Balance)>;
+	}
+}
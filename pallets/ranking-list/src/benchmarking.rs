@@ -1,9 +1,118 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for kine-ranking-list
 
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_system::RawOrigin;
+use crate::Pallet as RankingListPallet;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{dispatch::DispatchResult, traits::Currency, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use scale_info::prelude::string::String;
+use sp_runtime::{traits::AccountIdConversion, Perbill};
+use sp_std::vec;
 
+type BalanceOf<T> = <<T as kine_stat_tracker::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+const MOVIE: &str = "benchmark-movie";
+const MAX_EXISTING_VOTERS: u32 = 1_000;
+
+// Inserts an ongoing list with a single movie, skipping the movie pallet.
+fn setup_list<T: Config>() -> (RankingListId, BoundedVec<u8, T::LinkStringLimit>) {
+	let list_id: RankingListId = 0;
+	let movie_id: BoundedVec<u8, T::LinkStringLimit> = MOVIE.as_bytes().to_vec().try_into().unwrap();
+
+	let ranking_list = RankingList {
+		name: BoundedVec::default(),
+		description: BoundedVec::default(),
+		status: RankingListStatus::Ongoing,
+		list_duration: BlockNumberFor::<T>::from(1_000_000u32),
+		list_deadline: BlockNumberFor::<T>::from(1_000_000u32),
+		movies_in_list: vec![movie_id.clone()].try_into().unwrap(),
+		total_voters: 0,
+		total_lockup: BalanceOf::<T>::from(0u32),
+		categories_and_tags: BoundedVec::default(),
+	};
+	RankingLists::<T>::insert(list_id, ranking_list);
+
+	(list_id, movie_id)
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, 0);
+	<T as kine_stat_tracker::Config>::Currency::make_free_balance_be(
+		&who, BalanceOf::<T>::from(1_000_000_000u32),
+	);
+	who
+}
+
+// Adds `v` voters backing the list's movie.
+fn add_voters<T: Config>(
+	list_id: RankingListId,
+	movie_id: &BoundedVec<u8, T::LinkStringLimit>,
+	v: u32,
+) -> DispatchResult {
+	for i in 0 .. v {
+		let voter = funded_account::<T>("voter", i);
+		RankingLists::<T>::try_mutate(list_id, |ranking_list| -> DispatchResult {
+			let list = ranking_list.as_mut().ok_or(Error::<T>::RankingListNotFound)?;
+			RankingListPallet::<T>::do_add_vote(
+				list, list_id, &voter, movie_id.clone(),
+				BalanceOf::<T>::from(1_000u32), Conviction::Locked1x,
+			)
+		})?;
+	}
+	Ok(())
+}
+
+benchmarks! {
+	// `v` voters already backed the movie, the cost of a new vote should not depend on it.
+	vote_for {
+		let v in 0 .. MAX_EXISTING_VOTERS;
+		let (list_id, movie_id) = setup_list::<T>();
+		add_voters::<T>(list_id, &movie_id, v)?;
+
+		let caller: T::AccountId = whitelisted_caller();
+		<T as kine_stat_tracker::Config>::Currency::make_free_balance_be(
+			&caller, BalanceOf::<T>::from(1_000_000_000u32),
+		);
+	}: _(RawOrigin::Signed(caller.clone()), list_id, String::from(MOVIE), BalanceOf::<T>::from(1_000u32), Conviction::Locked1x)
+	verify {
+		assert_eq!(RankingLists::<T>::get(list_id).unwrap().total_voters, v + 1);
+		assert!(ListVotes::<T>::contains_key(list_id, caller));
+	}
+
+	// Resolving a round only reads the movie tallies, `v` is the number of voters in the list.
+	resolve_ranking_list {
+		let v in 0 .. MAX_EXISTING_VOTERS;
+		let (list_id, movie_id) = setup_list::<T>();
+		add_voters::<T>(list_id, &movie_id, v)?;
+	}: {
+		RankingListPallet::<T>::resolve_ranking_list(list_id, Perbill::one())?;
+	}
+	verify {
+		assert!(RankingSnapshots::<T>::contains_key(list_id, 0));
+	}
+
+	// Pays a resolved round's rewards to a page of `v` voters.
+	process_voter_pages {
+		let v in 1 .. T::VoterPageSize::get();
+		let (list_id, movie_id) = setup_list::<T>();
+		add_voters::<T>(list_id, &movie_id, v)?;
+
+		let pot: T::AccountId = <T as Config>::PalletId::get().into_account_truncating();
+		<T as kine_stat_tracker::Config>::Currency::make_free_balance_be(
+			&pot, BalanceOf::<T>::from(1_000_000_000u32),
+		);
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(500_000u32));
+		let reward_scale = RankingListPallet::<T>::do_lists_reward_scale(&[list_id])?;
+		RankingListPallet::<T>::resolve_ranking_list(list_id, reward_scale)?;
+		assert!(PendingPayouts::<T>::contains_key(list_id, 0));
+	}: {
+		RankingListPallet::<T>::do_process_voter_pages();
+	}
+	verify {
+		assert!(ListVotes::<T>::iter_prefix(list_id).all(|(_, voter)| voter.rewarded_until == 1));
+	}
+
+	impl_benchmark_test_suite!(RankingListPallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
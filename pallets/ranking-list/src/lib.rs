@@ -3,14 +3,9 @@
 	// based on locked funds.
 	// new era > find staking differences > reward top 1000 films
 	
-	//TODO-0 add dynamic APY to governance
 	//TODO-1 add dynamic deadlines, where if a MaxListsPerBlock is exceeded, a new block automatically calculated 
-	//TODO-2 compare mint_into vs deposit_into_existing
 	//TODO-3 validate the inserted deadline when creating a ranking list
-	//TODO-4 improve the blocks_per_year calculation by creating a static variable in stat-tracker
 	//TODO-5 sort tied entries by total votes after sorting the winners
-	//TODO-6 optimize into a map of maps, to avoid iterating the list.
-	//TODO-7 reimplement conviction, currently locked at x1 when resolving_list, and does nothing
 
 
 	#![cfg_attr(not(feature = "std"), no_std)]
@@ -25,12 +20,29 @@
 	
 	#[cfg(feature = "runtime-benchmarks")]
 	mod benchmarking;
-	
+
+	pub mod migrations;
+
 	
 	#[frame_support::pallet]
 	pub mod pallet {
-		// const EXAMPLE_ID: LockIdentifier = *b"example ";
-		
+		// votes are locked in the voter's own account under this identifier
+		pub const RANKING_LOCK_ID: LockIdentifier = *b"kine/rnk";
+		// delegation pools track their rewards per staked token with this precision
+		pub const REWARD_PER_STAKE_PRECISION: u32 = 1_000_000_000;
+		// pairwise lists start every movie at this Elo rating
+		pub const INITIAL_PAIRWISE_RATING: u32 = 1_500;
+		// curation reputation gained by the voters backing a round's top movie
+		pub const TOP_MOVIE_VOTER_REPUTATION: u32 = 2;
+		// passes made over a round's voters to retry the payouts that failed
+		pub const MAX_PAYOUT_PASSES: u8 = 3;
+		// expected Elo score, in parts per billion, for rating differences of 0, 50, .., 800
+		const ELO_EXPECTED_SCORES: [u32; 17] = [
+			500_000_000, 571_463_117, 640_065_000, 703_385_003, 759_746_927, 808_317_673,
+			849_020_443, 882_338_297, 909_090_909, 930_241_713, 946_759_785, 959_536_674,
+			969_346_570, 976_835_578, 982_527_909, 986_840_273, 990_099_010,
+		];
+
 		//** Config **//
 	
 			//* Imports *//
@@ -41,7 +53,12 @@
 					traits::{
 						Currency,
 						ReservableCurrency,
-						ExistenceRequirement::AllowDeath,
+						LockableCurrency,
+						LockIdentifier,
+						WithdrawReasons,
+						ExistenceRequirement::{AllowDeath, KeepAlive},
+						BalanceStatus,
+						Randomness,
 					},
 					PalletId,
 					BoundedVec,
@@ -61,7 +78,9 @@
 						AccountIdConversion,
 						Saturating,
 						CheckedDiv,
+						Hash,
 					},
+					Perbill,
 				};
 				use scale_info::{
                     TypeInfo,
@@ -76,11 +95,20 @@
 				use kine_tags::{
 					CategoryId as CategoryId,
 					TagId as TagId,
+					ContentModeration,
+					ContentOwnership,
+				};
+				use kine_stat_tracker::{
+					ReputationDomain,
+					ReputationHandler,
 				};
 	
 	
 			//* Config *//
+				const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 				#[pallet::pallet]
+				#[pallet::storage_version(STORAGE_VERSION)]
 				pub struct Pallet<T>(_);
 	
 				#[pallet::config]
@@ -90,16 +118,70 @@
 					type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 					// type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
 					
+					// locks the voters' tokens in place while they back a movie
+					type LockCurrency: LockableCurrency<
+						Self::AccountId,
+						Moment = BlockNumberFor<Self>,
+						Balance = BalanceOf<Self>,
+					>;
+
 					// how many ranking lists can be solved per block
 					type MaxListsPerBlock: Get<u32>;
 					type MaxVotersPerList: Get<u32>;
+					type MaxVotesPerVoter: Get<u32>;
 					type MaxMoviesInList: Get<u32>;
 	
 					// the minimum amount of blocks between a ranking list's refresh period
 					type MinimumListDuration: Get<u32>;
 	
 					type RankingStringLimit: Get<u32>;
+
+					// lists with an eligibility rule receive matching movies automatically,
+					// at most MaxAutoInsertionsPerBlock per block, the rest waits in a queue
+					type MaxAutoInsertionsPerBlock: Get<u32>;
+					type MaxPendingInsertions: Get<u32>;
+					type ListAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+					// reserved from the creator, returned when the list is closed cleanly
+					type ListCreationDeposit: Get<BalanceOf<Self>>;
+
+					// how much a single judgement can move the ratings of a pairwise list
+					type EloKFactor: Get<u32>;
+
+					// each account can submit up to MaxJudgementsPerWindow judgements every JudgementWindow blocks
+					type MaxJudgementsPerWindow: Get<u32>;
+					type JudgementWindow: Get<u32>;
+					// the pairs of movies to judge are drawn from it
+					type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+					// voting power can be delegated to curators, who allocate it in up to
+					// MaxListsPerCurator lists, with at most MaxCuratorsPerList curators per list
+					type MaxCuratorsPerList: Get<u32>;
+					type MaxListsPerCurator: Get<u32>;
+					// the highest commission a curator can keep from the delegators' rewards
+					type MaxCuratorCommission: Get<Perbill>;
+
+					// how many voters are refunded or paid their rewards per block
+					type VoterPageSize: Get<u32>;
+
+					// how many past snapshots are kept for each list, each one
+					// holding the top SnapshotDepth movies of its round
+					type SnapshotRetention: Get<u32>;
+					type SnapshotDepth: Get<u32>;
+
+					// only voters backing the top N movies of a list are rewarded
+					type RewardedMoviesPerList: Get<u32>;
+					// rewards the curation of the voters backing each round's top movie
+					type Reputation: ReputationHandler<Self::AccountId>;
+					type BlocksPerYear: Get<u32>;
+
+					// starting reward parameters, these can later be updated by the RewardsOrigin
+					type DefaultRewardApy: Get<Perbill>;
+					type DefaultRewardPeriod: Get<BlockNumberFor<Self>>;
+					type DefaultRewardBudget: Get<BalanceOf<Self>>;
+					type RewardsOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	
+					// the vault doubles as the ranking rewards pot
 					type PalletId : Get<PalletId>;
 				}
 	
@@ -114,14 +196,71 @@
 				type BalanceOf<T> = <<T as kine_stat_tracker::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 	
 				pub type RankingListId = u32;	
+
+				pub type RankingListOf<T> = RankingList<
+					BoundedVec<u8, <T as Config>::RankingStringLimit>,
+					RankingListStatus,
+					BlockNumberFor<T>,
+					BoundedVec<BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, <T as Config>::MaxMoviesInList>,
+					BalanceOf<T>,
+					BoundedVec<(CategoryId<T>, TagId<T>), <T as kine_tags::Config>::MaxTags>,
+				>;
+
+				pub type ListVoterOf<T> = ListVoter<
+					BoundedBTreeMap<
+						BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, 
+						RankingVote<BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>, 
+						<T as Config>::MaxVotesPerVoter,
+					>,
+				>;
+
+				pub type RewardPayoutOf<T> = RewardPayout<
+					BlockNumberFor<T>,
+					BalanceOf<T>,
+					BoundedVec<BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, <T as Config>::RewardedMoviesPerList>,
+					BoundedVec<u8, ConstU32<256>>,
+				>;
+
+				pub type CuratorPositionOf<T> = CuratorPosition<
+					BoundedVec<(BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, Perbill), <T as Config>::MaxVotesPerVoter>,
+					BalanceOf<T>,
+				>;
 				
 			//* Constants *//
 			//* Enums *//
 	
 				#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug,TypeInfo,MaxEncodedLen)]
+				// Suspended lists are hidden by a successful report, their rounds
+				// are paused until the list is reinstated.
 				pub enum RankingListStatus {
 					Ongoing,
 					Finished,
+					Archived,
+					Suspended,
+				}
+
+				// Perpetual lists start a new round after every deadline,
+				// fixed lists are closed after their last round is resolved.
+				#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug,TypeInfo,MaxEncodedLen)]
+				pub enum ListMode {
+					Perpetual,
+					FixedRounds(u32),
+				}
+
+				// Stake lists are ordered by the voting power behind each movie, pairwise lists
+				// by the Elo ratings that come out of "A beats B" judgements.
+				#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug,TypeInfo,MaxEncodedLen, Default)]
+				pub enum RankingMode {
+					#[default]
+					Stake,
+					Pairwise,
+				}
+
+				// Voting power is delegated either for every list or for a single one.
+				#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug,TypeInfo,MaxEncodedLen)]
+				pub enum DelegationScope {
+					AllLists,
+					List(RankingListId),
 				}
 	
 				#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug,TypeInfo,MaxEncodedLen)]
@@ -146,17 +285,27 @@
 			//* Structs *//
 	
 				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug,TypeInfo, MaxEncodedLen)]
-				pub struct RankingList<BoundedString, RankingListStatus, BlockNumber, MovieList, VoteMap, Balance, CategoryTagList> {
+				pub struct RankingList<BoundedString, RankingListStatus, BlockNumber, MovieList, Balance, CategoryTagList> {
 					pub name: BoundedString,
 					pub description: BoundedString,
 					pub status: RankingListStatus,
 					pub list_duration: BlockNumber,
 					pub list_deadline: BlockNumber,
 					pub movies_in_list: MovieList, // this becomes a sorted winner list after the "list_deadline" block
-					pub votes_by_user: VoteMap, // map of type {user_id : {movie_id : vote}}
+					pub total_voters: u32, // the votes themselves are kept in ListVotes
 					pub total_lockup: Balance,
 					pub categories_and_tags: CategoryTagList,
 				}
+
+				// Ownership and lifecycle of a user created list. Lists from the genesis have none.
+				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+				pub struct ListInfo<AccountId, Balance> {
+					pub owner: AccountId,
+					pub curator: Option<AccountId>,
+					pub mode: ListMode,
+					pub rounds_completed: u32,
+					pub deposit: Balance,
+				}
 	
 				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 				pub struct RankingVote<MovieId, BalanceOf, BlockNumber> {
@@ -164,6 +313,87 @@
 					pub locked_amount: BalanceOf,
 					pub conviction: Conviction,
 					pub unlock_block: BlockNumber,
+					// topping up a vote restarts its clock for time-weighted rewards
+					pub staked_since: BlockNumber,
+				}
+
+				// A voter's votes in one list. Rounds before `rewarded_until` were already paid to them.
+				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+				pub struct ListVoter<Votes> {
+					pub votes: Votes,
+					pub rewarded_until: u32,
+				}
+
+				// Running totals of a movie in a list, so a round resolves without reading every vote.
+				// `weighted_since` adds up each stake times the block it started counting in the current round.
+				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+				pub struct MovieTally<Balance> {
+					pub power: Balance,
+					pub stake: Balance,
+					pub weighted_since: Balance,
+				}
+
+				// A resolved round whose rewards are being paid out to the list's voters, a page per block.
+				// `last_key` is the storage key of the last voter reached. Voters whose payout failed
+				// (`failed` in the current pass) are retried in another pass over the voters.
+				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+				pub struct RewardPayout<BlockNumber, Balance, MovieList, RawKey> {
+					pub block: BlockNumber,
+					pub round_start: BlockNumber,
+					pub top_movies: MovieList,
+					pub apy: Perbill,
+					pub scale: Perbill,
+					pub planned: Balance,
+					pub paid: Balance,
+					pub last_key: Option<RawKey>,
+					pub failed: u32,
+					pub pass: u8,
+				}
+
+				// Tokens a delegator locked and handed to a curator. `reward_debt` is the part of
+				// the pool's rewards that was already accounted for this delegation.
+				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+				pub struct Delegation<AccountId, Balance> {
+					pub curator: AccountId,
+					pub amount: Balance,
+					pub conviction: Conviction,
+					pub reward_debt: Balance,
+				}
+
+				// Everything delegated to a curator for one scope, and the rewards earned per staked
+				// token (scaled by REWARD_PER_STAKE_PRECISION) since the pool was created.
+				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+				pub struct DelegationPool<Balance> {
+					pub stake: Balance,
+					pub power: Balance,
+					pub reward_per_stake: Balance,
+				}
+
+				// How a curator spreads their delegated stake over a list's movies. The position
+				// counts in the movie tallies like a vote, with `weighted_since` tracked as in MovieTally.
+				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+				pub struct CuratorPosition<Allocations, Balance> {
+					pub allocations: Allocations,
+					pub stake: Balance,
+					pub power: Balance,
+					pub weighted_since: Balance,
+					// the curator's commission when the list's round started
+					pub commission: Perbill,
+				}
+
+				// The outcome of a list round, movies ordered by voting power.
+				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+				pub struct RankingSnapshot<BlockNumber, RankedMovies> {
+					pub block: BlockNumber,
+					pub ranked_movies: RankedMovies,
+					pub total_voters: u32,
+				}
+
+				#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+				pub struct RewardParameters<Balance, BlockNumber> {
+					pub apy: Perbill,
+					pub period: BlockNumber,
+					pub budget: Balance,
 				}
 	
 	
@@ -221,16 +451,6 @@
 						let list_deadline_block = current_block.checked_add(&duration_blocks.clone()).ok_or(Error::<T>::Overflow).unwrap();
 						Pallet::<T>::create_list_deadline(ranking_list_id, list_deadline_block).unwrap();
 					
-						let votes_by_user: BoundedBTreeMap<
-							T::AccountId, 
-							BoundedBTreeMap<
-								BoundedVec<u8, T::LinkStringLimit>, 
-								RankingVote<BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>, 
-								T::MaxVotersPerList
-							>, 
-							T::MaxVotersPerList,
-						>
-						= BoundedBTreeMap::new();
 						let total_lockup = BalanceOf::<T>::from(0u32);
 	
 						let ranking_list = RankingList {
@@ -240,7 +460,7 @@
 							list_deadline: list_deadline_block.clone(),
 							list_duration: duration_blocks.clone(),
 							movies_in_list: movies_in_list.clone(),
-							votes_by_user: votes_by_user.clone(),
+							total_voters: 0,
 							total_lockup: total_lockup.clone(),
 							categories_and_tags: categories_and_tags.clone(),
 						};
@@ -279,15 +499,6 @@
 					RankingListStatus,
 					BlockNumberFor<T>,
 					BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInList>, //Movies in List
-					BoundedBTreeMap<
-						T::AccountId, 
-						BoundedBTreeMap<
-							BoundedVec<u8, T::LinkStringLimit>, 
-							RankingVote<BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>, 
-							T::MaxVotersPerList
-						>, 
-						T::MaxVotersPerList,
-					>,
 					BalanceOf<T>,
 					BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
 				>
@@ -296,6 +507,313 @@
 
 			
 	
+			// Each voter's votes in a list, keyed by (list id, voter).
+			#[pallet::storage]
+			#[pallet::getter(fn list_votes)]
+			pub type ListVotes<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				Blake2_128Concat, T::AccountId,
+				ListVoterOf<T>,
+			>;
+
+			// Voting power and stake of each movie in a list, updated with every vote.
+			#[pallet::storage]
+			#[pallet::getter(fn movie_tally)]
+			pub type MovieTallies<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				Blake2_128Concat, BoundedVec<u8, T::LinkStringLimit>,
+				MovieTally<BalanceOf<T>>,
+				ValueQuery,
+			>;
+
+			// Resolved rounds whose rewards are still being paid out, keyed by (list id, period index).
+			#[pallet::storage]
+			#[pallet::getter(fn pending_payout)]
+			pub type PendingPayouts<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				Twox64Concat, u32,
+				RewardPayoutOf<T>,
+			>;
+
+			// Closed lists whose voters are still being refunded, with the raw key
+			// of the last voter that was handled.
+			#[pallet::storage]
+			pub type PendingRefunds<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				Option<BoundedVec<u8, ConstU32<256>>>,
+			>;
+
+			// Accounts accepting delegations, with the commission they keep from the delegators' rewards.
+			// These curators are unrelated to the curator role of a list.
+			#[pallet::storage]
+			#[pallet::getter(fn curator_commission)]
+			pub type CuratorCommissions<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, T::AccountId,
+				Perbill,
+			>;
+
+			// Each delegator's delegations, keyed by (delegator, scope).
+			#[pallet::storage]
+			#[pallet::getter(fn delegation)]
+			pub type Delegations<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, T::AccountId,
+				Blake2_128Concat, DelegationScope,
+				Delegation<T::AccountId, BalanceOf<T>>,
+			>;
+
+			// Totals delegated to each curator, keyed by (curator, scope).
+			#[pallet::storage]
+			#[pallet::getter(fn delegation_pool)]
+			pub type DelegationPools<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, T::AccountId,
+				Blake2_128Concat, DelegationScope,
+				DelegationPool<BalanceOf<T>>,
+				ValueQuery,
+			>;
+
+			// Undelegated tokens still locked with their conviction, as (amount, unlock block).
+			#[pallet::storage]
+			#[pallet::getter(fn delegation_unlock)]
+			pub type DelegationUnlocks<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, T::AccountId,
+				Blake2_128Concat, DelegationScope,
+				(BalanceOf<T>, BlockNumberFor<T>),
+			>;
+
+			// Each curator's position in a list, keyed by (list id, curator).
+			#[pallet::storage]
+			#[pallet::getter(fn curator_position)]
+			pub type CuratorPositions<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				Blake2_128Concat, T::AccountId,
+				CuratorPositionOf<T>,
+			>;
+
+			// The curators holding a position in each list.
+			#[pallet::storage]
+			#[pallet::getter(fn curators_in_list)]
+			pub type CuratorsInList<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				BoundedVec<T::AccountId, T::MaxCuratorsPerList>,
+				ValueQuery,
+			>;
+
+			// The lists each curator holds a position in.
+			#[pallet::storage]
+			#[pallet::getter(fn curated_lists)]
+			pub type CuratedLists<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, T::AccountId,
+				BoundedVec<RankingListId, T::MaxListsPerCurator>,
+				ValueQuery,
+			>;
+
+			// Owner, curator, mode and deposit of each user created list.
+			#[pallet::storage]
+			#[pallet::getter(fn list_info)]
+			pub type ListGovernance<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				ListInfo<T::AccountId, BalanceOf<T>>,
+			>;
+
+			// How each list is ranked. Lists without an entry, like the genesis ones, use stake.
+			#[pallet::storage]
+			#[pallet::getter(fn ranking_mode)]
+			pub type ListRankingModes<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				RankingMode,
+				ValueQuery,
+			>;
+
+			#[pallet::type_value]
+			pub fn DefaultPairwiseRating() -> u32 {
+				INITIAL_PAIRWISE_RATING
+			}
+
+			// Elo rating of each movie in a pairwise list, keyed by (list id, movie id).
+			#[pallet::storage]
+			#[pallet::getter(fn pairwise_rating)]
+			pub type PairwiseRatings<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				Blake2_128Concat, BoundedVec<u8, T::LinkStringLimit>,
+				u32,
+				ValueQuery,
+				DefaultPairwiseRating,
+			>;
+
+			// Start of each account's current judgement window and the judgements submitted in it.
+			#[pallet::storage]
+			#[pallet::getter(fn judgement_allowance)]
+			pub type JudgementAllowances<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, T::AccountId,
+				(BlockNumberFor<T>, u32),
+				ValueQuery,
+			>;
+
+			// The pair of movies each account was drawn to judge in a pairwise list.
+			#[pallet::storage]
+			#[pallet::getter(fn judgement_pair)]
+			pub type JudgementPairs<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				Blake2_128Concat, T::AccountId,
+				(BoundedVec<u8, T::LinkStringLimit>, BoundedVec<u8, T::LinkStringLimit>),
+			>;
+
+			// Increased on every draw, so that draws within the same block differ.
+			#[pallet::storage]
+			pub type JudgementNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+			// Total amount each wallet has locked across all ranking lists.
+			#[pallet::storage]
+			#[pallet::getter(fn voter_locks)]
+			pub type VoterLocks<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, T::AccountId, 
+				BalanceOf<T>,
+				ValueQuery,
+			>;
+
+			// Stakes the v1 migration couldn't return from the vault. They are paid
+			// out of the vault before the voter's lock as their votes are removed.
+			#[pallet::storage]
+			#[pallet::getter(fn legacy_vault_stake)]
+			pub type LegacyVaultStakes<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, T::AccountId, 
+				BalanceOf<T>,
+				ValueQuery,
+			>;
+
+			// Sum of LegacyVaultStakes, kept out of the reward pot.
+			#[pallet::storage]
+			#[pallet::getter(fn total_legacy_vault_stakes)]
+			pub type TotalLegacyVaultStakes<T: Config> = StorageValue<
+				_, 
+				BalanceOf<T>,
+				ValueQuery,
+			>;
+
+			// The (category, tag) pairs that make a movie eligible for a list.
+			#[pallet::storage]
+			#[pallet::getter(fn list_eligibility_rule)]
+			pub type ListEligibilityRules<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
+			>;
+
+			// Reverse index of ListEligibilityRules, used to find the lists a tagged movie belongs to.
+			#[pallet::storage]
+			pub type ListsByEligibleTag<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, (CategoryId<T>, TagId<T>),
+				Blake2_128Concat, RankingListId,
+				(),
+			>;
+
+			// Automatic insertions done in the current block.
+			#[pallet::storage]
+			pub type AutoInsertionsInBlock<T: Config> = StorageValue<
+				_, 
+				(BlockNumberFor<T>, u32),
+				ValueQuery,
+			>;
+
+			// Automatic insertions that exceeded the per block cap, keyed by their position
+			// in the queue. They are processed in order in the next blocks.
+			#[pallet::storage]
+			#[pallet::getter(fn pending_list_insertion)]
+			pub type PendingListInsertions<T: Config> = StorageMap<
+				_, 
+				Twox64Concat, u32,
+				(RankingListId, BoundedVec<u8, T::LinkStringLimit>),
+			>;
+
+			// Position of the first queued insertion and of the next one to be queued.
+			// At most MaxPendingInsertions are waiting at any time.
+			#[pallet::storage]
+			pub type PendingInsertionsRange<T: Config> = StorageValue<
+				_, 
+				(u32, u32),
+				ValueQuery,
+			>;
+
+			// Past rankings of each list, keyed by (list id, period index).
+			// Only the last SnapshotRetention periods of a list are kept, and only their top SnapshotDepth movies.
+			#[pallet::storage]
+			#[pallet::getter(fn ranking_snapshot)]
+			pub type RankingSnapshots<T: Config> = StorageDoubleMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				Twox64Concat, u32,
+				RankingSnapshot<
+					BlockNumberFor<T>,
+					BoundedVec<(BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>), T::SnapshotDepth>,
+				>,
+			>;
+
+			// The period index the next snapshot of a list will be stored under.
+			#[pallet::storage]
+			#[pallet::getter(fn next_snapshot_index)]
+			pub type NextSnapshotIndex<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, RankingListId,
+				u32,
+				ValueQuery,
+			>;
+
+			#[pallet::type_value]
+			pub fn DefaultRewardParameters<T: Config>() -> RewardParameters<BalanceOf<T>, BlockNumberFor<T>> {
+				RewardParameters {
+					apy: T::DefaultRewardApy::get(),
+					period: T::DefaultRewardPeriod::get(),
+					budget: T::DefaultRewardBudget::get(),
+				}
+			}
+
+			// APY, period length and budget per period of the ranking rewards.
+			#[pallet::storage]
+			#[pallet::getter(fn reward_parameters)]
+			pub type RankingRewardParameters<T: Config> = StorageValue<
+				_, 
+				RewardParameters<BalanceOf<T>, BlockNumberFor<T>>,
+				ValueQuery,
+				DefaultRewardParameters<T>,
+			>;
+
+			// The current reward period index and how much of its budget was already handed out.
+			#[pallet::storage]
+			#[pallet::getter(fn reward_period_spending)]
+			pub type RewardPeriodSpending<T: Config> = StorageValue<
+				_, 
+				(BlockNumberFor<T>, BalanceOf<T>),
+				ValueQuery,
+			>;
+
+			// Rewards credited to voters but not yet claimed from the pot.
+			#[pallet::storage]
+			#[pallet::getter(fn outstanding_rewards)]
+			pub type OutstandingRankingRewards<T: Config> = StorageValue<
+				_, 
+				BalanceOf<T>,
+				ValueQuery,
+			>;
+
 			// Matches a block number to all ranking list's that need to be refreshed.
 			// After the set block, the entries are wiped to conserve
 			#[pallet::storage]
@@ -305,6 +823,16 @@
 				Blake2_128Concat, BlockNumberFor<T>, 
 				Deadlines<BoundedVec<RankingListId, T::MaxListsPerBlock>>,
 			>;
+
+			// Blocks whose list deadlines passed while the storage was being migrated.
+			// They are resolved one block at a time once the migration is done.
+			#[pallet::storage]
+			#[pallet::getter(fn postponed_deadline)]
+			pub type PostponedDeadlines<T: Config> = StorageMap<
+				_, 
+				Blake2_128Concat, BlockNumberFor<T>, 
+				(),
+			>;
 	
 	
 	
@@ -319,6 +847,27 @@
 				VotedInList(T::AccountId, RankingListId),	
 				RankingTokensClaimed(T::AccountId, BalanceOf<T>),	
 				RankingListPayoff(RankingListId),	
+				RewardPotFunded(T::AccountId, BalanceOf<T>),
+				RewardParametersUpdated(Perbill, BlockNumberFor<T>, BalanceOf<T>),
+				RankingRewardsDistributed(RankingListId, BalanceOf<T>),
+				EligibilityRuleUpdated(RankingListId),
+				MovieAutoAddedToList(RankingListId, BoundedVec<u8, T::LinkStringLimit>),
+				AutoInsertionDropped(RankingListId, BoundedVec<u8, T::LinkStringLimit>),
+				ListCuratorUpdated(RankingListId, Option<T::AccountId>),
+				RankingListClosed(RankingListId),
+				RankingListArchived(RankingListId),
+				CuratorCommissionSet(T::AccountId, Perbill),
+				Delegated(T::AccountId, T::AccountId, DelegationScope, BalanceOf<T>),
+				Undelegated(T::AccountId, DelegationScope, BalanceOf<T>),
+				DelegationUnlocked(T::AccountId, BalanceOf<T>),
+				DelegationRewardsHarvested(T::AccountId, BalanceOf<T>),
+				CuratorAllocationUpdated(RankingListId, T::AccountId),
+				JudgementPairDrawn(RankingListId, T::AccountId, BoundedVec<u8, T::LinkStringLimit>, BoundedVec<u8, T::LinkStringLimit>),
+				JudgementSubmitted(RankingListId, T::AccountId, BoundedVec<u8, T::LinkStringLimit>, BoundedVec<u8, T::LinkStringLimit>),
+				RankingListSuspended(RankingListId),
+				RankingListReinstated(RankingListId),
+				VoterRefundFailed(RankingListId, T::AccountId),
+				VoterPayoutFailed(RankingListId, T::AccountId),
 			}
 	
 	
@@ -346,6 +895,26 @@
 				NoVoteForMovie,
 				UnstakeValueTooHigh,
 				NoClaimableTokens,
+				RewardPeriodCannotBeZero,
+				TooManyVoters,
+				TooManyVotes,
+				NotListOwner,
+				RankingListNotOngoing,
+				InvalidListMode,
+				CuratorNotRegistered,
+				AlreadyDelegating,
+				NotDelegating,
+				NoDelegationUnlock,
+				AllocationTooHigh,
+				TooManyCurators,
+				TooManyCuratedLists,
+				WrongRankingMode,
+				TooManyJudgements,
+				NotEnoughMoviesToJudge,
+				NoJudgementPair,
+				MovieSuspended,
+				RankingListNotSuspended,
+				CommissionTooHigh,
 			}
 	
 	
@@ -354,11 +923,46 @@
 	
 			#[pallet::hooks]
 			impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-				// fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
-				//     // 0
-				// }
+				// Also reserves the weight of on_finalize: resolving the lists due in this block
+				// and in the postponed block it catches up on.
+				fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+					// nothing else runs until the storage is migrated
+					if crate::migrations::is_migrating::<T>() {
+						return crate::migrations::migrate_page::<T>()
+					}
+
+					let mut weight = T::DbWeight::get().reads(1);
+					let mut blocks = Vec::from([now]);
+					if let Some(postponed_block) = PostponedDeadlines::<T>::iter_keys().next() {
+						blocks.push(postponed_block);
+						weight = weight.saturating_add(T::DbWeight::get().writes(1));
+					}
+
+					let mut lists: u32 = 0;
+					for block in blocks {
+						if let Some(deadlines) = ListDeadlines::<T>::get(block) {
+							lists = lists.saturating_add(deadlines.list_deadlines.len() as u32);
+						}
+					}
+
+					weight
+						.saturating_add(Self::do_get_resolution_weight(lists))
+						.saturating_add(Self::do_process_pending_insertions(now))
+						.saturating_add(Self::do_process_voter_pages())
+				}
 	
 				fn on_finalize(now: BlockNumberFor<T>) {
+					if crate::migrations::is_migrating::<T>() {
+						if ListDeadlines::<T>::contains_key(now) {
+							PostponedDeadlines::<T>::insert(now, ());
+						}
+						return
+					}
+
+					if let Some(postponed_block) = PostponedDeadlines::<T>::iter_keys().next() {
+						PostponedDeadlines::<T>::remove(postponed_block);
+						let _ = Self::do_resolve_lists_deadline(postponed_block);
+					}
 					Self::do_resolve_lists_deadline(now);
 				}
 			}
@@ -377,6 +981,9 @@
 					description: String,
 					list_duration: BlockNumberFor<T>,
 					category_tag_list: BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
+					mode: ListMode,
+					curator: Option<T::AccountId>,
+					ranking_mode: RankingMode,
 				) -> DispatchResultWithPostInfo {
 	
 					let who = ensure_signed(origin)?;
@@ -393,6 +1000,14 @@
 						category_type.clone(), 
 						category_tag_list.clone()
 					)?;
+
+					if let ListMode::FixedRounds(rounds) = mode {
+						ensure!(rounds > 0, Error::<T>::InvalidListMode);
+					}
+
+					// reserve the creation deposit
+					let deposit = T::ListCreationDeposit::get();
+					T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::NotEnoughBalance)?;
 	
 					// create ranking list data
 					let ranking_list_id =
@@ -402,7 +1017,7 @@
 								.checked_add(One::one())
 								.ok_or(Error::<T>::Overflow)?;
 							Ok(current_id)
-						}).unwrap();
+						})?;
 					
 					let bounded_name: BoundedVec<u8, T::RankingStringLimit> =
 						TryInto::try_into(name.as_bytes().to_vec()).map_err(|_| Error::<T>::BadMetadata)?;
@@ -419,17 +1034,6 @@
 					//setup the deadline
 					Self::create_list_deadline(ranking_list_id, list_deadline_block)?;
 	
-					// create the vote's structure, pairing AccountIds to the Vote info.
-					let votes_by_user: BoundedBTreeMap<
-						T::AccountId, 
-						BoundedBTreeMap<
-							BoundedVec<u8, T::LinkStringLimit>, 
-							RankingVote<BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>, 
-							T::MaxVotersPerList
-						>, 
-						T::MaxVotersPerList,
-					>
-					= BoundedBTreeMap::new();
 					let total_lockup = BalanceOf::<T>::from(0u32);
 	
 					// create ranking list struct & insert into storage
@@ -440,11 +1044,19 @@
 						list_deadline: list_deadline_block,
 						list_duration: list_duration,
 						movies_in_list: movies_in_list,
-						votes_by_user: votes_by_user,
+						total_voters: 0,
 						total_lockup: total_lockup,
 						categories_and_tags: category_tag_list.clone(),
 					};
 					RankingLists::<T>::insert(ranking_list_id.clone(), ranking_list);
+					ListGovernance::<T>::insert(ranking_list_id.clone(), ListInfo {
+						owner: who.clone(),
+						curator: curator,
+						mode: mode,
+						rounds_completed: 0,
+						deposit: deposit,
+					});
+					ListRankingModes::<T>::insert(ranking_list_id, ranking_mode);
 	
 					// parse the u32 type into a BoundedVec<u8, T::ContentStringLimit
 					let encoded: Vec<u8> = ranking_list_id.encode();
@@ -484,72 +1096,23 @@
 
 					// ensure movie exists
 					kine_movie::Pallet::<T>::do_ensure_internal_movie_exist(movie_id.clone())?;
+					ensure!(!kine_movie::Pallet::<T>::is_movie_suspended(&movie_id), Error::<T>::MovieSuspended);
 				
 					// insert the movie in the ranking list's movies_list
 					RankingLists::<T>::try_mutate_exists(list_id, |ranking_list| -> DispatchResult {
 						let list = ranking_list.as_mut().ok_or(Error::<T>::BadMetadata)?;
+						ensure!(list.status == RankingListStatus::Ongoing, Error::<T>::RankingListNotOngoing);
 	
 						// ensure no entry for the movie exists in the ranking list
 						ensure!(!list.movies_in_list.contains(&movie_id.clone()), Error::<T>::MovieAlreadyInList);
+						list.movies_in_list.try_push(movie_id.clone()).map_err(|_| Error::<T>::MovieIdOverflow)?;
 	
-						// transfer amount to this pallet's vault
-						T::Currency::transfer(
-							&who.clone(),
-							&Self::account_id(), 
-							amount.clone(), 
-							AllowDeath
-						);
-						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
-							who.clone(), 
-							kine_stat_tracker::FeatureType::RankingList,
-							kine_stat_tracker::TokenType::Locked,
-							amount.clone(), false
-						).unwrap();
-						list.total_lockup =
-							list.total_lockup
-							.checked_add(&amount.clone())
-							.ok_or(Error::<T>::Overflow)?;
-	
-						// create the Vote
-						let unlock_block = Self::do_calculate_unlock_block(conviction).unwrap();
-						let vote = RankingVote {
-							movie_id: movie_id.clone(),
-							locked_amount: amount,
-							conviction: conviction.clone(),
-							unlock_block: unlock_block,
-						};
-
-						
-						// The voter hasn't voted in the list yet
-						if !list.votes_by_user.contains_key(&who.clone()) {
-							// create a new vote list, with the user's vote in it and add it
-							let mut user_votes: BoundedBTreeMap<
-								BoundedVec<u8, T::LinkStringLimit>, 
-								RankingVote<BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>, 
-								T::MaxVotersPerList
-							> = BoundedBTreeMap::new();
-							user_votes.try_insert(movie_id.clone(), vote).unwrap();
-							list.votes_by_user.try_insert(who.clone(), user_votes).unwrap();
-						}
-						// The voter has voted in the list, add a new vote
-						else {
-							let mut user_votes = list.votes_by_user.get_mut(&who.clone()).unwrap();
-							// the voter hasn't voted for the movie, add a vote entry for it
-							if !user_votes.contains_key(&movie_id.clone()) {
-								user_votes.try_insert(movie_id.clone(), vote).unwrap();
-							}
-							// The voter has already voted for the specific movie, update the entry
-							else {
-								let mut movie_vote = user_votes.get_mut(&movie_id.clone()).unwrap();
-								movie_vote.locked_amount =
-									movie_vote.locked_amount
-									.checked_add(&amount.clone())
-									.ok_or(Error::<T>::Overflow)?;
-							}
+						// lock the amount in the voter's account and record the vote,
+						// pairwise lists are ranked by judgements and take the movie without one
+						if ListRankingModes::<T>::get(list_id) == RankingMode::Stake {
+							Self::do_add_vote(list, list_id, &who, movie_id, amount, conviction)?;
 						}
 
-						list.movies_in_list.try_push(movie_id).unwrap();
-
 						Ok(().into())
 					})?;
 					
@@ -581,6 +1144,7 @@
 
 					// ensure movie exists
 					let does_movie_exist = kine_movie::Pallet::<T>::do_does_external_movie_exist(movie_id.clone())?;
+					ensure!(!kine_movie::Pallet::<T>::is_movie_suspended(&movie_id), Error::<T>::MovieSuspended);
 					if !does_movie_exist {
 						kine_movie::Pallet::<T>::do_create_external_movie(
 							&who.clone(),
@@ -593,76 +1157,29 @@
 					// insert the movie in the ranking list's movies_list
 					RankingLists::<T>::try_mutate_exists(list_id, |ranking_list| -> DispatchResult {
 						let list = ranking_list.as_mut().ok_or(Error::<T>::BadMetadata)?;
+						ensure!(list.status == RankingListStatus::Ongoing, Error::<T>::RankingListNotOngoing);
+	
+						// ensure no entry for the movie exists in the ranking list, unless it was
+						// just created and joined the list through its eligibility rule
+						let already_listed = list.movies_in_list.contains(&movie_id.clone());
+						ensure!(!already_listed || !does_movie_exist, Error::<T>::MovieAlreadyInList);
+						if !already_listed {
+							list.movies_in_list.try_push(movie_id.clone()).map_err(|_| Error::<T>::MovieIdOverflow)?;
+						}
 	
-						// ensure no entry for the movie exists in the ranking list
-						ensure!(!list.movies_in_list.contains(&movie_id.clone()), Error::<T>::MovieAlreadyInList);
-	
-						// transfer amount to this pallet's vault
-						T::Currency::transfer(
-							&who.clone(),
-							&Self::account_id(), 
-							amount.clone(), 
-							AllowDeath
-						);
-						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
-							who.clone(), 
-							kine_stat_tracker::FeatureType::RankingList,
-							kine_stat_tracker::TokenType::Locked,
-							amount.clone(), false
-						).unwrap();
-						list.total_lockup =
-							list.total_lockup
-							.checked_add(&amount.clone())
-							.ok_or(Error::<T>::Overflow)?;
-	
-							
-						// create the Vote
-						let unlock_block = Self::do_calculate_unlock_block(conviction).unwrap();
-						let vote = RankingVote {
-							movie_id: movie_id.clone(),
-							locked_amount: amount,
-							conviction: conviction,
-							unlock_block: unlock_block,
-						};
-		
-
-						// The voter hasn't voted in the list yet
-						if !list.votes_by_user.contains_key(&who.clone()) {
-							// create a new vote list, with the user's vote in it and add it
-							let mut user_votes: BoundedBTreeMap<
-								BoundedVec<u8, T::LinkStringLimit>, 
-								RankingVote<BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>, 
-								T::MaxVotersPerList
-							> = BoundedBTreeMap::new();
-							user_votes.try_insert(movie_id.clone(), vote).unwrap();
-							list.votes_by_user.try_insert(who.clone(), user_votes).unwrap();
-						}
-						// The voter has voted in the list, add a new vote
-						else {
-							let mut user_votes = list.votes_by_user.get_mut(&who.clone()).unwrap();
-							// the voter hasn't voted for the movie, add a vote entry for it
-							if !user_votes.contains_key(&movie_id.clone()) {
-								user_votes.try_insert(movie_id.clone(), vote).unwrap();
-							}
-							// The voter has already voted for the specific movie, update the entry
-							else {
-								let mut movie_vote = user_votes.get_mut(&movie_id.clone()).unwrap();
-								movie_vote.locked_amount =
-									movie_vote.locked_amount
-									.checked_add(&amount.clone())
-									.ok_or(Error::<T>::Overflow)?;
-							}
-						}
-
-						list.movies_in_list.try_push(movie_id).unwrap();
-					
-						Ok(().into())
-					})?;
-					
-					// finalize call
-					Self::deposit_event(Event::MovieAddedToList(list_id, movie_link_str, who.clone()));
-					Ok(().into())
-				}
+						// lock the amount in the voter's account and record the vote,
+						// pairwise lists are ranked by judgements and take the movie without one
+						if ListRankingModes::<T>::get(list_id) == RankingMode::Stake {
+							Self::do_add_vote(list, list_id, &who, movie_id, amount, conviction)?;
+						}
+					
+						Ok(().into())
+					})?;
+					
+					// finalize call
+					Self::deposit_event(Event::MovieAddedToList(list_id, movie_link_str, who.clone()));
+					Ok(().into())
+				}
 	
 	
 	
@@ -687,70 +1204,17 @@
 					//mutate the storage, while creating the Vote & bonding
 					RankingLists::<T>::try_mutate_exists(list_id, |ranking_list| -> DispatchResult {
 						let list = ranking_list.as_mut().ok_or(Error::<T>::BadMetadata)?;
+						ensure!(list.status == RankingListStatus::Ongoing, Error::<T>::RankingListNotOngoing);
 	
 						let movie_id: BoundedVec<u8, T::LinkStringLimit> =
 							TryInto::try_into(movie_id_str.as_bytes().to_vec()).map_err(|_| Error::<T>::BadMetadata)?;
 						
 						// ensure ranking list contains movie
 						ensure!(list.movies_in_list.contains(&movie_id), Error::<T>::MovieNotInRankingList);
+						ensure!(!kine_movie::Pallet::<T>::is_movie_suspended(&movie_id), Error::<T>::MovieSuspended);
 	
-						// transfer amount to this pallet's vault
-						T::Currency::transfer(
-							&who.clone(),
-							&Self::account_id(), 
-							amount.clone(), 
-							AllowDeath
-						);
-						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
-							who.clone(), 
-							kine_stat_tracker::FeatureType::RankingList,
-							kine_stat_tracker::TokenType::Locked,
-							amount.clone(), false
-						).unwrap();
-						list.total_lockup =
-							list.total_lockup
-							.checked_add(&amount.clone())
-							.ok_or(Error::<T>::Overflow)?;
-	
-						// create the Vote
-						let unlock_block = Self::do_calculate_unlock_block(conviction).unwrap();
-						let vote = RankingVote {
-							movie_id: movie_id.clone(),
-							locked_amount: amount,
-							conviction: conviction,
-							unlock_block: unlock_block,
-						};
-	
-						// retrieve the votes for the ranking list
-						
-						
-						// The voter hasn't voted in the list yet
-						if !list.votes_by_user.contains_key(&who.clone()) {
-							// create a new vote list, with the user's vote in it and add it
-							let mut user_votes: BoundedBTreeMap<
-								BoundedVec<u8, T::LinkStringLimit>, 
-								RankingVote<BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>, 
-								T::MaxVotersPerList
-							> = BoundedBTreeMap::new();
-							user_votes.try_insert(movie_id.clone(), vote).unwrap();
-							list.votes_by_user.try_insert(who.clone(), user_votes).unwrap();
-						}
-						// The voter has voted in the list, add a new vote
-						else {
-							let mut user_votes = list.votes_by_user.get_mut(&who.clone()).unwrap();
-							// the voter hasn't voted for the movie, add a vote entry for it
-							if !user_votes.contains_key(&movie_id.clone()) {
-								user_votes.try_insert(movie_id.clone(), vote).unwrap();
-							}
-							// The voter has already voted for the specific movie, update the entry
-							else {
-								let mut movie_vote = user_votes.get_mut(&movie_id.clone()).unwrap();
-								movie_vote.locked_amount =
-									movie_vote.locked_amount
-									.checked_add(&amount.clone())
-									.ok_or(Error::<T>::Overflow)?;
-							}
-						}
+						// lock the amount in the voter's account and record the vote
+						Self::do_add_vote(list, list_id, &who, movie_id, amount, conviction)?;
 						
 						Ok(().into())
 					})?;
@@ -783,46 +1247,9 @@
 						
 						// ensure ranking list contains movie
 						ensure!(list.movies_in_list.contains(&movie_id), Error::<T>::MovieNotInRankingList);
-						
-						// ensure user has voted and get the vote
-						ensure!(list.votes_by_user.contains_key(&who.clone()), Error::<T>::NoVoteInList);
-						let mut movie_votes_map = list.votes_by_user.get_mut(&who.clone()).unwrap();
-						ensure!(movie_votes_map.contains_key(&movie_id.clone()), Error::<T>::NoVoteForMovie);
-						let mut movie_vote = movie_votes_map.get_mut(&movie_id.clone()).unwrap();
-
-						ensure!(amount <= movie_vote.locked_amount, Error::<T>::UnstakeValueTooHigh);
-
-						list.total_lockup =
-							list.total_lockup
-							.checked_sub(&amount.clone())
-							.ok_or(Error::<T>::Overflow)?;
-						
-						// withdraw all the value and erase the vote
-						if amount == movie_vote.locked_amount {
-							movie_votes_map.remove(&movie_id);
-						}
-						// withdraw some value from the vote
-						else {
-							movie_vote.locked_amount =
-								movie_vote.locked_amount
-								.checked_sub(&amount.clone())
-								.ok_or(Error::<T>::Overflow)?;
-						}
-
-						// transfer the value to the user
-						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
-							who.clone(), 
-							kine_stat_tracker::FeatureType::RankingList,
-							kine_stat_tracker::TokenType::Locked,
-							amount.clone(), true
-						).unwrap();
 
-						T::Currency::transfer(
-							&Self::account_id(), 
-							&who.clone(),
-							amount.clone(), 
-							AllowDeath
-						);
+						// update the vote and release the value in the user's account
+						Self::do_remove_vote(list, list_id, &who, movie_id, amount)?;
 
 						Ok(().into())
 					})?;
@@ -853,10 +1280,16 @@
 						Error::<T>::NoClaimableTokens
 					);
 
-					T::Currency::deposit_into_existing(
+					// rewards are paid from the pot, never minted
+					T::Currency::transfer(
+						&Self::account_id(),
 						&who.clone(),
 						claimable_tokens_ranking.clone(), 
-					);
+						AllowDeath,
+					)?;
+					OutstandingRankingRewards::<T>::mutate(|outstanding| {
+						*outstanding = outstanding.saturating_sub(claimable_tokens_ranking);
+					});
 					
 					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
 						who.clone(), 
@@ -868,6 +1301,407 @@
 					Self::deposit_event(Event::RankingTokensClaimed(who, claimable_tokens_ranking));
 					Ok(().into())
 				}	
+
+
+				// Adds tokens to the pot that pays out the ranking list rewards.
+				#[pallet::call_index(6)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+				pub fn fund_reward_pot(
+					origin: OriginFor<T>,
+					amount: BalanceOf<T>,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+					ensure!(amount > BalanceOf::<T>::from(0u32), Error::<T>::VoteAmountCannotBeZero);
+
+					T::Currency::transfer(
+						&who.clone(),
+						&Self::account_id(),
+						amount.clone(),
+						KeepAlive,
+					)?;
+
+					Self::deposit_event(Event::RewardPotFunded(who, amount));
+					Ok(().into())
+				}
+
+
+				#[pallet::call_index(7)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(0,1))]
+				pub fn set_reward_parameters(
+					origin: OriginFor<T>,
+					apy: Perbill,
+					period: BlockNumberFor<T>,
+					budget: BalanceOf<T>,
+				) -> DispatchResultWithPostInfo {
+					
+					T::RewardsOrigin::ensure_origin(origin)?;
+					ensure!(!period.is_zero(), Error::<T>::RewardPeriodCannotBeZero);
+
+					RankingRewardParameters::<T>::put(RewardParameters {
+						apy: apy,
+						period: period,
+						budget: budget,
+					});
+
+					Self::deposit_event(Event::RewardParametersUpdated(apy, period, budget));
+					Ok(().into())
+				}
+
+
+				// Set or clear the movie tags that make movies join the list automatically.
+				// Only movies created or re-tagged afterwards are picked up.
+				// Callable by the list's owner or curator, or by the ListAdminOrigin.
+				#[pallet::call_index(8)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+				pub fn set_list_eligibility_rule(
+					origin: OriginFor<T>,
+					list_id: RankingListId,
+					eligibility_rule: Option<BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>>,
+				) -> DispatchResultWithPostInfo {
+					
+					ensure!(RankingLists::<T>::contains_key(list_id), Error::<T>::RankingListNotFound);
+					Self::do_ensure_list_manager(origin, list_id, true)?;
+
+					// drop the previous rule from the index
+					if let Some(old_rule) = ListEligibilityRules::<T>::take(list_id) {
+						for category_tag in old_rule {
+							ListsByEligibleTag::<T>::remove(category_tag, list_id);
+						}
+					}
+
+					if let Some(rule) = eligibility_rule {
+						let category_type: kine_tags::CategoryType<T>
+							= TryInto::try_into("Movie".as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
+						kine_tags::Pallet::<T>::do_validate_tag_data(category_type, rule.clone())?;
+
+						for category_tag in rule.iter() {
+							ListsByEligibleTag::<T>::insert(category_tag.clone(), list_id, ());
+						}
+						ListEligibilityRules::<T>::insert(list_id, rule);
+					}
+
+					Self::deposit_event(Event::EligibilityRuleUpdated(list_id));
+					Ok(().into())
+				}
+
+
+				// Set or clear the list's curator. Only the owner can change it.
+				#[pallet::call_index(9)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+				pub fn set_list_curator(
+					origin: OriginFor<T>,
+					list_id: RankingListId,
+					curator: Option<T::AccountId>,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+
+					ListGovernance::<T>::try_mutate(list_id, |list_info| -> DispatchResult {
+						let info = list_info.as_mut().ok_or(Error::<T>::RankingListNotFound)?;
+						ensure!(info.owner == who, Error::<T>::NotListOwner);
+						info.curator = curator.clone();
+						Ok(())
+					})?;
+
+					Self::deposit_event(Event::ListCuratorUpdated(list_id, curator));
+					Ok(().into())
+				}
+
+
+				// Ends the list and refunds every stake over the next blocks, including the ones still
+				// locked with conviction. The round in progress is not rewarded. The creation deposit
+				// is returned to the owner.
+				#[pallet::call_index(10)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4,4))]
+				pub fn close_ranking_list(
+					origin: OriginFor<T>,
+					list_id: RankingListId,
+				) -> DispatchResultWithPostInfo {
+					
+					ensure!(RankingLists::<T>::contains_key(list_id), Error::<T>::RankingListNotFound);
+					Self::do_ensure_list_manager(origin, list_id, false)?;
+
+					Self::do_close_list(list_id, RankingListStatus::Finished, true)?;
+
+					Self::deposit_event(Event::RankingListClosed(list_id));
+					Ok(().into())
+				}
+
+
+				// Takes down a list, e.g. an abandoned or abusive one. Stakes are refunded
+				// like in close_ranking_list, but the creation deposit goes to the reward pot.
+				#[pallet::call_index(11)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4,4))]
+				pub fn archive_ranking_list(
+					origin: OriginFor<T>,
+					list_id: RankingListId,
+				) -> DispatchResultWithPostInfo {
+					
+					T::ListAdminOrigin::ensure_origin(origin)?;
+					ensure!(RankingLists::<T>::contains_key(list_id), Error::<T>::RankingListNotFound);
+
+					Self::do_close_list(list_id, RankingListStatus::Archived, false)?;
+
+					Self::deposit_event(Event::RankingListArchived(list_id));
+					Ok(().into())
+				}
+
+
+				// Accept delegations, keeping the given commission from the delegators' rewards.
+				// A new commission applies to each list from its next round.
+				#[pallet::call_index(12)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(0,1))]
+				pub fn set_curator_commission(
+					origin: OriginFor<T>,
+					commission: Perbill,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+					ensure!(commission <= T::MaxCuratorCommission::get(), Error::<T>::CommissionTooHigh);
+					CuratorCommissions::<T>::insert(&who, commission);
+
+					Self::deposit_event(Event::CuratorCommissionSet(who, commission));
+					Ok(().into())
+				}
+
+
+				// Lock the amount and hand its voting power, multiplied by the conviction, to a curator.
+				// A delegator can have one delegation for all lists and one for each list.
+				#[pallet::call_index(13)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4,4))]
+				pub fn delegate(
+					origin: OriginFor<T>,
+					curator: T::AccountId,
+					scope: DelegationScope,
+					amount: BalanceOf<T>,
+					conviction: Conviction,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+					ensure!(amount > BalanceOf::<T>::from(0u32), Error::<T>::VoteAmountCannotBeZero);
+					ensure!(CuratorCommissions::<T>::contains_key(&curator), Error::<T>::CuratorNotRegistered);
+					ensure!(!Delegations::<T>::contains_key(&who, scope), Error::<T>::AlreadyDelegating);
+					if let DelegationScope::List(list_id) = scope {
+						ensure!(RankingLists::<T>::contains_key(list_id), Error::<T>::RankingListNotFound);
+					}
+
+					// lock the amount in the delegator's account
+					Self::do_lock_ranking_tokens(&who, amount)?;
+					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+						who.clone(), 
+						kine_stat_tracker::FeatureType::RankingList,
+						kine_stat_tracker::TokenType::Locked,
+						amount.clone(), false
+					)?;
+
+					let power = Self::do_calculate_voting_power(amount, conviction)?;
+					let reward_per_stake = DelegationPools::<T>::try_mutate(&curator, scope, |pool| -> Result<BalanceOf<T>, DispatchError> {
+						pool.stake = pool.stake.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+						pool.power = pool.power.checked_add(&power).ok_or(Error::<T>::Overflow)?;
+						Ok(pool.reward_per_stake)
+					})?;
+					Delegations::<T>::insert(&who, scope, Delegation {
+						curator: curator.clone(),
+						amount: amount,
+						conviction: conviction,
+						reward_debt: Self::do_accrued_pool_rewards(amount, reward_per_stake),
+					});
+					Self::do_refresh_curator_positions(&curator, scope);
+
+					Self::deposit_event(Event::Delegated(who, curator, scope, amount));
+					Ok(().into())
+				}
+
+
+				// Take the delegation back from the curator, collecting its rewards. As with votes, the tokens
+				// stay locked for the conviction's lock period and are then released with unlock_delegation.
+				#[pallet::call_index(14)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4,4))]
+				pub fn undelegate(
+					origin: OriginFor<T>,
+					scope: DelegationScope,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+					let mut delegation = Delegations::<T>::take(&who, scope).ok_or(Error::<T>::NotDelegating)?;
+					Self::do_harvest_delegation(&who, scope, &mut delegation)?;
+
+					let power = Self::do_calculate_voting_power(delegation.amount, delegation.conviction)?;
+					DelegationPools::<T>::mutate(&delegation.curator, scope, |pool| {
+						pool.stake = pool.stake.saturating_sub(delegation.amount);
+						pool.power = pool.power.saturating_sub(power);
+					});
+					Self::do_refresh_curator_positions(&delegation.curator, scope);
+
+					let unlock_block = Self::do_calculate_unlock_block(delegation.conviction)?;
+					DelegationUnlocks::<T>::mutate(&who, scope, |unlock| {
+						let (locked, block) = unlock.unwrap_or((Zero::zero(), unlock_block));
+						*unlock = Some((locked.saturating_add(delegation.amount), block.max(unlock_block)));
+					});
+
+					Self::deposit_event(Event::Undelegated(who, scope, delegation.amount));
+					Ok(().into())
+				}
+
+
+				// Release undelegated tokens once their conviction lock is over.
+				#[pallet::call_index(15)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2,2))]
+				pub fn unlock_delegation(
+					origin: OriginFor<T>,
+					scope: DelegationScope,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+					let (amount, unlock_block) = DelegationUnlocks::<T>::get(&who, scope).ok_or(Error::<T>::NoDelegationUnlock)?;
+					ensure!(
+						<frame_system::Pallet<T>>::block_number() >= unlock_block,
+						Error::<T>::VoteValueStillLockedWithConviction
+					);
+					DelegationUnlocks::<T>::remove(&who, scope);
+
+					kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+						who.clone(), 
+						kine_stat_tracker::FeatureType::RankingList,
+						kine_stat_tracker::TokenType::Locked,
+						amount.clone(), true
+					)?;
+					Self::do_unlock_ranking_tokens(&who, amount)?;
+
+					Self::deposit_event(Event::DelegationUnlocked(who, amount));
+					Ok(().into())
+				}
+
+
+				// Move the delegation's share of its pool's rewards to the claimable ranking tokens.
+				#[pallet::call_index(16)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2,2))]
+				pub fn claim_delegation_rewards(
+					origin: OriginFor<T>,
+					scope: DelegationScope,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+					let mut delegation = Delegations::<T>::get(&who, scope).ok_or(Error::<T>::NotDelegating)?;
+					let harvested = Self::do_harvest_delegation(&who, scope, &mut delegation)?;
+					Delegations::<T>::insert(&who, scope, delegation);
+
+					Self::deposit_event(Event::DelegationRewardsHarvested(who, harvested));
+					Ok(().into())
+				}
+
+
+				// Spread the curator's delegated stake over the list's movies, each share being a part of
+				// the stake delegated for every list plus the one delegated for this list. An empty
+				// allocation drops the position. Changing the allocation restarts its clock for the rewards.
+				#[pallet::call_index(17)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4,4))]
+				pub fn set_curator_allocation(
+					origin: OriginFor<T>,
+					list_id: RankingListId,
+					allocations: BoundedVec<(BoundedVec<u8, T::LinkStringLimit>, Perbill), T::MaxVotesPerVoter>,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+					ensure!(CuratorCommissions::<T>::contains_key(&who), Error::<T>::CuratorNotRegistered);
+					let list = RankingLists::<T>::get(list_id).ok_or(Error::<T>::RankingListNotFound)?;
+					ensure!(list.status == RankingListStatus::Ongoing, Error::<T>::RankingListNotOngoing);
+					ensure!(ListRankingModes::<T>::get(list_id) == RankingMode::Stake, Error::<T>::WrongRankingMode);
+
+					let mut total_share: u32 = 0;
+					for (movie_id, share) in allocations.iter() {
+						ensure!(list.movies_in_list.contains(movie_id), Error::<T>::MovieNotInRankingList);
+						total_share = total_share.saturating_add(share.deconstruct());
+					}
+					ensure!(total_share <= Perbill::one().deconstruct(), Error::<T>::AllocationTooHigh);
+
+					// take the previous position out of the tallies, its commission holds until the round ends
+					let mut commission = CuratorCommissions::<T>::get(&who).unwrap_or(Perbill::zero());
+					if let Some(old_position) = CuratorPositions::<T>::take(list_id, &who) {
+						Self::do_apply_position_to_tallies(list_id, &old_position, false);
+						commission = old_position.commission;
+					}
+
+					if allocations.is_empty() {
+						CuratorsInList::<T>::mutate(list_id, |curators| curators.retain(|curator| *curator != who));
+						CuratedLists::<T>::mutate(&who, |lists| lists.retain(|id| *id != list_id));
+					}
+					else {
+						if !CuratorsInList::<T>::get(list_id).contains(&who) {
+							CuratorsInList::<T>::try_mutate(list_id, |curators| curators.try_push(who.clone()))
+								.map_err(|_| Error::<T>::TooManyCurators)?;
+							CuratedLists::<T>::try_mutate(&who, |lists| lists.try_push(list_id))
+								.map_err(|_| Error::<T>::TooManyCuratedLists)?;
+						}
+
+						let (stake, power) = Self::do_curator_capital(&who, list_id);
+						let position = CuratorPosition {
+							allocations: allocations,
+							stake: stake,
+							power: power,
+							weighted_since: stake.saturating_mul(Self::do_block_as_balance(<frame_system::Pallet<T>>::block_number())),
+							commission: commission,
+						};
+						Self::do_apply_position_to_tallies(list_id, &position, true);
+						CuratorPositions::<T>::insert(list_id, &who, position);
+					}
+
+					Self::deposit_event(Event::CuratorAllocationUpdated(list_id, who));
+					Ok(().into())
+				}
+
+
+				// Judge the pair of movies drawn for the caller by `draw_judgement_pair`, `first_wins`
+				// telling whether the first movie of the pair beats the second one. Updates both
+				// movies' Elo ratings.
+				#[pallet::call_index(18)]#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(7,3))]
+				pub fn submit_judgement(
+					origin: OriginFor<T>,
+					list_id: RankingListId,
+					first_wins: bool,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+					let (first, second) = JudgementPairs::<T>::take(list_id, &who).ok_or(Error::<T>::NoJudgementPair)?;
+
+					let list = RankingLists::<T>::get(list_id).ok_or(Error::<T>::RankingListNotFound)?;
+					ensure!(list.status == RankingListStatus::Ongoing, Error::<T>::RankingListNotOngoing);
+					ensure!(ListRankingModes::<T>::get(list_id) == RankingMode::Pairwise, Error::<T>::WrongRankingMode);
+					ensure!(
+						list.movies_in_list.contains(&first) && list.movies_in_list.contains(&second),
+						Error::<T>::MovieNotInRankingList
+					);
+					ensure!(
+						!kine_movie::Pallet::<T>::is_movie_suspended(&first) && !kine_movie::Pallet::<T>::is_movie_suspended(&second),
+						Error::<T>::MovieSuspended
+					);
+
+					let (winner, loser) = if first_wins { (first, second) } else { (second, first) };
+					Self::do_update_pairwise_ratings(list_id, &winner, &loser);
+
+					Self::deposit_event(Event::JudgementSubmitted(list_id, who, winner, loser));
+					Ok(().into())
+				}
+
+
+				// Draw the next pair of movies the caller judges in a pairwise list, replacing any pair
+				// that wasn't judged yet. Drawing counts against the caller's allowance of
+				// MaxJudgementsPerWindow per window, so pairs can't be redrawn until a wanted one comes up.
+				#[pallet::call_index(19)]#[pallet::weight(
+					Weight::from_parts(10_000, 0) 
+					+ T::DbWeight::get().reads_writes(u64::from(T::MaxMoviesInList::get()).saturating_add(6), 3)
+				)]
+				pub fn draw_judgement_pair(
+					origin: OriginFor<T>,
+					list_id: RankingListId,
+				) -> DispatchResultWithPostInfo {
+					
+					let who = ensure_signed(origin)?;
+					ensure!(
+						kine_stat_tracker::Pallet::<T>::do_is_wallet_registered(who.clone())?,
+						Error::<T>::WalletStatsRegistryRequired,
+					);
+
+					let list = RankingLists::<T>::get(list_id).ok_or(Error::<T>::RankingListNotFound)?;
+					ensure!(list.status == RankingListStatus::Ongoing, Error::<T>::RankingListNotOngoing);
+					ensure!(ListRankingModes::<T>::get(list_id) == RankingMode::Pairwise, Error::<T>::WrongRankingMode);
+
+					Self::do_use_judgement_allowance(&who)?;
+					let (first, second) = Self::do_draw_judgement_pair(list.movies_in_list.into_inner())?;
+					JudgementPairs::<T>::insert(list_id, &who, (first.clone(), second.clone()));
+
+					Self::deposit_event(Event::JudgementPairDrawn(list_id, who, first, second));
+					Ok(().into())
+				}
 	
 	
 	
@@ -887,8 +1721,240 @@
 					fn account_id()->T::AccountId{
 						<T as Config>::PalletId::get().try_into_account().unwrap()
 					}
-	
-	
+	
+	
+					// Adds the amount to the wallet's ranking lock. The tokens never leave
+					// the voter's account, but can't be spent while locked.
+					pub fn do_lock_ranking_tokens(
+						who: &T::AccountId,
+						amount: BalanceOf<T>,
+					) -> DispatchResult {
+						
+						let new_lock =
+							VoterLocks::<T>::get(who)
+							.checked_add(&amount)
+							.ok_or(Error::<T>::Overflow)?;
+						ensure!(
+							T::LockCurrency::free_balance(who) >= new_lock,
+							Error::<T>::NotEnoughBalance
+						);
+
+						T::LockCurrency::set_lock(RANKING_LOCK_ID, who, new_lock, WithdrawReasons::all());
+						VoterLocks::<T>::insert(who, new_lock);
+						Ok(())
+					}
+
+
+					// Removes the amount from the wallet's ranking lock, dropping the lock when empty.
+					// Stakes the v1 migration left in the vault are paid out of it first.
+					pub fn do_unlock_ranking_tokens(
+						who: &T::AccountId,
+						amount: BalanceOf<T>,
+					) -> DispatchResult {
+						
+						let legacy_stake = LegacyVaultStakes::<T>::get(who);
+						let from_vault = legacy_stake.min(amount);
+						if !from_vault.is_zero() {
+							T::Currency::transfer(&Self::account_id(), who, from_vault, AllowDeath)?;
+							let remaining_stake = legacy_stake.saturating_sub(from_vault);
+							if remaining_stake.is_zero() {
+								LegacyVaultStakes::<T>::remove(who);
+							}
+							else {
+								LegacyVaultStakes::<T>::insert(who, remaining_stake);
+							}
+							TotalLegacyVaultStakes::<T>::mutate(|total| *total = total.saturating_sub(from_vault));
+						}
+						let amount = amount.saturating_sub(from_vault);
+						if amount.is_zero() {
+							return Ok(())
+						}
+
+						let new_lock =
+							VoterLocks::<T>::get(who)
+							.checked_sub(&amount)
+							.ok_or(Error::<T>::Underflow)?;
+
+						if new_lock.is_zero() {
+							T::LockCurrency::remove_lock(RANKING_LOCK_ID, who);
+							VoterLocks::<T>::remove(who);
+						}
+						else {
+							T::LockCurrency::set_lock(RANKING_LOCK_ID, who, new_lock, WithdrawReasons::all());
+							VoterLocks::<T>::insert(who, new_lock);
+						}
+						Ok(())
+					}
+
+
+					// Locks the amount and adds it to the voter's vote for the movie, keeping the movie's
+					// tally in sync. Topping up a vote applies the new conviction to the whole vote,
+					// never shortens its lock and restarts its clock for the rewards.
+					pub fn do_add_vote(
+						list: &mut RankingListOf<T>,
+						list_id: RankingListId,
+						who: &T::AccountId,
+						movie_id: BoundedVec<u8, T::LinkStringLimit>,
+						amount: BalanceOf<T>,
+						conviction: Conviction,
+					) -> DispatchResult {
+
+						ensure!(ListRankingModes::<T>::get(list_id) == RankingMode::Stake, Error::<T>::WrongRankingMode);
+						Self::do_lock_ranking_tokens(who, amount)?;
+						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+							who.clone(), 
+							kine_stat_tracker::FeatureType::RankingList,
+							kine_stat_tracker::TokenType::Locked,
+							amount.clone(), false
+						)?;
+						list.total_lockup =
+							list.total_lockup
+							.checked_add(&amount.clone())
+							.ok_or(Error::<T>::Overflow)?;
+
+						let now = <frame_system::Pallet<T>>::block_number();
+						let round_start = list.list_deadline.saturating_sub(list.list_duration);
+						let unlock_block = Self::do_calculate_unlock_block(conviction)?;
+
+						let mut voter = match ListVotes::<T>::get(list_id, who) {
+							Some(voter) => voter,
+							// The voter hasn't voted in the list yet
+							None => {
+								ensure!(list.total_voters < T::MaxVotersPerList::get(), Error::<T>::TooManyVoters);
+								list.total_voters = list.total_voters.checked_add(1).ok_or(Error::<T>::Overflow)?;
+								ListVoter {
+									votes: BoundedBTreeMap::new(),
+									rewarded_until: NextSnapshotIndex::<T>::get(list_id),
+								}
+							},
+						};
+						Self::do_settle_voter_rewards(list_id, who, &mut voter)?;
+
+						let mut tally = MovieTallies::<T>::get(list_id, &movie_id);
+						let vote_amount = match voter.votes.get_mut(&movie_id) {
+							// The voter has already voted for the movie, take the old vote out of the tally and update it
+							Some(movie_vote) => {
+								tally.power = tally.power.saturating_sub(
+									Self::do_calculate_voting_power(movie_vote.locked_amount, movie_vote.conviction)?
+								);
+								tally.weighted_since = tally.weighted_since.saturating_sub(
+									movie_vote.locked_amount.saturating_mul(Self::do_block_as_balance(movie_vote.staked_since.max(round_start)))
+								);
+
+								movie_vote.locked_amount =
+									movie_vote.locked_amount
+									.checked_add(&amount.clone())
+									.ok_or(Error::<T>::Overflow)?;
+								movie_vote.conviction = conviction;
+								movie_vote.unlock_block = movie_vote.unlock_block.max(unlock_block);
+								movie_vote.staked_since = now;
+								movie_vote.locked_amount
+							},
+							// The voter hasn't voted for the movie, add a vote entry for it
+							None => {
+								voter.votes.try_insert(movie_id.clone(), RankingVote {
+									movie_id: movie_id.clone(),
+									locked_amount: amount,
+									conviction: conviction,
+									unlock_block: unlock_block,
+									staked_since: now,
+								}).map_err(|_| Error::<T>::TooManyVotes)?;
+								amount
+							},
+						};
+
+						tally.power = 
+							tally.power
+							.checked_add(&Self::do_calculate_voting_power(vote_amount, conviction)?)
+							.ok_or(Error::<T>::Overflow)?;
+						tally.stake = tally.stake.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+						tally.weighted_since = tally.weighted_since.saturating_add(
+							vote_amount.saturating_mul(Self::do_block_as_balance(now))
+						);
+
+						MovieTallies::<T>::insert(list_id, &movie_id, tally);
+						ListVotes::<T>::insert(list_id, who, voter);
+						Ok(())
+					}
+
+
+					// Withdraws part or all of a vote and unlocks the amount. Votes locked with
+					// conviction can only be withdrawn after their unlock block, unless the list was closed.
+					pub fn do_remove_vote(
+						list: &mut RankingListOf<T>,
+						list_id: RankingListId,
+						who: &T::AccountId,
+						movie_id: BoundedVec<u8, T::LinkStringLimit>,
+						amount: BalanceOf<T>,
+					) -> DispatchResult {
+
+						// ensure user has voted and get the vote
+						let mut voter = ListVotes::<T>::get(list_id, who).ok_or(Error::<T>::NoVoteInList)?;
+						Self::do_settle_voter_rewards(list_id, who, &mut voter)?;
+
+						let round_start = list.list_deadline.saturating_sub(list.list_duration);
+						let mut tally = MovieTallies::<T>::get(list_id, &movie_id);
+						let movie_vote = voter.votes.get_mut(&movie_id).ok_or(Error::<T>::NoVoteForMovie)?;
+
+						ensure!(amount <= movie_vote.locked_amount, Error::<T>::UnstakeValueTooHigh);
+						ensure!(
+							list.status != RankingListStatus::Ongoing ||
+							<frame_system::Pallet<T>>::block_number() >= movie_vote.unlock_block,
+							Error::<T>::VoteValueStillLockedWithConviction
+						);
+
+						let remaining = movie_vote.locked_amount.checked_sub(&amount).ok_or(Error::<T>::Underflow)?;
+						tally.power = 
+							tally.power
+							.saturating_sub(Self::do_calculate_voting_power(movie_vote.locked_amount, movie_vote.conviction)?)
+							.saturating_add(Self::do_calculate_voting_power(remaining, movie_vote.conviction)?);
+						tally.stake = tally.stake.saturating_sub(amount);
+						tally.weighted_since = tally.weighted_since.saturating_sub(
+							amount.saturating_mul(Self::do_block_as_balance(movie_vote.staked_since.max(round_start)))
+						);
+						MovieTallies::<T>::insert(list_id, &movie_id, tally);
+
+						// withdraw all the value and erase the vote
+						if remaining.is_zero() {
+							voter.votes.remove(&movie_id);
+						}
+						// withdraw some value from the vote
+						else {
+							movie_vote.locked_amount = remaining;
+						}
+
+						if voter.votes.is_empty() {
+							ListVotes::<T>::remove(list_id, who);
+							list.total_voters = list.total_voters.saturating_sub(1);
+						}
+						else {
+							ListVotes::<T>::insert(list_id, who, voter);
+						}
+
+						list.total_lockup =
+							list.total_lockup
+							.checked_sub(&amount.clone())
+							.ok_or(Error::<T>::Underflow)?;
+
+						// release the value in the user's account
+						kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+							who.clone(), 
+							kine_stat_tracker::FeatureType::RankingList,
+							kine_stat_tracker::TokenType::Locked,
+							amount.clone(), true
+						)?;
+						Self::do_unlock_ranking_tokens(who, amount)
+					}
+
+
+					// Block numbers are multiplied with balances for the time-weighted stakes.
+					pub fn do_block_as_balance(
+						block: BlockNumberFor<T>,
+					) -> BalanceOf<T> {
+						BalanceOf::<T>::from(TryInto::<u32>::try_into(block).unwrap_or(u32::MAX))
+					}
+
+
 					// Creates a deadline entry for a ranking list in ListDeadlines.
 					// If no entries exist for the block, a new entry is created and
 					// the ranking list's id is added.
@@ -928,19 +1994,28 @@
 						block_deadline: BlockNumberFor<T>
 					) -> DispatchResultWithPostInfo {
 					
+						// lists that resolved their last round, closed once the deadlines are cleared
+						let mut finished_lists: Vec<RankingListId> = Vec::new();
+
 						// check if any entries exist for the block
 						ListDeadlines::<T>::try_mutate_exists(block_deadline, |deadlines_list| -> DispatchResult {
 							let deadlines = deadlines_list.as_mut().ok_or(Error::<T>::BadMetadata)?;
+							let reward_scale = Self::do_lists_reward_scale(&deadlines.list_deadlines)?;
 							
 							// check if any entries exist and if so refresh them
 							for list_id in deadlines.list_deadlines.iter() {
-								let sorted_ranking_list = Self::resolve_ranking_list(list_id.clone())?;
+								let sorted_ranking_list = Self::resolve_ranking_list(list_id.clone(), reward_scale)?;
+								let is_last_round = Self::do_complete_list_round(list_id.clone())?;
 	
 								// update the ranking list's sorted movies & determine the new deadline
 								RankingLists::<T>::try_mutate(list_id, |ranking_list| -> DispatchResult {
 									let list = ranking_list.as_mut().ok_or(Error::<T>::BadMetadata)?;
 									
 									list.movies_in_list = sorted_ranking_list;
+									if is_last_round {
+										finished_lists.push(list_id.clone());
+										return Ok(().into());
+									}
 									list.list_deadline =
 										<frame_system::Pallet<T>>::block_number().checked_add(&list.list_duration).ok_or(Error::<T>::Overflow)?;
 									Self::create_list_deadline(list_id.clone(), list.list_deadline).unwrap();
@@ -953,80 +2028,1003 @@
 	
 						//remove the entries from storage to free up space
 						ListDeadlines::<T>::remove(block_deadline);
+
+						for list_id in finished_lists {
+							Self::do_close_list(list_id, RankingListStatus::Finished, true)?;
+							Self::deposit_event(Event::RankingListClosed(list_id));
+						}
 	
 						Ok(().into())
 					}
 	
 	
 	
+					// Counts a resolved round. Returns true if it was the last round of a fixed list.
+					fn do_complete_list_round(
+						list_id: RankingListId,
+					) -> Result<bool, DispatchError> {
+						
+						ListGovernance::<T>::try_mutate(list_id, |list_info| -> Result<bool, DispatchError> {
+							match list_info.as_mut() {
+								Some(info) => {
+									info.rounds_completed = info.rounds_completed.checked_add(1).ok_or(Error::<T>::Overflow)?;
+									match info.mode {
+										ListMode::Perpetual => Ok(false),
+										ListMode::FixedRounds(rounds) => Ok(info.rounds_completed >= rounds),
+									}
+								},
+								// genesis lists are always perpetual
+								None => Ok(false),
+							}
+						})
+					}
+
+
+					// Ends a list: it stops receiving movies and votes, and its voters are refunded
+					// regardless of their conviction, a page per block. The creation deposit is either
+					// returned to the owner or, for archived lists, moved to the reward pot.
+					fn do_close_list(
+						list_id: RankingListId,
+						status: RankingListStatus,
+						return_deposit: bool,
+					) -> DispatchResult {
+
+						let list_deadline = RankingLists::<T>::try_mutate(list_id, |ranking_list| -> Result<BlockNumberFor<T>, DispatchError> {
+							let list = ranking_list.as_mut().ok_or(Error::<T>::RankingListNotFound)?;
+							ensure!(list.status == RankingListStatus::Ongoing, Error::<T>::RankingListNotOngoing);
+							list.status = status;
+							Ok(list.list_deadline)
+						})?;
+						Self::do_remove_list_deadline(list_id, list_deadline);
+						PendingRefunds::<T>::insert(list_id, None::<BoundedVec<u8, ConstU32<256>>>);
+
+						// the curators' positions are dropped with the list
+						for curator in CuratorsInList::<T>::take(list_id).iter() {
+							CuratorPositions::<T>::remove(list_id, curator);
+							CuratedLists::<T>::mutate(curator, |lists| lists.retain(|id| *id != list_id));
+						}
+
+						// closed lists no longer pick up tagged movies
+						if let Some(rule) = ListEligibilityRules::<T>::take(list_id) {
+							for category_tag in rule {
+								ListsByEligibleTag::<T>::remove(category_tag, list_id);
+							}
+						}
+
+						ListGovernance::<T>::try_mutate(list_id, |list_info| -> DispatchResult {
+							if let Some(info) = list_info.as_mut() {
+								if !info.deposit.is_zero() {
+									if return_deposit {
+										T::Currency::unreserve(&info.owner, info.deposit);
+									}
+									else {
+										T::Currency::repatriate_reserved(
+											&info.owner,
+											&Self::account_id(),
+											info.deposit,
+											BalanceStatus::Free,
+										)?;
+									}
+									info.deposit = Zero::zero();
+								}
+							}
+							Ok(())
+						})
+					}
+
+
+					// Removes a list from the deadlines of the given block, if it is there.
+					fn do_remove_list_deadline(
+						list_id: RankingListId,
+						list_deadline_block: BlockNumberFor<T>,
+					) {
+						ListDeadlines::<T>::mutate_exists(list_deadline_block, |deadlines_list| {
+							let is_empty = match deadlines_list.as_mut() {
+								Some(deadlines) => {
+									deadlines.list_deadlines.retain(|id| *id != list_id);
+									deadlines.list_deadlines.is_empty()
+								},
+								None => false,
+							};
+							if is_empty {
+								*deadlines_list = None;
+							}
+						});
+					}
+
+
+					// The ListAdminOrigin can always manage a list, otherwise the caller
+					// must be the list's owner, or its curator when allowed.
+					fn do_ensure_list_manager(
+						origin: OriginFor<T>,
+						list_id: RankingListId,
+						allow_curator: bool,
+					) -> DispatchResult {
+
+						if T::ListAdminOrigin::ensure_origin(origin.clone()).is_ok() {
+							return Ok(());
+						}
+
+						let who = ensure_signed(origin)?;
+						let info = ListGovernance::<T>::get(list_id).ok_or(Error::<T>::NotListOwner)?;
+						ensure!(
+							info.owner == who || (allow_curator && info.curator == Some(who)),
+							Error::<T>::NotListOwner
+						);
+						Ok(())
+					}
+
+
 					// Resolves a single Ranking List. 
 					// This means determining the winner(s) and distributing the rewards accordingly.
+					// Voters get `reward_scale` of the rewards they earned, see do_lists_reward_scale.
 					pub fn resolve_ranking_list(
-						list_id: RankingListId
+						list_id: RankingListId,
+						reward_scale: Perbill,
 					) -> Result<BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInList>, DispatchError> {
 					
 						// get the ranking list
-						let ranking_list = RankingLists::<T>::try_get(list_id.clone()).unwrap();
-	
-						// create a Btree that pairs movie ids to their total voting power
-						let mut movies_by_power: BoundedBTreeMap<
-							BoundedVec<u8, T::LinkStringLimit>,
-							BalanceOf::<T>,
-							T::MaxVotersPerList
-						> = BoundedBTreeMap::new();
-						for movie_id in ranking_list.movies_in_list {
-							movies_by_power.try_insert(movie_id.clone(), BalanceOf::<T>::from(0u32)).unwrap();
+						let ranking_list = RankingLists::<T>::try_get(list_id.clone()).map_err(|_| Error::<T>::RankingListNotFound)?;
+						let (movies_aux, suspended_movies) = Self::do_rank_list_movies(list_id, &ranking_list.movies_in_list);
+						let round = Self::do_store_ranking_snapshot(list_id, &movies_aux, ranking_list.total_voters)?;
+						let ordered_movies_power: Vec<BoundedVec<u8, T::LinkStringLimit>> = movies_aux.into_iter().map(|(_, movie_id)| movie_id).collect(); 
+						let mut ordered_movies: BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInList>
+							= TryInto::try_into(ordered_movies_power).map_err(|_|Error::<T>::BadMetadata)?;
+
+						let round_start = ranking_list.list_deadline.saturating_sub(ranking_list.list_duration);
+						Self::do_schedule_list_rewards(list_id, round, &ordered_movies, round_start, reward_scale)?;
+						for movie_id in suspended_movies {
+							ordered_movies.try_push(movie_id).map_err(|_| Error::<T>::BadMetadata)?;
 						}
 
+						// the next round starts now, older stakes count from here
+						let now_as_balance = Self::do_block_as_balance(<frame_system::Pallet<T>>::block_number());
+						for movie_id in ordered_movies.iter() {
+							MovieTallies::<T>::mutate(list_id, movie_id, |tally| {
+								tally.weighted_since = tally.stake.saturating_mul(now_as_balance);
+							});
+						}
+						// and commission changes apply from the new round
+						for curator in CuratorsInList::<T>::get(list_id).iter() {
+							CuratorPositions::<T>::mutate(list_id, curator, |position| {
+								if let Some(position) = position.as_mut() {
+									position.weighted_since = position.stake.saturating_mul(now_as_balance);
+									position.commission = CuratorCommissions::<T>::get(curator).unwrap_or(Perbill::zero());
+								}
+							});
+						}
 						
-						// iterate each user's votes in the ranking list and calculate the total return
-						let blocks_in_year: u32 = 5256000; //TODO-4
-						for (account_id, user_votes) in ranking_list.votes_by_user.iter() {
+						Self::deposit_event(Event::RankingListPayoff(list_id));
+						Ok(ordered_movies)
+					}
 
-							// get each vote's total power, adding it to movies_by_power and tallying the total tokens
-							let mut total_return = BalanceOf::<T>::from(0u32);
-							
-							for (movie_id, vote) in user_votes.iter() {
-								//TODO-7
-								// calculating the voting_power that determines list's order
-								let voting_power = Self::do_calculate_voting_power(vote.locked_amount, Conviction::Locked1x)?;
-								movies_by_power.get_mut(&vote.movie_id).unwrap().checked_add(&voting_power).ok_or(Error::<T>::Overflow)?;
-								total_return = total_return.checked_add(&vote.locked_amount).ok_or(Error::<T>::Overflow)?;
+
+					// Adds the movie with zero power to the list, unless this block's cap
+					// was reached, in which case the insertion is queued.
+					fn do_auto_insert_movie(
+						list_id: RankingListId,
+						movie_id: BoundedVec<u8, T::LinkStringLimit>,
+					) {
+						let now = <frame_system::Pallet<T>>::block_number();
+						let (block, inserted) = AutoInsertionsInBlock::<T>::get();
+						let inserted = if block == now { inserted } else { 0 };
+
+						if inserted >= T::MaxAutoInsertionsPerBlock::get() {
+							let (head, tail) = PendingInsertionsRange::<T>::get();
+							if tail.wrapping_sub(head) >= T::MaxPendingInsertions::get() {
+								Self::deposit_event(Event::AutoInsertionDropped(list_id, movie_id));
+								return;
 							}
+							PendingListInsertions::<T>::insert(tail, (list_id, movie_id));
+							PendingInsertionsRange::<T>::put((head, tail.wrapping_add(1)));
+							return;
+						}
 
-							// (total_stake / Blocks_Per_Year) * (APY / 100) = (total_stake * APY) / (Blocks_Per_Year * 100)
-							let new_earning = 
-								total_return
-								.saturating_mul(18u32.into());
-								
-							let total_earning_needed =
-								blocks_in_year
-								.saturating_mul(100u32.into());
-	
-							// return the 18% APY
-							kine_stat_tracker::Pallet::<T>::do_update_wallet_imbalance(
-								account_id.clone(), 
-								kine_stat_tracker::FeatureType::RankingList,
-								new_earning, BalanceOf::<T>::from(total_earning_needed),
-								false,
-							).unwrap();
+						if Self::do_insert_movie_in_list(list_id, movie_id.clone()) {
+							AutoInsertionsInBlock::<T>::put((now, inserted.saturating_add(1)));
+							Self::deposit_event(Event::MovieAutoAddedToList(list_id, movie_id));
 						}
-							
-	
-						// swap the id for the weight so the elements can be conveniently sorted
+					}
+
+
+					// Returns true if the movie was added. Movies already in the list are left untouched.
+					fn do_insert_movie_in_list(
+						list_id: RankingListId,
+						movie_id: BoundedVec<u8, T::LinkStringLimit>,
+					) -> bool {
+						RankingLists::<T>::mutate(list_id, |ranking_list| {
+							match ranking_list.as_mut() {
+								Some(list) if list.status == RankingListStatus::Ongoing
+									&& !list.movies_in_list.contains(&movie_id)
+									&& !kine_movie::Pallet::<T>::is_movie_suspended(&movie_id) =>
+									list.movies_in_list.try_push(movie_id).is_ok(),
+								_ => false,
+							}
+						})
+					}
+
+
+					// Processes the oldest queued automatic insertions, up to the per block cap.
+					fn do_process_pending_insertions(
+						now: BlockNumberFor<T>,
+					) -> Weight {
+						let (head, tail) = PendingInsertionsRange::<T>::get();
+						let queued = tail.wrapping_sub(head);
+						if queued == 0 {
+							return T::DbWeight::get().reads(1);
+						}
+
+						let to_process = queued.min(T::MaxAutoInsertionsPerBlock::get());
+						let mut inserted: u32 = 0;
+						for offset in 0..to_process {
+							let Some((list_id, movie_id)) = PendingListInsertions::<T>::take(head.wrapping_add(offset)) else { continue };
+							if Self::do_insert_movie_in_list(list_id, movie_id.clone()) {
+								inserted = inserted.saturating_add(1);
+								Self::deposit_event(Event::MovieAutoAddedToList(list_id, movie_id));
+							}
+						}
+						PendingInsertionsRange::<T>::put((head.wrapping_add(to_process), tail));
+						AutoInsertionsInBlock::<T>::put((now, inserted));
+
+						// each insertion takes its entry, checks the movie and updates the list
+						let to_process = to_process as u64;
+						T::DbWeight::get().reads_writes(1 + 3 * to_process, 2 + 2 * to_process)
+					}
+
+
+					// Stores the top of the round's ranking under the list's next period index, which is
+					// returned, dropping the oldest snapshot once the retention limit is reached.
+					fn do_store_ranking_snapshot(
+						list_id: RankingListId,
+						ordered_movies: &Vec<(BalanceOf::<T>, BoundedVec<u8, T::LinkStringLimit>)>,
+						total_voters: u32,
+					) -> Result<u32, DispatchError> {
+
+						let ranked_movies: BoundedVec<(BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>), T::SnapshotDepth> =
+							TryInto::try_into(
+								ordered_movies.iter()
+								.take(T::SnapshotDepth::get() as usize)
+								.map(|(voting_power, movie_id)| (movie_id.clone(), *voting_power))
+								.collect::<Vec<_>>()
+							).map_err(|_| Error::<T>::BadMetadata)?;
+
+						let period_index = NextSnapshotIndex::<T>::get(list_id);
+						RankingSnapshots::<T>::insert(list_id, period_index, RankingSnapshot {
+							block: <frame_system::Pallet<T>>::block_number(),
+							ranked_movies: ranked_movies,
+							total_voters: total_voters,
+						});
+						NextSnapshotIndex::<T>::insert(list_id, period_index.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+						let retention = T::SnapshotRetention::get();
+						if period_index >= retention {
+							RankingSnapshots::<T>::remove(list_id, period_index - retention);
+						}
+						Ok(period_index)
+					}
+
+
+					// Used by the runtime API. Returns the movie's rank in each retained snapshot of the list
+					// that has it among its top movies.
+					pub fn movie_rank_history(
+						list_id: RankingListId,
+						movie_id: Vec<u8>,
+					) -> Vec<(u32, BlockNumberFor<T>, u32, BalanceOf<T>)> {
+
+						let mut history = Vec::new();
+						let next_index = NextSnapshotIndex::<T>::get(list_id);
+						let first_index = next_index.saturating_sub(T::SnapshotRetention::get());
+						for period_index in first_index..next_index {
+							if let Some(snapshot) = RankingSnapshots::<T>::get(list_id, period_index) {
+								if let Some(position) = snapshot.ranked_movies.iter().position(|(id, _)| id.as_slice() == movie_id.as_slice()) {
+									let (_, voting_power) = snapshot.ranked_movies[position].clone();
+									history.push((period_index, snapshot.block, position as u32 + 1, voting_power));
+								}
+							}
+						}
+						history
+					}
+
+
+					// Ranks the list's movies by their voting power, kept up to date by the votes, or by
+					// their rating for pairwise lists. Suspended movies are returned apart, they keep their
+					// place in the list but are left out of the ranking.
+					fn do_rank_list_movies(
+						list_id: RankingListId,
+						movies_in_list: &BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInList>,
+					) -> (Vec<(BalanceOf::<T>, BoundedVec<u8, T::LinkStringLimit>)>, Vec<BoundedVec<u8, T::LinkStringLimit>>) {
+
+						let (ranked_movies, suspended_movies): (Vec<_>, Vec<_>) =
+							movies_in_list.iter().cloned()
+							.partition(|movie_id| !kine_movie::Pallet::<T>::is_movie_suspended(movie_id));
+
+						let ranking_mode = ListRankingModes::<T>::get(list_id);
 						let mut movies_aux: Vec<(BalanceOf::<T>, BoundedVec<u8, T::LinkStringLimit>)> =
-							movies_by_power.into_iter()
-							.map(|(movie_id, voting_power)| (voting_power, movie_id))
+							ranked_movies.into_iter()
+							.map(|movie_id| {
+								let score = match ranking_mode {
+									RankingMode::Stake => MovieTallies::<T>::get(list_id, movie_id).power,
+									RankingMode::Pairwise => BalanceOf::<T>::from(PairwiseRatings::<T>::get(list_id, movie_id)),
+								};
+								(score, movie_id)
+							})
 							.collect();
 	
 						// sort the list to get the rankings in order
 						movies_aux.sort_by(|a, b| b.cmp(a));
-						let ordered_movies_power: Vec<BoundedVec<u8, T::LinkStringLimit>> = movies_aux.into_iter().map(|(_, movie_id)| movie_id).collect(); 
-						let ordered_movies: BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInList>
-							= TryInto::try_into(ordered_movies_power).map_err(|_|Error::<T>::BadMetadata)?;
+						(movies_aux, suspended_movies)
+					}
+
+
+					// The rewards earned in the round by the voters of the list's top movies:
+					// the APY on their time-weighted stake.
+					fn do_desired_list_rewards(
+						list_id: RankingListId,
+						top_movies: &[BoundedVec<u8, T::LinkStringLimit>],
+					) -> Result<BalanceOf<T>, DispatchError> {
+
+						// stake * blocks staked in the round, over all the top movies
+						let now_as_balance = Self::do_block_as_balance(<frame_system::Pallet<T>>::block_number());
+						let mut time_weighted_stake = BalanceOf::<T>::from(0u32);
+						for movie_id in top_movies.iter() {
+							let tally = MovieTallies::<T>::get(list_id, movie_id);
+							time_weighted_stake = time_weighted_stake.saturating_add(
+								tally.stake.saturating_mul(now_as_balance).saturating_sub(tally.weighted_since)
+							);
+						}
+
+						Ok(RankingRewardParameters::<T>::get().apy.mul_floor(
+							time_weighted_stake
+							.checked_div(&BalanceOf::<T>::from(T::BlocksPerYear::get()))
+							.ok_or(Error::<T>::Underflow)?
+						))
+					}
+
+
+					// The current reward period index and how much of its budget was already handed out.
+					fn do_reward_period_spending() -> (BlockNumberFor<T>, BalanceOf<T>) {
+						let period = RankingRewardParameters::<T>::get().period;
+						let period_index = <frame_system::Pallet<T>>::block_number().checked_div(&period).unwrap_or(Zero::zero());
+						let (spending_period, spent) = RewardPeriodSpending::<T>::get();
+						let spent = if spending_period == period_index { spent } else { Zero::zero() };
+						(period_index, spent)
+					}
+
+
+					// The lists resolved in the same block share what is left of the period's budget, and
+					// what the pot can still honor, pro rata to the rewards their voters earned, rather than
+					// in the order they are resolved. Returns the share of the earned rewards that is paid.
+					pub fn do_lists_reward_scale(
+						list_ids: &[RankingListId],
+					) -> Result<Perbill, DispatchError> {
+
+						let mut total_desired = BalanceOf::<T>::from(0u32);
+						for list_id in list_ids.iter() {
+							let Some(ranking_list) = RankingLists::<T>::get(list_id) else { continue };
+							let top_movies: Vec<_> =
+								Self::do_rank_list_movies(*list_id, &ranking_list.movies_in_list).0
+								.into_iter()
+								.take(T::RewardedMoviesPerList::get() as usize)
+								.map(|(_, movie_id)| movie_id)
+								.collect();
+							total_desired = total_desired.saturating_add(Self::do_desired_list_rewards(*list_id, &top_movies)?);
+						}
+
+						let (_, spent) = Self::do_reward_period_spending();
+						let pot_available = 
+							T::Currency::free_balance(&Self::account_id())
+							.saturating_sub(T::Currency::minimum_balance())
+							.saturating_sub(OutstandingRankingRewards::<T>::get())
+							.saturating_sub(TotalLegacyVaultStakes::<T>::get());
+						let available = RankingRewardParameters::<T>::get().budget.saturating_sub(spent).min(pot_available);
+
+						Ok(if total_desired > available {
+							Perbill::from_rational(available, total_desired)
+						} else {
+							Perbill::one()
+						})
+					}
+
+
+					// Worst case weight of resolving this many lists in a block. Every movie of a list is
+					// ranked twice, once for the reward scale and once for the round itself, its tally then
+					// starts the next round. The rewarded movies' tallies are read twice, and the curators'
+					// positions are paid and restarted, or dropped with the list.
+					pub fn do_get_resolution_weight(
+						lists: u32,
+					) -> Weight {
+						let db = T::DbWeight::get();
+						let movies: u64 = T::MaxMoviesInList::get().into();
+						let rewarded_movies: u64 = T::RewardedMoviesPerList::get().into();
+						let curators: u64 = T::MaxCuratorsPerList::get().into();
+
+						// the list, its governance, ranking mode, snapshots, payout, spending and deadline
+						let per_list = db.reads_writes(12, 10)
+							.saturating_add(db.reads_writes(movies.saturating_mul(5), movies))
+							.saturating_add(db.reads(rewarded_movies.saturating_mul(2)))
+							.saturating_add(db.reads_writes(curators.saturating_mul(3), curators.saturating_mul(2)));
+
+						// the block's deadlines, the reward parameters and the pot
+						db.reads_writes(3, 1)
+							.saturating_add(per_list.saturating_mul(lists.into()))
+					}
+
+
+					// Works out the round's rewards from the movie tallies and queues them to be paid to
+					// the voters. Voters of the top ranked movies earn at most the APY on their time-weighted
+					// stake, scaled down by `scale` when the lists resolved in this block earned more than the
+					// period's budget or the pot can pay.
+					fn do_schedule_list_rewards(
+						list_id: RankingListId,
+						round: u32,
+						ordered_movies: &BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::MaxMoviesInList>,
+						round_start: BlockNumberFor<T>,
+						scale: Perbill,
+					) -> DispatchResult {
+
+						let params = RankingRewardParameters::<T>::get();
+						let now = <frame_system::Pallet<T>>::block_number();
+						let top_movies: BoundedVec<BoundedVec<u8, T::LinkStringLimit>, T::RewardedMoviesPerList> =
+							TryInto::try_into(
+								ordered_movies.iter()
+								.take(T::RewardedMoviesPerList::get() as usize)
+								.cloned()
+								.collect::<Vec<_>>()
+							).map_err(|_| Error::<T>::BadMetadata)?;
+
+						let total_desired = Self::do_desired_list_rewards(list_id, &top_movies)?;
+						if total_desired.is_zero() {
+							return Ok(());
+						}
+
+						let (period_index, spent) = Self::do_reward_period_spending();
+						let planned = scale.mul_floor(total_desired);
+
+						// the planned amount is set aside now, whatever isn't paid is released at the end
+						RewardPeriodSpending::<T>::put((period_index, spent.saturating_add(planned)));
+						OutstandingRankingRewards::<T>::mutate(|outstanding| {
+							*outstanding = outstanding.saturating_add(planned);
+						});
+
+						let mut payout = RewardPayout {
+							block: now,
+							round_start: round_start,
+							top_movies: top_movies,
+							apy: params.apy,
+							scale: scale,
+							planned: planned,
+							paid: BalanceOf::<T>::from(0u32),
+							last_key: None,
+							failed: 0,
+							pass: 0,
+						};
+						payout.paid = Self::do_pay_curator_positions(list_id, &payout)?;
+						PendingPayouts::<T>::insert(list_id, round, payout);
+						Ok(())
+					}
+
+
+					// Pays the curators' positions in the list right away. The curator keeps their
+					// commission and the rest goes to the pools backing the position, pro rata to their
+					// stake. Returns the total paid.
+					fn do_pay_curator_positions(
+						list_id: RankingListId,
+						payout: &RewardPayoutOf<T>,
+					) -> Result<BalanceOf<T>, DispatchError> {
+
+						let mut total_paid = BalanceOf::<T>::from(0u32);
+						for curator in CuratorsInList::<T>::get(list_id).iter() {
+							let position = match CuratorPositions::<T>::get(list_id, curator) {
+								Some(position) => position,
+								None => continue,
+							};
+
+							// stake * blocks staked in the round, for the shares placed on the top movies
+							let staked = 
+								position.stake
+								.saturating_mul(Self::do_block_as_balance(payout.block))
+								.saturating_sub(position.weighted_since);
+							let mut time_weighted_stake = BalanceOf::<T>::from(0u32);
+							for (movie_id, share) in position.allocations.iter() {
+								if payout.top_movies.contains(movie_id) {
+									time_weighted_stake = time_weighted_stake.saturating_add(share.mul_floor(staked));
+								}
+							}
+							let reward = payout.scale.mul_floor(payout.apy.mul_floor(
+								time_weighted_stake
+								.checked_div(&BalanceOf::<T>::from(T::BlocksPerYear::get()))
+								.unwrap_or(Zero::zero())
+							));
+							if reward.is_zero() || position.stake.is_zero() {
+								continue;
+							}
+
+							let commission = position.commission.mul_floor(reward);
+							if !commission.is_zero() {
+								kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+									curator.clone(), 
+									kine_stat_tracker::FeatureType::RankingList,
+									kine_stat_tracker::TokenType::Claimable,
+									commission, false,
+								)?;
+							}
+
+							let delegators_reward = reward.saturating_sub(commission);
+							for scope in [DelegationScope::AllLists, DelegationScope::List(list_id)] {
+								DelegationPools::<T>::mutate(curator, scope, |pool| {
+									if pool.stake.is_zero() {
+										return;
+									}
+									let pool_reward = Perbill::from_rational(pool.stake, position.stake).mul_floor(delegators_reward);
+									pool.reward_per_stake = pool.reward_per_stake.saturating_add(
+										pool_reward
+										.saturating_mul(BalanceOf::<T>::from(REWARD_PER_STAKE_PRECISION))
+										.checked_div(&pool.stake)
+										.unwrap_or(Zero::zero())
+									);
+								});
+							}
+							total_paid = total_paid.saturating_add(reward);
+						}
+						Ok(total_paid)
+					}
+
+
+					// Stake and voting power a curator moves in a list: what was delegated to them
+					// for every list plus what was delegated for this list only.
+					fn do_curator_capital(
+						curator: &T::AccountId,
+						list_id: RankingListId,
+					) -> (BalanceOf<T>, BalanceOf<T>) {
+						let all_lists = DelegationPools::<T>::get(curator, DelegationScope::AllLists);
+						let single_list = DelegationPools::<T>::get(curator, DelegationScope::List(list_id));
+						(
+							all_lists.stake.saturating_add(single_list.stake),
+							all_lists.power.saturating_add(single_list.power),
+						)
+					}
+
+
+					// Adds the curator's position to the list's movie tallies, or takes it out.
+					fn do_apply_position_to_tallies(
+						list_id: RankingListId,
+						position: &CuratorPositionOf<T>,
+						add: bool,
+					) {
+						for (movie_id, share) in position.allocations.iter() {
+							let power = share.mul_floor(position.power);
+							let stake = share.mul_floor(position.stake);
+							let weighted_since = share.mul_floor(position.weighted_since);
+							MovieTallies::<T>::mutate(list_id, movie_id, |tally| {
+								if add {
+									tally.power = tally.power.saturating_add(power);
+									tally.stake = tally.stake.saturating_add(stake);
+									tally.weighted_since = tally.weighted_since.saturating_add(weighted_since);
+								}
+								else {
+									tally.power = tally.power.saturating_sub(power);
+									tally.stake = tally.stake.saturating_sub(stake);
+									tally.weighted_since = tally.weighted_since.saturating_sub(weighted_since);
+								}
+							});
+						}
+					}
+
+
+					// Updates the curator's positions after the pool of the given scope changed.
+					// New stake starts counting now, withdrawn stake takes its part of the elapsed time with it.
+					fn do_refresh_curator_positions(
+						curator: &T::AccountId,
+						scope: DelegationScope,
+					) {
+						let list_ids: Vec<RankingListId> = match scope {
+							DelegationScope::AllLists => CuratedLists::<T>::get(curator).into_inner(),
+							DelegationScope::List(list_id) => [list_id].to_vec(),
+						};
+						let now_as_balance = Self::do_block_as_balance(<frame_system::Pallet<T>>::block_number());
+
+						for list_id in list_ids {
+							let old_position = match CuratorPositions::<T>::get(list_id, curator) {
+								Some(position) => position,
+								None => continue,
+							};
+							Self::do_apply_position_to_tallies(list_id, &old_position, false);
+
+							let (stake, power) = Self::do_curator_capital(curator, list_id);
+							let weighted_since = if stake >= old_position.stake {
+								old_position.weighted_since.saturating_add(
+									stake.saturating_sub(old_position.stake).saturating_mul(now_as_balance)
+								)
+							} else {
+								Perbill::from_rational(stake, old_position.stake).mul_floor(old_position.weighted_since)
+							};
+							let position = CuratorPosition {
+								allocations: old_position.allocations,
+								stake: stake,
+								power: power,
+								weighted_since: weighted_since,
+								commission: old_position.commission,
+							};
+							Self::do_apply_position_to_tallies(list_id, &position, true);
+							CuratorPositions::<T>::insert(list_id, curator, position);
+						}
+					}
+
+
+					// The rewards a delegated amount earned since its pool started.
+					fn do_accrued_pool_rewards(
+						amount: BalanceOf<T>,
+						reward_per_stake: BalanceOf<T>,
+					) -> BalanceOf<T> {
+						amount
+							.saturating_mul(reward_per_stake)
+							.checked_div(&BalanceOf::<T>::from(REWARD_PER_STAKE_PRECISION))
+							.unwrap_or(Zero::zero())
+					}
+
+
+					// Credits the delegation's pending share of its pool's rewards as claimable ranking tokens.
+					fn do_harvest_delegation(
+						delegator: &T::AccountId,
+						scope: DelegationScope,
+						delegation: &mut Delegation<T::AccountId, BalanceOf<T>>,
+					) -> Result<BalanceOf<T>, DispatchError> {
+
+						let pool = DelegationPools::<T>::get(&delegation.curator, scope);
+						let accrued = Self::do_accrued_pool_rewards(delegation.amount, pool.reward_per_stake);
+						let pending = accrued.saturating_sub(delegation.reward_debt);
+						delegation.reward_debt = accrued;
+
+						if !pending.is_zero() {
+							kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+								delegator.clone(), 
+								kine_stat_tracker::FeatureType::RankingList,
+								kine_stat_tracker::TokenType::Claimable,
+								pending, false,
+							)?;
+						}
+						Ok(pending)
+					}
+
+
+					// Counts a judgement against the account's allowance, starting a new window
+					// once the previous one is over.
+					fn do_use_judgement_allowance(
+						who: &T::AccountId,
+					) -> DispatchResult {
+
+						let now = <frame_system::Pallet<T>>::block_number();
+						JudgementAllowances::<T>::try_mutate(who, |(window_start, judgements)| -> DispatchResult {
+							if now >= window_start.saturating_add(T::JudgementWindow::get().into()) {
+								*window_start = now;
+								*judgements = 0;
+							}
+							ensure!(*judgements < T::MaxJudgementsPerWindow::get(), Error::<T>::TooManyJudgements);
+							*judgements = judgements.saturating_add(1);
+							Ok(())
+						})
+					}
+
+
+					// Draws two different movies that aren't suspended, each pick hashing
+					// the draw's seed with its index like the moderators' drafts.
+					fn do_draw_judgement_pair(
+						movies: Vec<BoundedVec<u8, T::LinkStringLimit>>,
+					) -> Result<(BoundedVec<u8, T::LinkStringLimit>, BoundedVec<u8, T::LinkStringLimit>), DispatchError> {
+
+						let mut candidates: Vec<BoundedVec<u8, T::LinkStringLimit>> =
+							movies.into_iter()
+							.filter(|movie_id| !kine_movie::Pallet::<T>::is_movie_suspended(movie_id))
+							.collect();
+						ensure!(candidates.len() >= 2, Error::<T>::NotEnoughMoviesToJudge);
+
+						let nonce = JudgementNonce::<T>::mutate(|nonce| {
+							*nonce = nonce.wrapping_add(1);
+							*nonce
+						});
+						let (seed, _) = T::Randomness::random(&(b"kine/rnk", nonce).encode());
+
+						let mut pair = Vec::new();
+						for draw_index in 0u32..2 {
+							let random_hash = T::Hashing::hash_of(&(seed, draw_index));
+							let pick = 
+								u64::decode(&mut random_hash.as_ref())
+								.map_err(|_| Error::<T>::BadMetadata)?
+								% candidates.len() as u64;
+							pair.push(candidates.swap_remove(pick as usize));
+						}
+						let second = pair.pop().ok_or(Error::<T>::NotEnoughMoviesToJudge)?;
+						let first = pair.pop().ok_or(Error::<T>::NotEnoughMoviesToJudge)?;
+
+						Ok((first, second))
+					}
+
+
+					// The score the rated movie is expected to get against the opponent,
+					// interpolated from ELO_EXPECTED_SCORES.
+					fn do_expected_pairwise_score(
+						rating: u32,
+						opponent_rating: u32,
+					) -> Perbill {
+
+						let difference = rating.abs_diff(opponent_rating).min(800);
+						let index = (difference / 50) as usize;
+						let low = ELO_EXPECTED_SCORES[index];
+						let high = ELO_EXPECTED_SCORES[(index + 1).min(ELO_EXPECTED_SCORES.len() - 1)];
+						let favourite_score = Perbill::from_parts(low + (high - low) / 50 * (difference % 50));
+
+						if rating >= opponent_rating {
+							favourite_score
+						} else {
+							Perbill::one().saturating_sub(favourite_score)
+						}
+					}
+
+
+					// Moves the winner's rating up and the loser's down by the same amount,
+					// the less expected the outcome the bigger the change.
+					fn do_update_pairwise_ratings(
+						list_id: RankingListId,
+						winner: &BoundedVec<u8, T::LinkStringLimit>,
+						loser: &BoundedVec<u8, T::LinkStringLimit>,
+					) {
+						let winner_rating = PairwiseRatings::<T>::get(list_id, winner);
+						let loser_rating = PairwiseRatings::<T>::get(list_id, loser);
+
+						let expected_score = Self::do_expected_pairwise_score(winner_rating, loser_rating);
+						let change = Perbill::one().saturating_sub(expected_score) * T::EloKFactor::get();
+
+						PairwiseRatings::<T>::insert(list_id, winner, winner_rating.saturating_add(change));
+						PairwiseRatings::<T>::insert(list_id, loser, loser_rating.saturating_sub(change));
+					}
+
+
+					// A voter's share of a round, based on the stake they had in the round's top movies.
+					fn do_calculate_voter_reward(
+						votes: &BoundedBTreeMap<
+							BoundedVec<u8, T::LinkStringLimit>, 
+							RankingVote<BoundedVec<u8, T::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>, 
+							T::MaxVotesPerVoter,
+						>,
+						payout: &RewardPayoutOf<T>,
+					) -> BalanceOf<T> {
+
+						let mut time_weighted_stake = BalanceOf::<T>::from(0u32);
+						for (movie_id, vote) in votes.iter() {
+							if !payout.top_movies.contains(movie_id) {
+								continue;
+							}
+							// votes cast after the round ended count for nothing
+							let staked_blocks = payout.block.saturating_sub(vote.staked_since.max(payout.round_start));
+							time_weighted_stake = time_weighted_stake.saturating_add(
+								vote.locked_amount.saturating_mul(Self::do_block_as_balance(staked_blocks))
+							);
+						}
+
+						let reward = payout.apy.mul_floor(
+							time_weighted_stake
+							.checked_div(&BalanceOf::<T>::from(T::BlocksPerYear::get()))
+							.unwrap_or(Zero::zero())
+						);
+						payout.scale.mul_floor(reward)
+					}
+
+
+					// Pays the voter every round of the list that is still being paid out and that they
+					// haven't received yet. This runs before their votes change, so the rewards are
+					// based on the votes they had during those rounds.
+					fn do_settle_voter_rewards(
+						list_id: RankingListId,
+						who: &T::AccountId,
+						voter: &mut ListVoterOf<T>,
+					) -> DispatchResult {
+
+						let mut payouts: Vec<(u32, RewardPayoutOf<T>)> =
+							PendingPayouts::<T>::iter_prefix(list_id)
+							.filter(|(round, _)| *round >= voter.rewarded_until)
+							.collect();
+						payouts.sort_by_key(|(round, _)| *round);
+
+						for (round, payout) in payouts {
+							let reward = Self::do_calculate_voter_reward(&voter.votes, &payout);
+							if !reward.is_zero() {
+								kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+									who.clone(), 
+									kine_stat_tracker::FeatureType::RankingList,
+									kine_stat_tracker::TokenType::Claimable,
+									reward, false,
+								)?;
+								PendingPayouts::<T>::mutate(list_id, round, |pending| {
+									if let Some(pending) = pending.as_mut() {
+										pending.paid = pending.paid.saturating_add(reward);
+									}
+								});
+							}
+							voter.rewarded_until = round.saturating_add(1);
+						}
+						Ok(())
+					}
+
+
+					// Works through the voters of closed lists, refunding them, and then through the
+					// voters of resolved rounds, paying their rewards. At most VoterPageSize voters
+					// are handled per block.
+					pub fn do_process_voter_pages() -> Weight {
 						
-						Self::deposit_event(Event::RankingListPayoff(list_id));
-						Ok(ordered_movies)
+						let page_size = T::VoterPageSize::get();
+						let mut processed: u32 = 0;
+						let mut reads: u64 = 2;
+						let mut writes: u64 = 0;
+						let mut reputation_updates: u64 = 0;
+
+						let refunding: Vec<(RankingListId, Option<BoundedVec<u8, ConstU32<256>>>)> =
+							PendingRefunds::<T>::iter().take(page_size as usize).collect();
+						for (list_id, last_key) in refunding {
+							let requested = page_size.saturating_sub(processed);
+							if requested == 0 {
+								break;
+							}
+
+							let page: Vec<(T::AccountId, ListVoterOf<T>)> = match last_key {
+								Some(key) => ListVotes::<T>::iter_prefix_from(list_id, key.into_inner()).take(requested as usize).collect(),
+								None => ListVotes::<T>::iter_prefix(list_id).take(requested as usize).collect(),
+							};
+							let page_len = page.len() as u32;
+							let mut last_voter = None;
+							for (who, voter) in page {
+								// the voter keeps their votes and can still withdraw them with unvote_from
+								if Self::do_refund_voter(list_id, &who, voter).is_err() {
+									Self::deposit_event(Event::VoterRefundFailed(list_id, who.clone()));
+								}
+								last_voter = Some(who);
+							}
+							processed = processed.saturating_add(page_len);
+							reads = reads.saturating_add(3u64.saturating_mul(page_len.into()));
+							writes = writes.saturating_add(4u64.saturating_mul(page_len.into()));
+
+							let next_key: Option<BoundedVec<u8, ConstU32<256>>> = last_voter
+								.and_then(|who| TryInto::try_into(ListVotes::<T>::hashed_key_for(list_id, &who)).ok());
+							match next_key {
+								Some(key) if page_len >= requested => {
+									PendingRefunds::<T>::insert(list_id, Some(key));
+								},
+								// every voter was handled
+								_ => {
+									PendingRefunds::<T>::remove(list_id);
+									let _ = MovieTallies::<T>::clear_prefix(list_id, u32::MAX, None);
+									let _ = PairwiseRatings::<T>::clear_prefix(list_id, u32::MAX, None);
+									let _ = JudgementPairs::<T>::clear_prefix(list_id, u32::MAX, None);
+									writes = writes.saturating_add(3);
+								},
+							}
+							writes = writes.saturating_add(1);
+						}
+
+						let paying: Vec<(RankingListId, u32)> = PendingPayouts::<T>::iter_keys().take(page_size as usize).collect();
+						for (list_id, round) in paying {
+							let requested = page_size.saturating_sub(processed);
+							if requested == 0 {
+								break;
+							}
+
+							let payout = match PendingPayouts::<T>::get(list_id, round) {
+								Some(payout) => payout,
+								None => continue,
+							};
+							let page: Vec<(T::AccountId, ListVoterOf<T>)> = match payout.last_key.clone() {
+								Some(key) => ListVotes::<T>::iter_prefix_from(list_id, key.into_inner()).take(requested as usize).collect(),
+								None => ListVotes::<T>::iter_prefix(list_id).take(requested as usize).collect(),
+							};
+							let page_len = page.len() as u32;
+							let top_movie = RankingSnapshots::<T>::get(list_id, round)
+								.and_then(|snapshot| snapshot.ranked_movies.first().map(|(movie_id, _)| movie_id.clone()));
+
+							let mut last_voter = None;
+							let mut failed: u32 = 0;
+							for (who, mut voter) in page {
+								// every voter who backed the top movie during the round gains reputation once
+								let backed_top_movie = top_movie.as_ref()
+									.and_then(|movie_id| voter.votes.get(movie_id))
+									.map_or(false, |vote| vote.staked_since < payout.block);
+								if payout.pass == 0 && backed_top_movie {
+									T::Reputation::update_reputation(&who, ReputationDomain::Curation, TOP_MOVIE_VOTER_REPUTATION, false);
+									reputation_updates = reputation_updates.saturating_add(1);
+								}
+
+								// voters whose votes changed since the round, or paid in an earlier pass, were already paid
+								if voter.rewarded_until <= round {
+									// the rounds paid before the failure are kept, the rest is retried in the next pass
+									if Self::do_settle_voter_rewards(list_id, &who, &mut voter).is_err() {
+										failed = failed.saturating_add(1);
+										Self::deposit_event(Event::VoterPayoutFailed(list_id, who.clone()));
+									}
+									ListVotes::<T>::insert(list_id, &who, voter);
+								}
+								last_voter = Some(who);
+							}
+							processed = processed.saturating_add(page_len);
+							reads = reads.saturating_add(3u64.saturating_mul(page_len.into()).saturating_add(1));
+							writes = writes.saturating_add(4u64.saturating_mul(page_len.into()));
+
+							let next_key: Option<BoundedVec<u8, ConstU32<256>>> = last_voter
+								.and_then(|who| TryInto::try_into(ListVotes::<T>::hashed_key_for(list_id, &who)).ok());
+							match next_key {
+								Some(key) if page_len >= requested => {
+									PendingPayouts::<T>::mutate(list_id, round, |pending| {
+										if let Some(pending) = pending.as_mut() {
+											pending.last_key = Some(key);
+											pending.failed = pending.failed.saturating_add(failed);
+										}
+									});
+								},
+								// start another pass for the voters whose payout failed
+								_ if payout.failed.saturating_add(failed) > 0 && payout.pass.saturating_add(1) < MAX_PAYOUT_PASSES => {
+									PendingPayouts::<T>::mutate(list_id, round, |pending| {
+										if let Some(pending) = pending.as_mut() {
+											pending.last_key = None;
+											pending.failed = 0;
+											pending.pass = pending.pass.saturating_add(1);
+										}
+									});
+								},
+								_ => Self::do_finish_payout(list_id, round),
+							}
+							writes = writes.saturating_add(1);
+						}
+
+						T::DbWeight::get().reads_writes(reads, writes)
+							.saturating_add(T::Reputation::update_weight().saturating_mul(reputation_updates))
+					}
+
+
+					// Pays what is left of the voter's rewards and returns their whole stake in a closed list.
+					// If any step fails the whole refund is rolled back, leaving the voter's votes in place.
+					fn do_refund_voter(
+						list_id: RankingListId,
+						who: &T::AccountId,
+						mut voter: ListVoterOf<T>,
+					) -> DispatchResult {
+						frame_support::storage::with_storage_layer(|| -> DispatchResult {
+							Self::do_settle_voter_rewards(list_id, who, &mut voter)?;
+
+							let mut stake = BalanceOf::<T>::from(0u32);
+							for (_, vote) in voter.votes.iter() {
+								stake = stake.saturating_add(vote.locked_amount);
+							}
+							if !stake.is_zero() {
+								kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+									who.clone(), 
+									kine_stat_tracker::FeatureType::RankingList,
+									kine_stat_tracker::TokenType::Locked,
+									stake.clone(), true
+								)?;
+								Self::do_unlock_ranking_tokens(who, stake)?;
+							}
+
+							ListVotes::<T>::remove(list_id, who);
+							RankingLists::<T>::mutate(list_id, |ranking_list| {
+								if let Some(list) = ranking_list.as_mut() {
+									list.total_lockup = list.total_lockup.saturating_sub(stake);
+									list.total_voters = list.total_voters.saturating_sub(1);
+								}
+							});
+							Ok(())
+						})
+					}
+
+
+					// Drops a fully paid round, releasing the part of its budget nobody was owed.
+					fn do_finish_payout(
+						list_id: RankingListId,
+						round: u32,
+					) {
+						if let Some(payout) = PendingPayouts::<T>::take(list_id, round) {
+							let unpaid = payout.planned.saturating_sub(payout.paid);
+							OutstandingRankingRewards::<T>::mutate(|outstanding| {
+								*outstanding = outstanding.saturating_sub(unpaid);
+							});
+							Self::deposit_event(Event::RankingRewardsDistributed(list_id, payout.paid));
+						}
 					}
 	
 	
@@ -1124,4 +3122,77 @@
 	
 			}
 	
-	}
\ No newline at end of file
+	
+		impl<T: Config> kine_movie::MovieTagsHandler<
+			BoundedVec<u8, T::LinkStringLimit>,
+			BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
+		> for Pallet<T> {
+			// Adds a newly created or re-tagged movie to every list whose rule it matches.
+			fn on_movie_tagged(
+				movie_id: &BoundedVec<u8, T::LinkStringLimit>,
+				categories_and_tags: &BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
+			) {
+				let mut matching_lists: Vec<RankingListId> = Vec::new();
+				for category_tag in categories_and_tags.iter() {
+					for list_id in ListsByEligibleTag::<T>::iter_key_prefix(category_tag) {
+						if !matching_lists.contains(&list_id) {
+							matching_lists.push(list_id);
+						}
+					}
+				}
+
+				for list_id in matching_lists {
+					Self::do_auto_insert_movie(list_id, movie_id.clone());
+				}
+			}
+		}
+
+
+		// Lists from the genesis have no owner.
+		impl<T: Config> ContentOwnership<RankingListId, T::AccountId> for Pallet<T> {
+			fn content_owner(
+				list_id: &RankingListId,
+			) -> Result<Option<T::AccountId>, DispatchError> {
+				ensure!(RankingLists::<T>::contains_key(list_id), Error::<T>::RankingListNotFound);
+				Ok(ListGovernance::<T>::get(list_id).map(|info| info.owner))
+			}
+		}
+
+
+		impl<T: Config> ContentModeration<RankingListId> for Pallet<T> {
+			// Pauses the list's rounds. Its voters can still leave it, regardless of their conviction.
+			fn suspend_content(
+				list_id: &RankingListId,
+			) -> DispatchResult {
+				let list_deadline = RankingLists::<T>::try_mutate(list_id, |ranking_list| -> Result<BlockNumberFor<T>, DispatchError> {
+					let list = ranking_list.as_mut().ok_or(Error::<T>::RankingListNotFound)?;
+					ensure!(list.status == RankingListStatus::Ongoing, Error::<T>::RankingListNotOngoing);
+					list.status = RankingListStatus::Suspended;
+					Ok(list.list_deadline)
+				})?;
+				Self::do_remove_list_deadline(*list_id, list_deadline);
+
+				Self::deposit_event(Event::RankingListSuspended(*list_id));
+				Ok(())
+			}
+
+			// Resumes the list with a full round starting now.
+			fn reinstate_content(
+				list_id: &RankingListId,
+			) -> DispatchResult {
+				RankingLists::<T>::try_mutate(list_id, |ranking_list| -> DispatchResult {
+					let list = ranking_list.as_mut().ok_or(Error::<T>::RankingListNotFound)?;
+					ensure!(list.status == RankingListStatus::Suspended, Error::<T>::RankingListNotSuspended);
+					list.status = RankingListStatus::Ongoing;
+					list.list_deadline =
+						<frame_system::Pallet<T>>::block_number().checked_add(&list.list_duration).ok_or(Error::<T>::Overflow)?;
+					Self::create_list_deadline(*list_id, list.list_deadline).map_err(|e| e.error)?;
+					Ok(())
+				})?;
+
+				Self::deposit_event(Event::RankingListReinstated(*list_id));
+				Ok(())
+			}
+		}
+	
+}
\ No newline at end of file
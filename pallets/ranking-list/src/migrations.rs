@@ -0,0 +1,378 @@
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::{bounded_btree_map::BoundedBTreeMap, IterableStorageMap},
+	traits::{
+		Currency, ExistenceRequirement::AllowDeath, GetStorageVersion,
+		OnRuntimeUpgrade, StorageVersion,
+	},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use kine_tags::{CategoryId, TagId};
+use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+type BalanceOf<T> = <<T as kine_stat_tracker::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+// Migrations are paged: the runtime upgrade translates the first page and stores a cursor,
+// `on_initialize` then translates a page per block, moving on to the next version until the
+// storage is current. Lists due meanwhile are postponed, calls are refused by the runtime.
+pub const MIGRATION_PAGE_SIZE: u32 = 10;
+
+// The raw key of the last translated list, an empty key when the next version starts from scratch.
+#[frame_support::storage_alias]
+pub type MigrationCursor<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+
+// The ranking lists can't be touched while they, or the wallet tokens they update, are migrated.
+pub fn is_migrating<T: Config>() -> bool {
+	MigrationCursor::<T>::exists() || kine_stat_tracker::migrations::is_migrating::<T>()
+}
+
+pub fn migrate_page<T: Config>() -> Weight {
+	let cursor = match MigrationCursor::<T>::get() {
+		Some(cursor) => cursor,
+		None => return T::DbWeight::get().reads(1),
+	};
+	// the stakes are credited as wallet tokens, which must be translated first
+	if kine_stat_tracker::migrations::is_migrating::<T>() {
+		return T::DbWeight::get().reads(2)
+	}
+
+	let mut previous_key = if cursor.is_empty() { None } else { Some(cursor) };
+	let mut remaining = MIGRATION_PAGE_SIZE;
+	let mut weight = T::DbWeight::get().reads_writes(3, 1);
+	while remaining > 0 {
+		let version = Pallet::<T>::on_chain_storage_version();
+		let (next_version, (next_key, translated, page_weight)) = if version == 0 {
+			(1, v1::migrate_page::<T>(previous_key, remaining))
+		} else if version == 1 {
+			(2, v2::migrate_page::<T>(previous_key, remaining))
+		} else if version == 2 {
+			(3, v3::migrate_page::<T>(previous_key, remaining))
+		} else {
+			MigrationCursor::<T>::kill();
+			return weight
+		};
+		weight = weight.saturating_add(page_weight);
+		remaining = remaining.saturating_sub(translated);
+
+		previous_key = next_key;
+		if previous_key.is_none() {
+			StorageVersion::new(next_version).put::<Pallet<T>>();
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+		}
+	}
+
+	MigrationCursor::<T>::put(previous_key.unwrap_or_default());
+	weight
+}
+
+// Starts paging through the lists, unless the storage isn't at `version` or is already being migrated.
+fn start_migration<T: Config>(version: u16) -> Weight {
+	if Pallet::<T>::on_chain_storage_version() != version || MigrationCursor::<T>::exists() {
+		return T::DbWeight::get().reads(2)
+	}
+	MigrationCursor::<T>::put(Vec::<u8>::new());
+	migrate_page::<T>()
+}
+
+// Layout of the ranking lists before votes tracked when they were staked.
+pub mod v0 {
+	use super::*;
+
+	// Ranking lists used to hold all of their votes.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OldRankingList<BoundedString, RankingListStatus, BlockNumber, MovieList, VoteMap, Balance, CategoryTagList> {
+		pub name: BoundedString,
+		pub description: BoundedString,
+		pub status: RankingListStatus,
+		pub list_duration: BlockNumber,
+		pub list_deadline: BlockNumber,
+		pub movies_in_list: MovieList,
+		pub votes_by_user: VoteMap,
+		pub total_lockup: Balance,
+		pub categories_and_tags: CategoryTagList,
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OldRankingVote<MovieId, BalanceOf, BlockNumber> {
+		pub movie_id: MovieId,
+		pub locked_amount: BalanceOf,
+		pub conviction: Conviction,
+		pub unlock_block: BlockNumber,
+	}
+
+	pub type OldRankingListOf<T> = OldRankingList<
+		BoundedVec<u8, <T as Config>::RankingStringLimit>,
+		RankingListStatus,
+		BlockNumberFor<T>,
+		BoundedVec<BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, <T as Config>::MaxMoviesInList>,
+		BoundedBTreeMap<
+			<T as frame_system::Config>::AccountId,
+			BoundedBTreeMap<
+				BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>,
+				OldRankingVote<BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>,
+				<T as Config>::MaxVotersPerList,
+			>,
+			<T as Config>::MaxVotersPerList,
+		>,
+		BalanceOf<T>,
+		BoundedVec<(CategoryId<T>, TagId<T>), <T as kine_tags::Config>::MaxTags>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type RankingLists<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, RankingListId, OldRankingListOf<T>>;
+}
+
+// Votes used to be transferred into the `kine/rnk` vault. This returns every
+// voter's stake from the vault and locks it in their own account instead. Stakes
+// the vault can't return are recorded in LegacyVaultStakes and paid as they're unvoted.
+pub mod v1 {
+	use super::*;
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			start_migration::<T>(0)
+		}
+	}
+
+	// Returns the stakes of up to `limit` lists after `previous_key`. Returns the key
+	// to resume from, `None` once every list is done, and the lists migrated.
+	pub fn migrate_page<T: Config>(previous_key: Option<Vec<u8>>, limit: u32) -> (Option<Vec<u8>>, u32, Weight) {
+		let lists = match previous_key {
+			Some(key) => v0::RankingLists::<T>::iter_from(key),
+			None => v0::RankingLists::<T>::iter(),
+		};
+
+		let vault: T::AccountId = <T as Config>::PalletId::get().into_account_truncating();
+		let mut last_key = None;
+		let mut translated: u32 = 0;
+		let mut reads_writes: u64 = 0;
+		for (list_id, ranking_list) in lists.take(limit as usize) {
+			last_key = Some(v0::RankingLists::<T>::hashed_key_for(list_id));
+			translated = translated.saturating_add(1);
+
+			for (voter, user_votes) in ranking_list.votes_by_user.iter() {
+				let mut stake: BalanceOf<T> = Zero::zero();
+				for (_, vote) in user_votes.iter() {
+					stake = stake.saturating_add(vote.locked_amount);
+				}
+				if stake.is_zero() {
+					continue
+				}
+				reads_writes = reads_writes.saturating_add(3);
+
+				// the stake is only locked once it's back in the voter's account
+				let returned = frame_support::storage::with_storage_layer(|| -> DispatchResult {
+					<T as kine_stat_tracker::Config>::Currency::transfer(
+						&vault, voter,
+						stake, AllowDeath,
+					)?;
+					Pallet::<T>::do_lock_ranking_tokens(voter, stake)
+				});
+				if let Err(e) = returned {
+					frame_support::log::warn!(
+						target: "runtime::ranking-list",
+						"stake of {:?} in list {} left in the vault: {:?}",
+						voter, list_id, e,
+					);
+					LegacyVaultStakes::<T>::mutate(voter, |legacy_stake| *legacy_stake = legacy_stake.saturating_add(stake));
+					TotalLegacyVaultStakes::<T>::mutate(|total| *total = total.saturating_add(stake));
+				}
+			}
+		}
+
+		let next_key = if translated < limit { None } else { last_key };
+		let weight = T::DbWeight::get().reads_writes(
+			reads_writes.saturating_add(translated.into()),
+			reads_writes,
+		);
+		(next_key, translated, weight)
+	}
+}
+
+// Adds `staked_since` to every vote. Existing votes count as staked since
+// the start of their list's current round.
+pub mod v2 {
+	use super::*;
+
+	pub type V2RankingListOf<T> = v0::OldRankingList<
+		BoundedVec<u8, <T as Config>::RankingStringLimit>,
+		RankingListStatus,
+		BlockNumberFor<T>,
+		BoundedVec<BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, <T as Config>::MaxMoviesInList>,
+		BoundedBTreeMap<
+			<T as frame_system::Config>::AccountId,
+			BoundedBTreeMap<
+				BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>,
+				RankingVote<BoundedVec<u8, <T as kine_movie::Config>::LinkStringLimit>, BalanceOf<T>, BlockNumberFor<T>>,
+				<T as Config>::MaxVotersPerList,
+			>,
+			<T as Config>::MaxVotersPerList,
+		>,
+		BalanceOf<T>,
+		BoundedVec<(CategoryId<T>, TagId<T>), <T as kine_tags::Config>::MaxTags>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type RankingLists<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, RankingListId, V2RankingListOf<T>>;
+
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			start_migration::<T>(1)
+		}
+	}
+
+	// Translates up to `limit` lists after `previous_key`. Returns the key to resume
+	// from, `None` once every list is translated, and the lists translated.
+	pub fn migrate_page<T: Config>(mut previous_key: Option<Vec<u8>>, limit: u32) -> (Option<Vec<u8>>, u32, Weight) {
+		let mut translated: u32 = 0;
+		while translated < limit {
+			previous_key = RankingLists::<T>::translate_next::<v0::OldRankingListOf<T>, _>(previous_key, |_, old| {
+				let round_start = old.list_deadline.saturating_sub(old.list_duration);
+
+				let mut votes_by_user = BoundedBTreeMap::new();
+				for (voter, user_votes) in old.votes_by_user.into_iter() {
+					let mut new_user_votes = BoundedBTreeMap::new();
+					for (movie_id, vote) in user_votes.into_iter() {
+						let _ = new_user_votes.try_insert(movie_id, RankingVote {
+							movie_id: vote.movie_id,
+							locked_amount: vote.locked_amount,
+							conviction: vote.conviction,
+							unlock_block: vote.unlock_block,
+							staked_since: round_start,
+						});
+					}
+					let _ = votes_by_user.try_insert(voter, new_user_votes);
+				}
+
+				Some(v0::OldRankingList {
+					name: old.name,
+					description: old.description,
+					status: old.status,
+					list_duration: old.list_duration,
+					list_deadline: old.list_deadline,
+					movies_in_list: old.movies_in_list,
+					votes_by_user: votes_by_user,
+					total_lockup: old.total_lockup,
+					categories_and_tags: old.categories_and_tags,
+				})
+			});
+			if previous_key.is_none() {
+				break
+			}
+			translated = translated.saturating_add(1);
+		}
+		(previous_key, translated, T::DbWeight::get().reads_writes(translated.into(), translated.into()))
+	}
+}
+
+// Moves the votes out of the ranking lists into ListVotes and builds the movie tallies.
+// Voters holding more than MaxVotesPerVoter votes in a list get the extra stakes unlocked.
+pub mod v3 {
+	use super::*;
+
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			start_migration::<T>(2)
+		}
+	}
+
+	// Translates up to `limit` lists after `previous_key`. Returns the key to resume
+	// from, `None` once every list is translated, and the lists translated.
+	pub fn migrate_page<T: Config>(mut previous_key: Option<Vec<u8>>, limit: u32) -> (Option<Vec<u8>>, u32, Weight) {
+		let mut translated: u32 = 0;
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		while translated < limit {
+			previous_key = RankingLists::<T>::translate_next::<v2::V2RankingListOf<T>, _>(previous_key, |list_id, old| {
+				reads = reads.saturating_add(2);
+				writes = writes.saturating_add(1);
+				let round_start = old.list_deadline.saturating_sub(old.list_duration);
+				let rewarded_until = NextSnapshotIndex::<T>::get(list_id);
+				let mut total_lockup = old.total_lockup;
+				let mut total_voters: u32 = 0;
+
+				for (voter, user_votes) in old.votes_by_user.into_iter() {
+					let mut votes: BoundedBTreeMap<_, _, <T as Config>::MaxVotesPerVoter> = BoundedBTreeMap::new();
+					for (movie_id, vote) in user_votes.into_iter() {
+						let amount = vote.locked_amount;
+						let conviction = vote.conviction;
+						let staked_from = vote.staked_since.max(round_start);
+
+						if votes.try_insert(movie_id.clone(), vote).is_err() {
+							let unlocked = frame_support::storage::with_storage_layer(|| -> DispatchResult {
+								Pallet::<T>::do_unlock_ranking_tokens(&voter, amount)?;
+								kine_stat_tracker::Pallet::<T>::do_update_wallet_tokens(
+									voter.clone(),
+									kine_stat_tracker::FeatureType::RankingList,
+									kine_stat_tracker::TokenType::Locked,
+									amount, true,
+								)?;
+								Ok(())
+							});
+							// the vote is dropped either way, a failed unlock is left as it was and logged
+							if let Err(e) = unlocked {
+								frame_support::log::error!(
+									target: "runtime::ranking-list",
+									"couldn't unlock the extra vote of {:?} in list {}: {:?}",
+									voter, list_id, e,
+								);
+							}
+							total_lockup = total_lockup.saturating_sub(amount);
+							writes = writes.saturating_add(3);
+							continue
+						}
+
+						let voting_power = Pallet::<T>::do_calculate_voting_power(amount, conviction).unwrap_or(Zero::zero());
+						MovieTallies::<T>::mutate(list_id, &movie_id, |tally| {
+							tally.power = tally.power.saturating_add(voting_power);
+							tally.stake = tally.stake.saturating_add(amount);
+							tally.weighted_since = tally.weighted_since.saturating_add(
+								amount.saturating_mul(Pallet::<T>::do_block_as_balance(staked_from))
+							);
+						});
+						writes = writes.saturating_add(1);
+					}
+
+					if votes.is_empty() {
+						continue
+					}
+					total_voters = total_voters.saturating_add(1);
+					ListVotes::<T>::insert(list_id, &voter, ListVoter {
+						votes: votes,
+						rewarded_until: rewarded_until,
+					});
+					writes = writes.saturating_add(1);
+				}
+
+				Some(RankingList {
+					name: old.name,
+					description: old.description,
+					status: old.status,
+					list_duration: old.list_duration,
+					list_deadline: old.list_deadline,
+					movies_in_list: old.movies_in_list,
+					total_voters: total_voters,
+					total_lockup: total_lockup,
+					categories_and_tags: old.categories_and_tags,
+				})
+			});
+			if previous_key.is_none() {
+				break
+			}
+			translated = translated.saturating_add(1);
+		}
+		(previous_key, translated, T::DbWeight::get().reads_writes(reads, writes))
+	}
+}
@@ -1,13 +1,13 @@
 use crate as kine_ranking_list;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU32, ConstU64, ConstU128},
+	traits::{ConstU16, ConstU32, ConstU64, ConstU128, Randomness},
 	PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	BuildStorage, Perbill, Percent,
 	testing::Header,
 };
 
@@ -78,7 +78,8 @@ impl kine_tags::Config for Test {
 
 // Stat Tracker
 parameter_types! {
-	pub const DefaultReputation: u32 = 15;
+	pub const ReputationDecay: Percent = Percent::from_percent(10);
+	pub const ReputationDecayPeriod: u64 = 100;
 	pub const WalletNameStringLimit: u32 = 50;
 	pub const PalletStatTrackerId : PalletId = PalletId(*b"kine/trk");
 }
@@ -86,9 +87,10 @@ parameter_types! {
 impl kine_stat_tracker::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type DefaultReputation = DefaultReputation;
 	type NameStringLimit = WalletNameStringLimit;
 	type PalletId = PalletStatTrackerId;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ReputationDecayPeriod;
 }
 
 
@@ -105,6 +107,7 @@ impl kine_movie::Config for Test {
     type StringLimit = MovieStringLimit;
     type LinkStringLimit = LinkStringLimit;
     type MovieCollateral = MovieCollateral;
+    type OnMovieTagged = RankingListModule;
 }
 
 
@@ -139,19 +142,69 @@ parameter_types!{
 	pub const MaxMoviesInList: u32 = 100000;
 	pub const MinimumListDuration: u32 = 3600; // six hours in blocks
 	pub const MaxVotersPerList: u32 = 10000000;
+	pub const MaxVotesPerVoter: u32 = 1000;
+	pub const VoterPageSize: u32 = 2;
+	pub const MaxCuratorsPerList: u32 = 100;
+	pub const EloKFactor: u32 = 32;
+	pub const MaxJudgementsPerWindow: u32 = 20;
+	pub const JudgementWindow: u32 = 600;
+	pub const MaxListsPerCurator: u32 = 100;
+	pub const MaxCuratorCommission: Perbill = Perbill::from_percent(20);
 	pub const MaxListsPerBlock: u32 = 50;
+	pub const RewardedMoviesPerList: u32 = 10;
+	pub const RankingSnapshotRetention: u32 = 52;
+	pub const RankingSnapshotDepth: u32 = 3;
+	pub const MaxAutoInsertionsPerBlock: u32 = 100;
+	pub const MaxPendingInsertions: u32 = 10000;
+	pub const ListCreationDeposit: Balance = 1000;
+	pub const BlocksPerYear: u32 = 5256000; // 6 second blocks
+	pub const DefaultRankingRewardApy: Perbill = Perbill::from_percent(18);
+	pub const DefaultRankingRewardPeriod: u64 = 100800; // one week in blocks
+	pub const DefaultRankingRewardBudget: Balance = 1_000_000_000;
 }
 
 impl kine_ranking_list::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+	type LockCurrency = Balances;
 	type MaxListsPerBlock = MaxListsPerBlock;
 	type MaxVotersPerList = MaxVotersPerList;
+	type MaxVotesPerVoter = MaxVotesPerVoter;
+	type VoterPageSize = VoterPageSize;
+	type MaxCuratorsPerList = MaxCuratorsPerList;
+	type EloKFactor = EloKFactor;
+	type MaxJudgementsPerWindow = MaxJudgementsPerWindow;
+	type JudgementWindow = JudgementWindow;
+	type Randomness = TestRandomness;
+	type MaxListsPerCurator = MaxListsPerCurator;
+	type MaxCuratorCommission = MaxCuratorCommission;
 	type MaxMoviesInList = MaxMoviesInList;
 	type MinimumListDuration = MinimumListDuration;
     type RankingStringLimit = RankingStringLimit;
+	type MaxAutoInsertionsPerBlock = MaxAutoInsertionsPerBlock;
+	type MaxPendingInsertions = MaxPendingInsertions;
+	type ListCreationDeposit = ListCreationDeposit;
+	type ListAdminOrigin = frame_system::EnsureRoot<u64>;
+	type SnapshotRetention = RankingSnapshotRetention;
+	type SnapshotDepth = RankingSnapshotDepth;
+	type RewardedMoviesPerList = RewardedMoviesPerList;
+	type Reputation = StatTrackerModule;
+	type BlocksPerYear = BlocksPerYear;
+	type DefaultRewardApy = DefaultRankingRewardApy;
+	type DefaultRewardPeriod = DefaultRankingRewardPeriod;
+	type DefaultRewardBudget = DefaultRankingRewardBudget;
+	type RewardsOrigin = frame_system::EnsureRoot<u64>;
     type PalletId = PalletRankingListId;
 }
 
+// Hashes the subject, which is enough to spread the draws in tests.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash(subject), System::block_number())
+	}
+}
+
 
 
 
@@ -1,5 +1,52 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, Conviction, Event, CuratorPositions, DelegationPools, DelegationScope, DelegationUnlocks, Delegations, Error, JudgementAllowances, JudgementPairs, LegacyVaultStakes, ListDeadlines, ListGovernance, ListMode, ListRankingModes, ListVotes, ListsByEligibleTag, MovieTallies, AutoInsertionsInBlock, NextSnapshotIndex, PairwiseRatings, PendingInsertionsRange, PendingListInsertions, PendingPayouts, PendingRefunds, PostponedDeadlines, RankingList, RankingListId, RankingListStatus, RankingLists, RankingMode, RankingRewardParameters, RankingSnapshots, RewardParameters, TotalLegacyVaultStakes, VoterLocks};
+use crate::migrations::{self, v0::{OldRankingList, OldRankingListOf, OldRankingVote}, v1::MigrateToV1, MIGRATION_PAGE_SIZE};
+use frame_support::{assert_noop, assert_ok, storage::bounded_btree_map::BoundedBTreeMap, traits::{Currency, GetStorageVersion, Hooks, OnRuntimeUpgrade}, BoundedVec};
+use sp_runtime::{traits::AccountIdConversion, Perbill};
+use kine_movie::MovieTagsHandler;
+use kine_stat_tracker::{ReputationDomain, ReputationHandler};
+use kine_tags::{CategoryId, ContentModeration, TagId};
+
+const YEAR: u64 = 5_256_000;
+
+// Inserts an ongoing list holding the given movies, skipping the movie pallet.
+fn setup_list(list_id: RankingListId, movies: &[&str], list_deadline: u64) {
+	RankingLists::<Test>::insert(list_id, RankingList {
+		name: BoundedVec::default(),
+		description: BoundedVec::default(),
+		status: RankingListStatus::Ongoing,
+		list_duration: list_deadline,
+		list_deadline: list_deadline,
+		movies_in_list: BoundedVec::truncate_from(
+			movies.iter().map(|movie| BoundedVec::truncate_from(movie.as_bytes().to_vec())).collect()
+		),
+		total_voters: 0,
+		total_lockup: 0,
+		categories_and_tags: BoundedVec::default(),
+	});
+	assert_ok!(RankingListModule::create_list_deadline(list_id, list_deadline));
+}
+
+fn vote(who: u64, list_id: RankingListId, movie: &str, amount: u128) {
+	Balances::make_free_balance_be(&who, Balances::free_balance(who) + amount + ExistentialDeposit::get());
+	assert_ok!(RankingListModule::vote_for(RuntimeOrigin::signed(who), list_id, movie.into(), amount, Conviction::Locked1x));
+}
+
+fn fund_pot(amount: u128) {
+	let pot: u64 = PalletRankingListId::get().into_account_truncating();
+	Balances::make_free_balance_be(&pot, amount);
+}
+
+fn claimable(who: u64) -> u128 {
+	StatTrackerModule::get_wallet_tokens(who).map_or(0, |tokens| tokens.claimable_tokens_ranking)
+}
+
+// `delegator` hands `amount` to `curator`, who places all of it on the movie.
+fn curate(curator: u64, delegator: u64, list_id: RankingListId, movie: &str, amount: u128) {
+	Balances::make_free_balance_be(&delegator, amount + ExistentialDeposit::get());
+	assert_ok!(RankingListModule::delegate(RuntimeOrigin::signed(delegator), curator, DelegationScope::AllLists, amount, Conviction::Locked1x));
+	let allocations = BoundedVec::truncate_from(vec![(BoundedVec::truncate_from(movie.as_bytes().to_vec()), Perbill::one())]);
+	assert_ok!(RankingListModule::set_curator_allocation(RuntimeOrigin::signed(curator), list_id, allocations));
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -18,6 +65,44 @@ fn correct_error_for_none_value() {
 
 // create_ranking_list
 
+fn create_list(owner: u64, mode: ListMode, curator: Option<u64>, ranking_mode: RankingMode) {
+	Balances::make_free_balance_be(&owner, 10_000);
+	assert_ok!(RankingListModule::create_ranking_list(
+		RuntimeOrigin::signed(owner), "list".into(), "description".into(),
+		MinimumListDuration::get().into(), BoundedVec::default(), mode, curator, ranking_mode,
+	));
+}
+
+#[test]
+fn lists_keep_their_mode_until_their_last_round() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&1, 10_000);
+		assert_noop!(
+			RankingListModule::create_ranking_list(
+				RuntimeOrigin::signed(1), "list".into(), "description".into(),
+				MinimumListDuration::get().into(), BoundedVec::default(), ListMode::FixedRounds(0), None, RankingMode::Stake,
+			),
+			Error::<Test>::InvalidListMode
+		);
+
+		create_list(1, ListMode::FixedRounds(1), Some(2), RankingMode::Pairwise);
+		let info = ListGovernance::<Test>::get(0).unwrap();
+		assert_eq!((info.owner, info.curator, info.mode, info.deposit), (1, Some(2), ListMode::FixedRounds(1), ListCreationDeposit::get()));
+		assert_eq!(ListRankingModes::<Test>::get(0), RankingMode::Pairwise);
+		assert_eq!(Balances::reserved_balance(1), ListCreationDeposit::get());
+
+		// a single round closes the list and returns the deposit
+		let deadline = 1 + MinimumListDuration::get() as u64;
+		System::set_block_number(deadline);
+		assert_ok!(RankingListModule::do_resolve_lists_deadline(deadline));
+		assert_eq!(RankingLists::<Test>::get(0).unwrap().status, RankingListStatus::Finished);
+		assert_eq!(ListGovernance::<Test>::get(0).unwrap().rounds_completed, 1);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::RankingListClosed(0).into());
+	});
+}
+
 
 // add_internal_movie_to_ranking_list
 
@@ -25,13 +110,276 @@ fn correct_error_for_none_value() {
 // add_external_movie_to_ranking_list
 
 
-// vote_for
+// vote_for / unvote_from
+
+#[test]
+fn votes_stay_locked_in_the_voters_account_until_their_unlock_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &["a"], YEAR + 1);
+		Balances::make_free_balance_be(&1, 1_000);
+
+		assert_noop!(
+			RankingListModule::vote_for(RuntimeOrigin::signed(1), 0, "a".into(), 1_001, Conviction::Locked2x),
+			Error::<Test>::NotEnoughBalance
+		);
+		assert_ok!(RankingListModule::vote_for(RuntimeOrigin::signed(1), 0, "a".into(), 400, Conviction::Locked2x));
+
+		// the stake never leaves the account, and counts twice
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(VoterLocks::<Test>::get(1), 400);
+		assert_eq!(MovieTallies::<Test>::get(0, BoundedVec::<u8, LinkStringLimit>::truncate_from(b"a".to_vec())).power, 800);
+		let movie_vote = ListVotes::<Test>::get(0, 1).unwrap().votes.into_iter().next().unwrap().1;
+		assert_eq!(movie_vote.unlock_block, 1 + 2 * MinimumListDuration::get() as u64);
+
+		System::set_block_number(movie_vote.unlock_block - 1);
+		assert_noop!(
+			RankingListModule::unvote_from(RuntimeOrigin::signed(1), 0, "a".into(), 400),
+			Error::<Test>::VoteValueStillLockedWithConviction
+		);
+
+		System::set_block_number(movie_vote.unlock_block);
+		assert_ok!(RankingListModule::unvote_from(RuntimeOrigin::signed(1), 0, "a".into(), 400));
+		assert_eq!(VoterLocks::<Test>::get(1), 0);
+		assert!(Balances::locks(1).is_empty());
+	});
+}
+
+
+// on_movie_tagged
+
+// Tags `movie` so that it matches the rule of each of the lists.
+fn tag_movie_for_lists(movie: &str, lists: &[RankingListId]) {
+	let category_tag: (CategoryId<Test>, TagId<Test>) =
+		(BoundedVec::truncate_from(b"genre".to_vec()), BoundedVec::truncate_from(b"drama".to_vec()));
+	for list_id in lists {
+		ListsByEligibleTag::<Test>::insert(&category_tag, list_id, ());
+	}
+	<RankingListModule as MovieTagsHandler<_, _>>::on_movie_tagged(
+		&BoundedVec::truncate_from(movie.as_bytes().to_vec()),
+		&BoundedVec::truncate_from(vec![category_tag]),
+	);
+}
+
+fn list_has_movie(list_id: RankingListId, movie: &str) -> bool {
+	RankingLists::<Test>::get(list_id).unwrap().movies_in_list.contains(&BoundedVec::truncate_from(movie.as_bytes().to_vec()))
+}
+
+#[test]
+fn insertions_over_the_block_cap_are_queued_for_the_next_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &[], YEAR + 1);
+		setup_list(1, &[], YEAR + 1);
+
+		// one insertion left in this block
+		AutoInsertionsInBlock::<Test>::put((1, MaxAutoInsertionsPerBlock::get() - 1));
+		tag_movie_for_lists("a", &[0, 1]);
+		assert_eq!([0, 1].iter().filter(|list_id| list_has_movie(**list_id, "a")).count(), 1);
+		assert_eq!(PendingInsertionsRange::<Test>::get(), (0, 1));
+		assert!(PendingListInsertions::<Test>::get(0).is_some());
+
+		System::set_block_number(2);
+		RankingListModule::on_initialize(2);
+		assert!(list_has_movie(0, "a") && list_has_movie(1, "a"));
+		assert_eq!(PendingInsertionsRange::<Test>::get(), (1, 1));
+		assert!(PendingListInsertions::<Test>::get(0).is_none());
+		assert_eq!(AutoInsertionsInBlock::<Test>::get(), (2, 1));
+	});
+}
+
+#[test]
+fn insertions_are_dropped_once_the_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &[], YEAR + 1);
+
+		AutoInsertionsInBlock::<Test>::put((1, MaxAutoInsertionsPerBlock::get()));
+		PendingInsertionsRange::<Test>::put((5, 5 + MaxPendingInsertions::get()));
+		tag_movie_for_lists("a", &[0]);
+
+		assert!(!list_has_movie(0, "a"));
+		assert_eq!(PendingInsertionsRange::<Test>::get(), (5, 5 + MaxPendingInsertions::get()));
+		System::assert_last_event(
+			Event::<Test>::AutoInsertionDropped(0, BoundedVec::truncate_from(b"a".to_vec())).into()
+		);
+	});
+}
 
 
 // claim_ranking_rewards
 
 
+// fund_reward_pot
 
+#[test]
+fn anyone_can_fund_the_reward_pot() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let pot: u64 = PalletRankingListId::get().into_account_truncating();
+		Balances::make_free_balance_be(&1, 10_000);
+
+		assert_noop!(RankingListModule::fund_reward_pot(RuntimeOrigin::signed(1), 0), Error::<Test>::VoteAmountCannotBeZero);
+		assert_ok!(RankingListModule::fund_reward_pot(RuntimeOrigin::signed(1), 2_000));
+		assert_eq!(Balances::free_balance(pot), 2_000);
+		assert_eq!(Balances::free_balance(1), 8_000);
+		System::assert_last_event(Event::RewardPotFunded(1, 2_000).into());
+
+		// the funder's account is kept alive
+		assert!(RankingListModule::fund_reward_pot(RuntimeOrigin::signed(1), 8_000).is_err());
+	});
+}
+
+
+// set_reward_parameters
+
+#[test]
+fn reward_parameters_are_set_by_the_rewards_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(RankingRewardParameters::<Test>::get(), RewardParameters {
+			apy: DefaultRankingRewardApy::get(),
+			period: DefaultRankingRewardPeriod::get(),
+			budget: DefaultRankingRewardBudget::get(),
+		});
+
+		assert_noop!(
+			RankingListModule::set_reward_parameters(RuntimeOrigin::signed(1), Perbill::from_percent(5), 100, 1_000),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RankingListModule::set_reward_parameters(RuntimeOrigin::root(), Perbill::from_percent(5), 0, 1_000),
+			Error::<Test>::RewardPeriodCannotBeZero
+		);
+
+		assert_ok!(RankingListModule::set_reward_parameters(RuntimeOrigin::root(), Perbill::from_percent(5), 100, 1_000));
+		assert_eq!(RankingRewardParameters::<Test>::get(), RewardParameters {
+			apy: Perbill::from_percent(5),
+			period: 100,
+			budget: 1_000,
+		});
+		System::assert_last_event(Event::RewardParametersUpdated(Perbill::from_percent(5), 100, 1_000).into());
+	});
+}
+
+
+// set_curator_commission
+
+#[test]
+fn curator_commissions_are_capped_and_change_from_the_next_round() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			RankingListModule::set_curator_commission(RuntimeOrigin::signed(5), Perbill::from_percent(21)),
+			Error::<Test>::CommissionTooHigh
+		);
+		assert_ok!(RankingListModule::set_curator_commission(RuntimeOrigin::signed(5), Perbill::from_percent(10)));
+		setup_list(0, &["a"], YEAR + 1);
+		curate(5, 6, 0, "a", 1_000);
+		fund_pot(1_000_000);
+
+		// raised during the round, the position keeps the commission it started with
+		System::set_block_number(2);
+		assert_ok!(RankingListModule::set_curator_commission(RuntimeOrigin::signed(5), Perbill::from_percent(20)));
+		assert_eq!(CuratorPositions::<Test>::get(0, 5).unwrap().commission, Perbill::from_percent(10));
+
+		// a year of the delegated stake earns 180, of which the curator keeps 10%
+		System::set_block_number(YEAR + 1);
+		assert_ok!(RankingListModule::do_resolve_lists_deadline(YEAR + 1));
+		assert_eq!(claimable(5), 18);
+		assert_eq!(CuratorPositions::<Test>::get(0, 5).unwrap().commission, Perbill::from_percent(20));
+	});
+}
+
+
+// set_list_curator
+
+#[test]
+fn only_the_owner_sets_the_curator_who_can_then_manage_the_list() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_list(1, ListMode::Perpetual, None, RankingMode::Stake);
+
+		assert_noop!(RankingListModule::set_list_curator(RuntimeOrigin::signed(2), 0, Some(2)), Error::<Test>::NotListOwner);
+		assert_noop!(RankingListModule::set_list_curator(RuntimeOrigin::signed(1), 5, Some(2)), Error::<Test>::RankingListNotFound);
+		assert_noop!(RankingListModule::set_list_eligibility_rule(RuntimeOrigin::signed(3), 0, None), Error::<Test>::NotListOwner);
+
+		assert_ok!(RankingListModule::set_list_curator(RuntimeOrigin::signed(1), 0, Some(3)));
+		assert_eq!(ListGovernance::<Test>::get(0).unwrap().curator, Some(3));
+		System::assert_last_event(Event::ListCuratorUpdated(0, Some(3)).into());
+
+		// the curator manages the list's eligibility, but can't close it
+		assert_ok!(RankingListModule::set_list_eligibility_rule(RuntimeOrigin::signed(3), 0, None));
+		assert_noop!(RankingListModule::close_ranking_list(RuntimeOrigin::signed(3), 0), Error::<Test>::NotListOwner);
+
+		assert_ok!(RankingListModule::set_list_curator(RuntimeOrigin::signed(1), 0, None));
+		assert_noop!(RankingListModule::set_list_eligibility_rule(RuntimeOrigin::signed(3), 0, None), Error::<Test>::NotListOwner);
+	});
+}
+
+
+// archive_ranking_list
+
+#[test]
+fn archived_lists_forfeit_their_deposit_to_the_reward_pot() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let pot: u64 = PalletRankingListId::get().into_account_truncating();
+		create_list(1, ListMode::Perpetual, None, RankingMode::Stake);
+
+		assert_noop!(RankingListModule::archive_ranking_list(RuntimeOrigin::signed(1), 0), sp_runtime::DispatchError::BadOrigin);
+		assert_noop!(RankingListModule::archive_ranking_list(RuntimeOrigin::root(), 5), Error::<Test>::RankingListNotFound);
+
+		assert_ok!(RankingListModule::archive_ranking_list(RuntimeOrigin::root(), 0));
+		assert_eq!(RankingLists::<Test>::get(0).unwrap().status, RankingListStatus::Archived);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10_000 - ListCreationDeposit::get());
+		assert_eq!(Balances::free_balance(pot), ListCreationDeposit::get());
+		assert_eq!(ListGovernance::<Test>::get(0).unwrap().deposit, 0);
+		// its voters are refunded over the next blocks
+		assert!(PendingRefunds::<Test>::contains_key(0));
+		assert!(!ListDeadlines::<Test>::contains_key(1 + MinimumListDuration::get() as u64));
+
+		assert_noop!(RankingListModule::archive_ranking_list(RuntimeOrigin::root(), 0), Error::<Test>::RankingListNotOngoing);
+	});
+}
+
+
+// delegate / undelegate / unlock_delegation
+
+#[test]
+fn undelegated_tokens_stay_locked_for_the_conviction_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(RankingListModule::set_curator_commission(RuntimeOrigin::signed(5), Perbill::from_percent(10)));
+		Balances::make_free_balance_be(&2, 1_000 + ExistentialDeposit::get());
+		assert_ok!(RankingListModule::delegate(RuntimeOrigin::signed(2), 5, DelegationScope::AllLists, 1_000, Conviction::Locked1x));
+
+		assert_noop!(RankingListModule::undelegate(RuntimeOrigin::signed(3), DelegationScope::AllLists), Error::<Test>::NotDelegating);
+		assert_ok!(RankingListModule::undelegate(RuntimeOrigin::signed(2), DelegationScope::AllLists));
+		System::assert_last_event(Event::Undelegated(2, DelegationScope::AllLists, 1_000).into());
+		assert!(Delegations::<Test>::get(2, DelegationScope::AllLists).is_none());
+		assert_eq!(DelegationPools::<Test>::get(5, DelegationScope::AllLists).stake, 0);
+		assert_eq!(VoterLocks::<Test>::get(2), 1_000);
+
+		let (amount, unlock_block) = DelegationUnlocks::<Test>::get(2, DelegationScope::AllLists).unwrap();
+		assert_eq!(amount, 1_000);
+		assert!(unlock_block > 1);
+		assert_noop!(
+			RankingListModule::unlock_delegation(RuntimeOrigin::signed(2), DelegationScope::AllLists),
+			Error::<Test>::VoteValueStillLockedWithConviction
+		);
+
+		System::set_block_number(unlock_block);
+		assert_ok!(RankingListModule::unlock_delegation(RuntimeOrigin::signed(2), DelegationScope::AllLists));
+		assert_eq!(VoterLocks::<Test>::get(2), 0);
+		assert!(DelegationUnlocks::<Test>::get(2, DelegationScope::AllLists).is_none());
+		assert_noop!(
+			RankingListModule::unlock_delegation(RuntimeOrigin::signed(2), DelegationScope::AllLists),
+			Error::<Test>::NoDelegationUnlock
+		);
+	});
+}
 
 
 // create_list_deadline
@@ -39,8 +387,204 @@ fn correct_error_for_none_value() {
 
 // do_resolve_lists_deadline
 
+#[test]
+fn lists_resolved_together_share_the_budget_pro_rata() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &["a"], YEAR + 1);
+		setup_list(1, &["b"], YEAR + 1);
+		vote(1, 0, "a", 1_000);
+		vote(2, 1, "b", 3_000);
+		fund_pot(1_000_000);
+
+		// a year of stake earns 180 in the first list and 540 in the second, the budget only covers half
+		RankingRewardParameters::<Test>::put(RewardParameters {
+			apy: Perbill::from_percent(18),
+			period: YEAR * 2,
+			budget: 360,
+		});
+		System::set_block_number(YEAR + 1);
+		assert_ok!(RankingListModule::do_resolve_lists_deadline(YEAR + 1));
+
+		let first = PendingPayouts::<Test>::get(0, 0).unwrap();
+		let second = PendingPayouts::<Test>::get(1, 0).unwrap();
+		assert_eq!((first.scale, second.scale), (Perbill::from_percent(50), Perbill::from_percent(50)));
+		assert_eq!((first.planned, second.planned), (90, 270));
+		assert_eq!(RankingListModule::reward_period_spending(), (0, 360));
+		assert!(!ListDeadlines::<Test>::contains_key(YEAR + 1));
+	});
+}
+
+
+// resolve_ranking_list / do_process_voter_pages
+
+#[test]
+fn rewards_are_weighted_by_how_long_the_stake_was_held() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &["a", "b"], YEAR + 1);
+		vote(1, 0, "a", 1_000);
+		fund_pot(1_000_000);
+		RankingRewardParameters::<Test>::put(RewardParameters {
+			apy: Perbill::from_percent(18),
+			period: YEAR * 2,
+			budget: 1_000_000,
+		});
+
+		// the second voter only holds the same stake for the last half of the round
+		System::set_block_number(YEAR / 2 + 1);
+		vote(2, 0, "a", 1_000);
+
+		System::set_block_number(YEAR + 1);
+		assert_ok!(RankingListModule::do_resolve_lists_deadline(YEAR + 1));
+		let snapshot = RankingSnapshots::<Test>::get(0, 0).unwrap();
+		assert_eq!(snapshot.ranked_movies[0].0.to_vec(), b"a".to_vec());
+		assert_eq!(snapshot.total_voters, 2);
+
+		RankingListModule::do_process_voter_pages();
+		assert_eq!((claimable(1), claimable(2)), (180, 90));
+	});
+}
+
+#[test]
+fn voters_of_closed_lists_are_refunded_unless_their_refund_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &["a"], YEAR + 1);
+		vote(1, 0, "a", 1_000);
+		vote(2, 0, "a", 1_000);
+		// the second voter's lock went missing, unlocking their stake fails
+		VoterLocks::<Test>::remove(2);
+
+		assert_ok!(RankingListModule::close_ranking_list(RuntimeOrigin::root(), 0));
+		RankingListModule::do_process_voter_pages();
+		RankingListModule::do_process_voter_pages();
+
+		assert!(ListVotes::<Test>::get(0, 1).is_none());
+		assert!(Balances::locks(1).is_empty());
+		System::assert_has_event(Event::<Test>::VoterRefundFailed(0, 2).into());
+
+		// the failed refund was rolled back, the voter keeps their votes
+		assert!(ListVotes::<Test>::get(0, 2).is_some());
+		assert_eq!(RankingLists::<Test>::get(0).unwrap().total_voters, 1);
+		assert!(!PendingRefunds::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn voters_are_paid_a_page_per_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &["a"], YEAR + 1);
+		for voter in 1..=3 {
+			vote(voter, 0, "a", 1_000);
+		}
+		fund_pot(1_000_000);
+		RankingRewardParameters::<Test>::put(RewardParameters {
+			apy: Perbill::from_percent(18),
+			period: YEAR * 2,
+			budget: 1_000_000,
+		});
+
+		System::set_block_number(YEAR + 1);
+		assert_ok!(RankingListModule::do_resolve_lists_deadline(YEAR + 1));
+
+		// the page holds two voters, the round stays open for the third
+		RankingListModule::do_process_voter_pages();
+		assert_eq!((1..=3).filter(|voter| claimable(*voter) == 180).count(), 2);
+		let payout = PendingPayouts::<Test>::get(0, 0).unwrap();
+		assert_eq!(payout.paid, 360);
+		assert!(payout.last_key.is_some());
 
-// resolve_ranking_list
+		RankingListModule::do_process_voter_pages();
+		assert_eq!((1..=3).map(claimable).collect::<Vec<_>>(), vec![180, 180, 180]);
+		assert!(PendingPayouts::<Test>::get(0, 0).is_none());
+	});
+}
+
+#[test]
+fn failed_payouts_are_retried_and_top_movie_voters_gain_reputation_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &["a"], YEAR + 1);
+		for voter in 1..=3 {
+			vote(voter, 0, "a", 1_000);
+		}
+		fund_pot(1_000_000);
+		RankingRewardParameters::<Test>::put(RewardParameters {
+			apy: Perbill::from_percent(18),
+			period: YEAR * 2,
+			budget: 1_000_000,
+		});
+		// crediting the third voter's reward overflows their winnings
+		let set_winnings = |amount: u128| kine_stat_tracker::WalletTokens::<Test>::mutate(3, |tokens| {
+			tokens.as_mut().unwrap().total_tokens_won_ranking = amount;
+		});
+		set_winnings(u128::MAX);
+
+		System::set_block_number(YEAR + 1);
+		assert_ok!(RankingListModule::do_resolve_lists_deadline(YEAR + 1));
+		// joining after the round doesn't earn the round's reputation
+		vote(4, 0, "a", 1_000);
+
+		// two full pages of voters, and the end of the pass
+		for _ in 0..3 {
+			RankingListModule::do_process_voter_pages();
+		}
+		System::assert_has_event(Event::<Test>::VoterPayoutFailed(0, 3).into());
+		assert_eq!((1..=3).map(claimable).collect::<Vec<_>>(), vec![180, 180, 0]);
+		let payout = PendingPayouts::<Test>::get(0, 0).unwrap();
+		assert_eq!((payout.pass, payout.failed, payout.last_key), (1, 0, None));
+
+		let reputation = |voter: u64| StatTrackerModule::reputation(&voter, ReputationDomain::Curation);
+		assert_eq!((1..=4).map(reputation).collect::<Vec<_>>(), vec![2, 2, 2, 0]);
+
+		set_winnings(0);
+		for _ in 0..3 {
+			RankingListModule::do_process_voter_pages();
+		}
+		assert_eq!((1..=3).map(claimable).collect::<Vec<_>>(), vec![180, 180, 180]);
+		assert!(PendingPayouts::<Test>::get(0, 0).is_none());
+		assert_eq!((1..=4).map(reputation).collect::<Vec<_>>(), vec![2, 2, 2, 0]);
+	});
+}
+
+
+// movie_rank_history
+
+#[test]
+fn rank_history_follows_the_top_movies_of_the_retained_rounds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &["a", "b", "c", "d"], 100);
+		vote(1, 0, "a", 400);
+		vote(2, 0, "b", 300);
+		vote(3, 0, "c", 200);
+		vote(4, 0, "d", 100);
+		let power = |movie: &str| MovieTallies::<Test>::get(0, BoundedVec::<u8, LinkStringLimit>::truncate_from(movie.as_bytes().to_vec())).power;
+
+		System::set_block_number(100);
+		let first_round_power = power("a");
+		assert_ok!(RankingListModule::resolve_ranking_list(0, Perbill::zero()));
+		// snapshots only hold the top SnapshotDepth movies
+		assert_eq!(RankingSnapshots::<Test>::get(0, 0).unwrap().ranked_movies.len(), 3);
+		assert_eq!(RankingListModule::movie_rank_history(0, b"a".to_vec()), vec![(0, 100, 1, first_round_power)]);
+		assert!(RankingListModule::movie_rank_history(0, b"d".to_vec()).is_empty());
+
+		System::set_block_number(200);
+		vote(4, 0, "d", 1_000);
+		assert_ok!(RankingListModule::resolve_ranking_list(0, Perbill::zero()));
+		assert_eq!(RankingListModule::movie_rank_history(0, b"d".to_vec()), vec![(1, 200, 1, power("d"))]);
+		assert_eq!(
+			RankingListModule::movie_rank_history(0, b"a".to_vec()),
+			vec![(0, 100, 1, first_round_power), (1, 200, 2, power("a"))]
+		);
+
+		// only the last SnapshotRetention rounds are reported
+		NextSnapshotIndex::<Test>::insert(0, 1 + RankingSnapshotRetention::get());
+		assert_eq!(RankingListModule::movie_rank_history(0, b"a".to_vec()), vec![(1, 200, 2, power("a"))]);
+	});
+}
 
 
 // do_calculate_voting_power
@@ -48,3 +592,221 @@ fn correct_error_for_none_value() {
 
 // do_resolve_lists_deadline
 
+
+// draw_judgement_pair / submit_judgement
+
+// A pairwise list of the given movies, judged by a registered account.
+fn setup_pairwise_list(list_id: RankingListId, movies: &[&str], judge: u64) {
+	setup_list(list_id, movies, 100);
+	ListRankingModes::<Test>::insert(list_id, RankingMode::Pairwise);
+	if !kine_stat_tracker::WalletStats::<Test>::contains_key(judge) {
+		assert_ok!(StatTrackerModule::register_new_wallet(RuntimeOrigin::signed(judge), false, false, "judge".into()));
+	}
+}
+
+#[test]
+fn judges_rate_the_pair_drawn_for_them() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_pairwise_list(0, &["a", "b", "c"], 1);
+
+		assert_noop!(RankingListModule::submit_judgement(RuntimeOrigin::signed(1), 0, true), Error::<Test>::NoJudgementPair);
+		assert_noop!(RankingListModule::draw_judgement_pair(RuntimeOrigin::signed(2), 0), Error::<Test>::WalletStatsRegistryRequired);
+
+		assert_ok!(RankingListModule::draw_judgement_pair(RuntimeOrigin::signed(1), 0));
+		let (first, second) = JudgementPairs::<Test>::get(0, 1).unwrap();
+		assert_ne!(first, second);
+		assert!(RankingLists::<Test>::get(0).unwrap().movies_in_list.contains(&first));
+		assert!(RankingLists::<Test>::get(0).unwrap().movies_in_list.contains(&second));
+
+		// evenly rated movies move by half the K factor
+		assert_ok!(RankingListModule::submit_judgement(RuntimeOrigin::signed(1), 0, false));
+		assert_eq!(PairwiseRatings::<Test>::get(0, &second), 1_516);
+		assert_eq!(PairwiseRatings::<Test>::get(0, &first), 1_484);
+
+		// each pair is judged once
+		assert!(!JudgementPairs::<Test>::contains_key(0, 1));
+		assert_noop!(RankingListModule::submit_judgement(RuntimeOrigin::signed(1), 0, true), Error::<Test>::NoJudgementPair);
+	});
+}
+
+#[test]
+fn pairs_are_only_drawn_from_unsuspended_movies_of_pairwise_lists() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &["a", "b"], 100);
+		setup_pairwise_list(1, &["a"], 1);
+		setup_pairwise_list(2, &["a", "b", "c"], 1);
+
+		assert_noop!(RankingListModule::draw_judgement_pair(RuntimeOrigin::signed(1), 0), Error::<Test>::WrongRankingMode);
+		assert_noop!(RankingListModule::draw_judgement_pair(RuntimeOrigin::signed(1), 1), Error::<Test>::NotEnoughMoviesToJudge);
+
+		kine_movie::SuspendedMovies::<Test>::insert(BoundedVec::<u8, LinkStringLimit>::truncate_from(b"b".to_vec()), ());
+		for _ in 0..5 {
+			assert_ok!(RankingListModule::draw_judgement_pair(RuntimeOrigin::signed(1), 2));
+			let (first, second) = JudgementPairs::<Test>::get(2, 1).unwrap();
+			assert!(first.to_vec() != b"b".to_vec() && second.to_vec() != b"b".to_vec());
+		}
+
+		// a movie suspended after the draw can't be judged anymore
+		kine_movie::SuspendedMovies::<Test>::insert(BoundedVec::<u8, LinkStringLimit>::truncate_from(b"c".to_vec()), ());
+		assert_noop!(RankingListModule::submit_judgement(RuntimeOrigin::signed(1), 2, true), Error::<Test>::MovieSuspended);
+	});
+}
+
+#[test]
+fn draws_are_limited_per_judgement_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_pairwise_list(0, &["a", "b"], 1);
+
+		for _ in 0..MaxJudgementsPerWindow::get() {
+			assert_ok!(RankingListModule::draw_judgement_pair(RuntimeOrigin::signed(1), 0));
+		}
+		assert_noop!(RankingListModule::draw_judgement_pair(RuntimeOrigin::signed(1), 0), Error::<Test>::TooManyJudgements);
+
+		// a new window starts once the previous one is over
+		System::set_block_number(1 + JudgementWindow::get() as u64);
+		assert_ok!(RankingListModule::draw_judgement_pair(RuntimeOrigin::signed(1), 0));
+		assert_eq!(JudgementAllowances::<Test>::get(1), (1 + JudgementWindow::get() as u64, 1));
+	});
+}
+
+
+// suspend_content / reinstate_content
+
+#[test]
+fn suspended_lists_stop_until_reinstated_with_a_new_round() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_list(0, &["a"], 100);
+
+		assert_ok!(<RankingListModule as ContentModeration<RankingListId>>::suspend_content(&0));
+		assert_eq!(RankingLists::<Test>::get(0).unwrap().status, RankingListStatus::Suspended);
+		assert!(!ListDeadlines::<Test>::contains_key(100));
+
+		Balances::make_free_balance_be(&1, 1_000);
+		assert_noop!(
+			RankingListModule::vote_for(RuntimeOrigin::signed(1), 0, "a".into(), 100, Conviction::Locked1x),
+			Error::<Test>::RankingListNotOngoing
+		);
+
+		// the list gets a full round from the block it's reinstated at
+		System::set_block_number(50);
+		assert_ok!(<RankingListModule as ContentModeration<RankingListId>>::reinstate_content(&0));
+		let list = RankingLists::<Test>::get(0).unwrap();
+		assert_eq!((list.status, list.list_deadline), (RankingListStatus::Ongoing, 150));
+		assert_eq!(ListDeadlines::<Test>::get(150).unwrap().list_deadlines.into_inner(), vec![0]);
+
+		assert_noop!(
+			<RankingListModule as ContentModeration<RankingListId>>::reinstate_content(&0),
+			Error::<Test>::RankingListNotSuspended
+		);
+	});
+}
+
+
+// migrations
+
+// Inserts a list in the layout before v1, with one vote per voter held in the vault.
+fn put_legacy_list(list_id: RankingListId, votes: &[(u64, &str, u128)]) {
+	let link = |movie: &str| BoundedVec::<u8, LinkStringLimit>::truncate_from(movie.as_bytes().to_vec());
+	let mut votes_by_user = BoundedBTreeMap::new();
+	for (voter, movie, amount) in votes {
+		let mut user_votes = BoundedBTreeMap::new();
+		assert_ok!(user_votes.try_insert(link(movie), OldRankingVote {
+			movie_id: link(movie),
+			locked_amount: *amount,
+			conviction: Conviction::Locked1x,
+			unlock_block: 0,
+		}));
+		assert_ok!(votes_by_user.try_insert(*voter, user_votes));
+	}
+	let list: OldRankingListOf<Test> = OldRankingList {
+		name: BoundedVec::default(),
+		description: BoundedVec::default(),
+		status: RankingListStatus::Ongoing,
+		list_duration: YEAR,
+		list_deadline: YEAR + 1,
+		movies_in_list: BoundedVec::truncate_from(votes.iter().map(|(_, movie, _)| link(movie)).collect()),
+		votes_by_user: votes_by_user,
+		total_lockup: votes.iter().map(|(_, _, amount)| amount).sum(),
+		categories_and_tags: BoundedVec::default(),
+	};
+	migrations::v0::RankingLists::<Test>::insert(list_id, list);
+}
+
+#[test]
+fn legacy_stakes_the_vault_cant_return_are_paid_from_it_on_unvote() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		put_legacy_list(0, &[(1, "a", 1_000), (2, "b", 800)]);
+		// only the first stake can be returned
+		fund_pot(1_000);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(RankingListModule::on_chain_storage_version(), 3);
+		assert!(!migrations::is_migrating::<Test>());
+
+		assert_eq!((Balances::free_balance(1), VoterLocks::<Test>::get(1)), (1_000, 1_000));
+		assert_eq!((Balances::free_balance(2), VoterLocks::<Test>::get(2)), (0, 0));
+		assert_eq!(LegacyVaultStakes::<Test>::get(2), 800);
+		assert_eq!(TotalLegacyVaultStakes::<Test>::get(), 800);
+		assert!(ListVotes::<Test>::contains_key(0, 2));
+		assert_eq!(RankingLists::<Test>::get(0).unwrap().total_voters, 2);
+
+		// once the vault holds it again, the stake is paid out of it instead of underflowing the lock
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(
+			2, kine_stat_tracker::FeatureType::RankingList, kine_stat_tracker::TokenType::Locked, 800, false,
+		));
+		fund_pot(800);
+		assert_ok!(RankingListModule::unvote_from(RuntimeOrigin::signed(2), 0, "b".into(), 800));
+		assert_eq!(Balances::free_balance(2), 800);
+		assert!(!LegacyVaultStakes::<Test>::contains_key(2));
+		assert_eq!(TotalLegacyVaultStakes::<Test>::get(), 0);
+		assert!(Balances::locks(2).is_empty());
+	});
+}
+
+#[test]
+fn lists_are_migrated_a_page_per_block_and_their_deadlines_postponed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let lists = MIGRATION_PAGE_SIZE + 1;
+		for list_id in 0..lists {
+			put_legacy_list(list_id, &[(1, "a", 1_000)]);
+		}
+		fund_pot(1_000_000);
+
+		// the first page is migrated in the upgrade, the rest in the following blocks
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert!(migrations::is_migrating::<Test>());
+		assert_eq!(RankingListModule::on_chain_storage_version(), 0);
+		assert_eq!(VoterLocks::<Test>::get(1), 1_000 * MIGRATION_PAGE_SIZE as u128);
+
+		// lists due meanwhile aren't resolved yet
+		assert_ok!(RankingListModule::create_list_deadline(0, 2));
+		RankingListModule::on_finalize(2);
+		assert!(PostponedDeadlines::<Test>::contains_key(2));
+		assert!(ListDeadlines::<Test>::contains_key(2));
+
+		let mut block = 3;
+		while migrations::is_migrating::<Test>() {
+			RankingListModule::on_initialize(block);
+			block += 1;
+		}
+		// the 11 lists are translated three times, 10 at a time
+		assert_eq!(block, 6);
+		assert_eq!(RankingListModule::on_chain_storage_version(), 3);
+		assert_eq!(VoterLocks::<Test>::get(1), 1_000 * lists as u128);
+		for list_id in 0..lists {
+			assert!(ListVotes::<Test>::contains_key(list_id, 1));
+			assert_eq!(RankingLists::<Test>::get(list_id).unwrap().total_voters, 1);
+		}
+
+		System::set_block_number(block);
+		RankingListModule::on_finalize(block);
+		assert!(!PostponedDeadlines::<Test>::contains_key(2));
+		assert!(!ListDeadlines::<Test>::contains_key(2));
+	});
+}
@@ -0,0 +1,14 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	// Reputation kept in the WalletReputation storage.
+	pub trait ReputationApi<AccountId, ReputationBreakdown> where
+		AccountId: Codec,
+		ReputationBreakdown: Codec,
+	{
+		// the wallet's score in each domain and their total, with the pending decay applied
+		fn reputation_breakdown(who: AccountId) -> ReputationBreakdown;
+	}
+}
@@ -6,11 +6,13 @@
 	// tokens alongside the representation of how much each pallet has allocated
 	// per wallet. It is also used as an abstraction to transfer funds to/from the
 	// treasury, with the amount being designated per feature.
+	// Reputation is tracked per domain (curation, moderation, creation and community),
+	// updated by the other pallets through the ReputationHandler trait and decayed
+	// lazily by ReputationDecay every ReputationDecayPeriod blocks.
 	
 	//TODO-0 add comments to the pallet
 	//TODO-1 check the use of references in the helper functions that do not need to use .clone()
 	//TODO-2 implement the treasury from this pallet and migrate all other treasuries here
-	//TODO-3 implement claim all tokens
 	//TODO-5 add a static lookup for values like blocks per year when calculating imbalances
 	//TODO-6 optimize self::account_id, store the value during genesis because calculating it is expensive
 
@@ -23,6 +25,8 @@
 	
 	#[cfg(test)]
 	mod tests;
+
+	pub mod migrations;
 	
 
 	
@@ -37,6 +41,7 @@
 					dispatch::DispatchResultWithPostInfo,
 					pallet_prelude::*,
 					PalletId,
+					PalletError,
 					traits::{
 						Currency,
 						ReservableCurrency,
@@ -51,7 +56,11 @@
 							CheckedDiv,
 							Saturating,
 							AccountIdConversion,
+							One,
+							Zero,
 						},
+						Percent,
+						PerThing,
 					}
 				};
 				use frame_system::pallet_prelude::*;
@@ -60,7 +69,10 @@
 	
 			//* Config *//
 	
+				const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 				#[pallet::pallet]
+				#[pallet::storage_version(STORAGE_VERSION)]
 				pub struct Pallet<T>(_);
 	
 				#[pallet::config]
@@ -68,11 +80,32 @@
 					type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 					type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
 	
-					type DefaultReputation: Get<u32>;
 					type NameStringLimit: Get<u32>;
+
+					// share of every reputation score that is lost per decay period
+					type ReputationDecay: Get<Percent>;
+					type ReputationDecayPeriod: Get<BlockNumberFor<Self>>;
 					
 					type PalletId: Get<PalletId>;
 				}
+
+				// Lets the other pallets update the reputation of the domains they track,
+				// without depending on how it is stored or decayed.
+				pub trait ReputationHandler<AccountId> {
+					// returns the updated score of the domain
+					fn update_reputation(who: &AccountId, domain: ReputationDomain, points: u32, is_slash: bool) -> u32;
+					fn reputation(who: &AccountId, domain: ReputationDomain) -> u32;
+					fn total_reputation(who: &AccountId) -> u32;
+					// upper bound of a single update_reputation call
+					fn update_weight() -> Weight;
+				}
+
+				impl<AccountId> ReputationHandler<AccountId> for () {
+					fn update_reputation(_who: &AccountId, _domain: ReputationDomain, _points: u32, _is_slash: bool) -> u32 { 0 }
+					fn reputation(_who: &AccountId, _domain: ReputationDomain) -> u32 { 0 }
+					fn total_reputation(_who: &AccountId) -> u32 { 0 }
+					fn update_weight() -> Weight { Weight::zero() }
+				}
 	
 	
 				
@@ -104,6 +137,16 @@
 					Locked,
 					Claimable,
 				}
+
+				// The areas a wallet's reputation is tracked in. Curation covers festival
+				// and ranking accuracy, creation covers movie wins and reviews.
+				#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+				pub enum ReputationDomain {
+					Curation,
+					Moderation,
+					Creation,
+					Community,
+				}
 	
 			//* Structs *//
 	
@@ -122,7 +165,6 @@
 				// To get the current locked balance, you must do "total_..." - "claimable_..." = "locked_...". 
 				#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo,)]
 				pub struct Tokens<Balance, TokenImbalance> {
-					pub reputation_moderation: u32,
 					pub locked_tokens_moderation: Balance,
 					pub claimable_tokens_moderation: Balance,
 					
@@ -138,6 +180,53 @@
 					pub locked_tokens_movie: Balance,
 					pub claimable_tokens_movie: Balance,
 				}
+
+
+				// A wallet's score in a single domain. The decay is only applied
+				// when the score is read or updated, "decayed_at" being the start
+				// of the first period that was not yet applied.
+				#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+				pub struct ReputationScore<BlockNumber> {
+					pub score: u32,
+					pub decayed_at: BlockNumber,
+				}
+
+
+				// The decayed scores of a wallet in every domain, as exposed by the runtime API.
+				#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+				pub struct ReputationBreakdown {
+					pub curation: u32,
+					pub moderation: u32,
+					pub creation: u32,
+					pub community: u32,
+					pub total: u32,
+				}
+
+
+				// The features that still hold locked or claimable tokens of a wallet, one bit
+				// each. Any of them being set blocks the wallet's unregistration. Packed in a
+				// single byte to fit the size limit of module errors.
+				#[derive(Clone, Encode, Copy, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, PalletError, Default)]
+				pub struct UnregistrationBlockers(pub u8);
+
+				impl UnregistrationBlockers {
+					pub const LOCKED_FESTIVAL: u8 = 1 << 0;
+					pub const LOCKED_RANKING: u8 = 1 << 1;
+					pub const LOCKED_MODERATION: u8 = 1 << 2;
+					pub const LOCKED_MOVIE: u8 = 1 << 3;
+					pub const CLAIMABLE_FESTIVAL: u8 = 1 << 4;
+					pub const CLAIMABLE_RANKING: u8 = 1 << 5;
+					pub const CLAIMABLE_MODERATION: u8 = 1 << 6;
+					pub const CLAIMABLE_MOVIE: u8 = 1 << 7;
+
+					pub fn contains(&self, blocker: u8) -> bool {
+						self.0 & blocker == blocker
+					}
+
+					pub fn is_empty(&self) -> bool {
+						self.0 == 0
+					}
+				}
 	
 	
 	
@@ -161,7 +250,7 @@
 				>;
 	
 	
-			// Keeps track of the amount of tokens (and reputation_moderation) a wallet has.
+			// Keeps track of the amount of tokens a wallet has.
 			// It is independent from the "WalletStats" storage, meaning an entry
 			// can exist by itself without being registed in "WalletStats".
 			#[pallet::storage]
@@ -175,6 +264,19 @@
 						(BalanceOf<T>, BalanceOf<T>),
 					>,
 				>;
+
+
+			// The reputation of a wallet in each domain. Wallets start at 0 in all of them.
+			#[pallet::storage]
+			#[pallet::getter(fn get_wallet_reputation)]
+			pub type WalletReputation<T: Config> = 
+				StorageDoubleMap<
+					_, 
+					Blake2_128Concat, T::AccountId,
+					Twox64Concat, ReputationDomain,
+					ReputationScore<BlockNumberFor<T>>,
+					ValueQuery,
+				>;
 		
 		
 		//** Events **//
@@ -192,6 +294,8 @@
 				AccountDataUpdatedName(String),
 	
 				TokensClaimed(T::AccountId),
+
+				ReputationUpdated(T::AccountId, ReputationDomain, u32),
 			}
 		
 	
@@ -215,14 +319,22 @@
 				TokenUnderflow,
 				ReputationUnderflow,
 				NotEnoughBalance,
+				// lists the features the wallet still has locked or claimable tokens in
+				WalletHasPendingTokens(UnregistrationBlockers),
 			}
 	
 	
 	
 		//** Hooks **//
 	
-			// #[pallet::hooks]
-			// impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+			#[pallet::hooks]
+			impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+				
+				// Translates the next page of a pending storage migration.
+				fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+					crate::migrations::migrate_page::<T>()
+				}
+			}
 		
 	
 			
@@ -277,11 +389,13 @@
 				}
 	
 	
-				// Unregister a wallet, automatically claiming any leftover tokens.
-				//TODO-2
-				//TODO-3
+				// Unregister a wallet. This is refused while any feature still holds locked
+				// or claimable tokens of the wallet, as each feature pays them out from its
+				// own account. Otherwise both the wallet's stats and tokens are removed.
+				// The wallet's reputation is kept on purpose: it is earned and slashed by the other
+				// pallets whether or not the wallet is registered, and re-registering must not reset it.
 				#[pallet::call_index(1)]
-				#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+				#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2,2))]
 				pub fn unregister_wallet(
 					origin: OriginFor<T>,
 					name_str: String,
@@ -292,7 +406,14 @@
 					let name: BoundedVec<u8, T::NameStringLimit>
 						= TryInto::try_into(name_str.as_bytes().to_vec()).map_err(|_|Error::<T>::BadMetadata)?;
 
-					let stats = WalletStats::<T>::try_get(who.clone()).unwrap();
+					let stats = WalletStats::<T>::get(who.clone()).ok_or(Error::<T>::WalletNotRegisteredStatTracker)?;
+
+					if let Some(tokens) = WalletTokens::<T>::get(who.clone()) {
+						let blockers = Self::do_get_unregistration_blockers(&tokens);
+						ensure!(blockers.is_empty(), Error::<T>::WalletHasPendingTokens(blockers));
+
+						WalletTokens::<T>::remove(who.clone());
+					}
 	
 					WalletStats::<T>::remove(who.clone());
 	
@@ -354,7 +475,7 @@
 	
 	
 	
-				// TODO-3
+				// Pays out the claimable tokens of every feature.
 				#[pallet::call_index(3)]
 				#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
 				pub fn claim_all_tokens(
@@ -362,47 +483,7 @@
 				) -> DispatchResultWithPostInfo {
 					
 					let who = ensure_signed(origin)?;
-	
-					WalletTokens::<T>::try_mutate_exists(who.clone(), |wallet_tokens| -> DispatchResult {
-						let tokens = wallet_tokens.as_mut().ok_or(Error::<T>::WalletTokensNotFound)?;
-	
-						let zero_balance = BalanceOf::<T>::from(0u32);
-						
-						// add all the claimable tokens into the same var
-						let mut total_tokens = 
-							zero_balance.clone()
-							.checked_add(&tokens.claimable_tokens_moderation)
-							.ok_or(Error::<T>::TokenOverflow)?;
-						total_tokens = 
-							total_tokens
-							.checked_add(&tokens.claimable_tokens_festival)
-							.ok_or(Error::<T>::TokenOverflow)?;
-						total_tokens = 
-							total_tokens
-							.checked_add(&tokens.claimable_tokens_ranking)
-							.ok_or(Error::<T>::TokenOverflow)?;
-	
-						// ensure the transfer works
-						ensure!(
-							T::Currency::transfer(
-								&Self::account_id(),
-								&who.clone(),
-								total_tokens.clone(), 
-								AllowDeath
-							) == Ok(()),
-							Error::<T>::NotEnoughBalance
-						);
-	
-						// reset the total claimable tokens 
-						tokens.claimable_tokens_moderation = zero_balance.clone();
-						tokens.claimable_tokens_festival = zero_balance.clone();
-						tokens.claimable_tokens_ranking = zero_balance;
-	
-						Self::deposit_event(Event::TokensClaimed(who));   
-						Ok(().into())
-					})?;
-	
-	
+					Self::do_claim_all_tokens(who)?;
 	
 					Ok(().into())
 				}
@@ -463,10 +544,16 @@
 	
 					match (feature_type, token_type) {
 						(FeatureType::Festival, TokenType::Locked) => wallet_tokens.locked_tokens_festival = token_change.clone(),
-						(FeatureType::Festival, TokenType::Claimable) => wallet_tokens.claimable_tokens_festival = token_change.clone(),
+						(FeatureType::Festival, TokenType::Claimable) => {
+							wallet_tokens.claimable_tokens_festival = token_change.clone();
+							wallet_tokens.total_tokens_won_festival = token_change.clone();
+						},
 	
 						(FeatureType::RankingList, TokenType::Locked) => wallet_tokens.locked_tokens_ranking = token_change.clone(),
-						(FeatureType::RankingList, TokenType::Claimable) => wallet_tokens.claimable_tokens_ranking = token_change.clone(),
+						(FeatureType::RankingList, TokenType::Claimable) => {
+							wallet_tokens.claimable_tokens_ranking = token_change.clone();
+							wallet_tokens.total_tokens_won_ranking = token_change.clone();
+						},
 						
 						(FeatureType::Moderation, TokenType::Locked) => wallet_tokens.locked_tokens_moderation = token_change.clone(),
 						(FeatureType::Moderation, TokenType::Claimable) => wallet_tokens.claimable_tokens_moderation = token_change.clone(),
@@ -810,46 +897,169 @@
 				}
 	
 	
-				// Calculates a new value for a wallet's reputation.
-				// If this is a slash, the value is subtracted to a minimum of 0.
-				// If not, the value is added to the total.
-				// The updated values are then returned.
-				pub fn do_calculate_reputation_change(
+				// Applies the decay of every period that fully elapsed since the
+				// score was last decayed. Each period removes ReputationDecay of
+				// the score, rounded up so that small scores still reach 0.
+				pub fn do_get_decayed_score(
+					reputation: ReputationScore<BlockNumberFor<T>>,
+				) -> ReputationScore<BlockNumberFor<T>> {
+					
+					let current_block = <frame_system::Pallet<T>>::block_number();
+					let decay_period = T::ReputationDecayPeriod::get();
+					if decay_period.is_zero() {
+						return ReputationScore { score: reputation.score, decayed_at: current_block };
+					}
+
+					let elapsed_periods = current_block.saturating_sub(reputation.decayed_at) / decay_period;
+					let decay = T::ReputationDecay::get();
+					let mut score = reputation.score;
+					let mut periods = elapsed_periods;
+					// every step removes at least 1 point, so this ends once the score is 0
+					while !periods.is_zero() {
+						let decayed_points = decay.mul_ceil(score);
+						if decayed_points == 0 {
+							break;
+						}
+						score = score.saturating_sub(decayed_points);
+						periods = periods.saturating_sub(One::one());
+					}
+
+					ReputationScore {
+						score,
+						decayed_at: reputation.decayed_at.saturating_add(elapsed_periods.saturating_mul(decay_period)),
+					}
+				}
+
+
+				// Decays and then updates a wallet's score in the domain.
+				// If this is a slash, the points are subtracted to a minimum of 0.
+				// If not, they are added to the score. The new score is returned.
+				pub fn do_update_reputation(
 					who: T::AccountId,
-					new_reputation: u32,
+					domain: ReputationDomain,
+					points: u32,
 					is_slash: bool,
-				) -> Result<u32, DispatchError> {
+				) -> u32 {
+					
+					let new_score = WalletReputation::<T>::mutate(who.clone(), domain, |reputation| {
+						let mut decayed = Self::do_get_decayed_score(*reputation);
+						decayed.score = 
+							if is_slash { decayed.score.saturating_sub(points) }
+							else { decayed.score.saturating_add(points) };
+						
+						*reputation = decayed;
+						decayed.score
+					});
+
+					Self::deposit_event(Event::ReputationUpdated(who, domain, new_score));
+					new_score
+				}
+
+
+				// The wallet's score in the domain, with the pending decay applied.
+				pub fn do_get_reputation(
+					who: T::AccountId,
+					domain: ReputationDomain,
+				) -> u32 {
+					Self::do_get_decayed_score(WalletReputation::<T>::get(who, domain)).score
+				}
+
+
+				pub fn do_get_reputation_breakdown(
+					who: T::AccountId,
+				) -> ReputationBreakdown {
+					
+					let curation = Self::do_get_reputation(who.clone(), ReputationDomain::Curation);
+					let moderation = Self::do_get_reputation(who.clone(), ReputationDomain::Moderation);
+					let creation = Self::do_get_reputation(who.clone(), ReputationDomain::Creation);
+					let community = Self::do_get_reputation(who, ReputationDomain::Community);
+
+					ReputationBreakdown {
+						curation,
+						moderation,
+						creation,
+						community,
+						total: curation
+							.saturating_add(moderation)
+							.saturating_add(creation)
+							.saturating_add(community),
+					}
+				}
 					
-					let final_reputation: u32 = WalletTokens::<T>::try_mutate(who.clone(), |wal_tokens| -> Result<u32, DispatchError> {
-						let wallet_tokens = wal_tokens.as_mut().ok_or(Error::<T>::WalletTokensNotFound)?;
-						let mut current_reputation = wallet_tokens.reputation_moderation;
-	
-						if is_slash {
-							if current_reputation > new_reputation {
-								current_reputation =
-									current_reputation
-									.checked_sub(new_reputation)
-									.ok_or(Error::<T>::ReputationUnderflow)?;
-							}
-							else {
-								current_reputation = 0u32;
-							}
-						}
-						else {
-							current_reputation =
-								current_reputation
-								.checked_add(new_reputation)
-								.ok_or(Error::<T>::ReputationOverflow)?;
-						}
 	
-						Ok(current_reputation)
-					})?;
 	
-					Ok(final_reputation)
-				}
+				// Transfers the claimable tokens of every feature to the wallet
+				// and resets them. Returns the amount that was paid out.
+				pub fn do_claim_all_tokens(
+					who: T::AccountId,
+				) -> Result<BalanceOf<T>, DispatchError> {
 					
+					let total_tokens = WalletTokens::<T>::try_mutate_exists(who.clone(), |wallet_tokens| 
+					-> Result<BalanceOf<T>, DispatchError> {
+						let tokens = wallet_tokens.as_mut().ok_or(Error::<T>::WalletTokensNotFound)?;
 	
+						let zero_balance = BalanceOf::<T>::from(0u32);
+						
+						// add all the claimable tokens into the same var
+						let total_tokens = 
+							tokens.claimable_tokens_moderation
+							.checked_add(&tokens.claimable_tokens_festival)
+							.and_then(|total| total.checked_add(&tokens.claimable_tokens_ranking))
+							.and_then(|total| total.checked_add(&tokens.claimable_tokens_movie))
+							.ok_or(Error::<T>::TokenOverflow)?;
 	
+						// ensure the transfer works
+						if total_tokens > zero_balance {
+							ensure!(
+								T::Currency::transfer(
+									&Self::account_id(),
+									&who.clone(),
+									total_tokens.clone(), 
+									AllowDeath
+								) == Ok(()),
+								Error::<T>::NotEnoughBalance
+							);
+						}
+	
+						// reset the total claimable tokens 
+						tokens.claimable_tokens_moderation = zero_balance.clone();
+						tokens.claimable_tokens_festival = zero_balance.clone();
+						tokens.claimable_tokens_ranking = zero_balance.clone();
+						tokens.claimable_tokens_movie = zero_balance;
+	
+						Ok(total_tokens)
+					})?;
+
+					Self::deposit_event(Event::TokensClaimed(who));
+					Ok(total_tokens)
+				}
+
+
+				// The features that still hold locked or claimable tokens in the wallet.
+				pub fn do_get_unregistration_blockers(
+					tokens: &Tokens<BalanceOf<T>, (BalanceOf<T>, BalanceOf<T>)>,
+				) -> UnregistrationBlockers {
+					
+					let zero_balance = BalanceOf::<T>::from(0u32);
+					let pending = [
+						(tokens.locked_tokens_festival, UnregistrationBlockers::LOCKED_FESTIVAL),
+						(tokens.locked_tokens_ranking, UnregistrationBlockers::LOCKED_RANKING),
+						(tokens.locked_tokens_moderation, UnregistrationBlockers::LOCKED_MODERATION),
+						(tokens.locked_tokens_movie, UnregistrationBlockers::LOCKED_MOVIE),
+						(tokens.claimable_tokens_festival, UnregistrationBlockers::CLAIMABLE_FESTIVAL),
+						(tokens.claimable_tokens_ranking, UnregistrationBlockers::CLAIMABLE_RANKING),
+						(tokens.claimable_tokens_moderation, UnregistrationBlockers::CLAIMABLE_MODERATION),
+						(tokens.claimable_tokens_movie, UnregistrationBlockers::CLAIMABLE_MOVIE),
+					];
+
+					UnregistrationBlockers(
+						pending.iter()
+						.filter(|(amount, _)| *amount > zero_balance)
+						.fold(0u8, |blockers, (_, blocker)| blockers | blocker)
+					)
+				}
+
+
 				// True if the wallet is registered in the "WalletStats" storage.
 				// This always implies that an entry also exists e the 
 				// "WalletTokens" storage.
@@ -875,7 +1085,6 @@
 					let zero_balance = BalanceOf::<T>::from(0u32);
 
 					let mut wallet_tokens = Tokens {
-						reputation_moderation: T::DefaultReputation::get(),
 						locked_tokens_moderation: zero_balance.clone(),
 						claimable_tokens_moderation: zero_balance.clone(),
 						
@@ -896,7 +1105,31 @@
                 }
 
 
+				// Used by the runtime API.
+				pub fn reputation_breakdown(
+					who: T::AccountId,
+				) -> ReputationBreakdown {
+					Self::do_get_reputation_breakdown(who)
+				}
 
 				
 			}
+
+			impl<T: Config> ReputationHandler<T::AccountId> for Pallet<T> {
+				fn update_reputation(who: &T::AccountId, domain: ReputationDomain, points: u32, is_slash: bool) -> u32 {
+					Self::do_update_reputation(who.clone(), domain, points, is_slash)
+				}
+
+				fn reputation(who: &T::AccountId, domain: ReputationDomain) -> u32 {
+					Self::do_get_reputation(who.clone(), domain)
+				}
+
+				fn total_reputation(who: &T::AccountId) -> u32 {
+					Self::do_get_reputation_breakdown(who.clone()).total
+				}
+
+				fn update_weight() -> Weight {
+					T::DbWeight::get().reads_writes(1, 1)
+				}
+			}
 	}
\ No newline at end of file
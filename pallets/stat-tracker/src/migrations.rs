@@ -0,0 +1,122 @@
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::IterableStorageMap,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use core::marker::PhantomData;
+use sp_std::vec::Vec;
+
+type BalanceOf<T> = <<T as Config>::Currency as frame_support::traits::Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+// Migrations are paged: the runtime upgrade translates the first page and stores a cursor,
+// `on_initialize` translates a page per block until the cursor is gone. The other pallets
+// read `WalletTokens`, so they hold back their own migrations and hooks while this one runs.
+pub const MIGRATION_PAGE_SIZE: u32 = 100;
+
+// The raw key of the last translated entry, an empty key when the next step starts from scratch.
+#[frame_support::storage_alias]
+pub type MigrationCursor<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+
+pub fn is_migrating<T: Config>() -> bool {
+	MigrationCursor::<T>::exists()
+}
+
+pub fn migrate_page<T: Config>() -> Weight {
+	let cursor = match MigrationCursor::<T>::get() {
+		Some(cursor) => cursor,
+		None => return T::DbWeight::get().reads(1),
+	};
+	let previous_key = if cursor.is_empty() { None } else { Some(cursor) };
+
+	let (next_key, reads_writes) = match Pallet::<T>::on_chain_storage_version() {
+		version if version == 0 => v1::migrate_page::<T>(previous_key, MIGRATION_PAGE_SIZE),
+		_ => (None, 0),
+	};
+
+	match next_key {
+		Some(key) => MigrationCursor::<T>::put(key),
+		None => {
+			if Pallet::<T>::on_chain_storage_version() == 0 {
+				StorageVersion::new(1).put::<Pallet<T>>();
+			}
+			MigrationCursor::<T>::kill();
+		},
+	}
+	T::DbWeight::get().reads_writes(reads_writes.saturating_add(2), reads_writes.saturating_mul(2).saturating_add(2))
+}
+
+// Moves `Tokens.reputation_moderation` into the Moderation domain of `WalletReputation`,
+// where reputation is now kept per domain. Existing wallets, and so existing moderators,
+// keep the reputation they had, the other domains start at 0.
+pub mod v1 {
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	pub struct OldTokens<Balance, TokenImbalance> {
+		pub reputation_moderation: u32,
+		pub locked_tokens_moderation: Balance,
+		pub claimable_tokens_moderation: Balance,
+		pub locked_tokens_festival: Balance,
+		pub claimable_tokens_festival: Balance,
+		pub total_tokens_won_festival: Balance,
+		pub locked_tokens_ranking: Balance,
+		pub claimable_tokens_ranking: Balance,
+		pub imbalance_tokens_ranking: TokenImbalance,
+		pub total_tokens_won_ranking: Balance,
+		pub locked_tokens_movie: Balance,
+		pub claimable_tokens_movie: Balance,
+	}
+
+	pub type OldTokensOf<T> = OldTokens<BalanceOf<T>, (BalanceOf<T>, BalanceOf<T>)>;
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 0 || MigrationCursor::<T>::exists() {
+				return T::DbWeight::get().reads(2)
+			}
+
+			MigrationCursor::<T>::put(Vec::<u8>::new());
+			migrate_page::<T>()
+		}
+	}
+
+	// Translates up to `limit` wallets after `previous_key`, returning the key to resume from
+	// (`None` once every wallet is translated) and the number of wallets translated.
+	pub fn migrate_page<T: Config>(mut previous_key: Option<Vec<u8>>, limit: u32) -> (Option<Vec<u8>>, u64) {
+		let current_block = <frame_system::Pallet<T>>::block_number();
+		let mut translated: u64 = 0;
+		while translated < limit as u64 {
+			previous_key = WalletTokens::<T>::translate_next::<OldTokensOf<T>, _>(previous_key, |who, old| {
+				WalletReputation::<T>::insert(who, ReputationDomain::Moderation, ReputationScore {
+					score: old.reputation_moderation,
+					decayed_at: current_block,
+				});
+
+				Some(Tokens {
+					locked_tokens_moderation: old.locked_tokens_moderation,
+					claimable_tokens_moderation: old.claimable_tokens_moderation,
+					locked_tokens_festival: old.locked_tokens_festival,
+					claimable_tokens_festival: old.claimable_tokens_festival,
+					total_tokens_won_festival: old.total_tokens_won_festival,
+					locked_tokens_ranking: old.locked_tokens_ranking,
+					claimable_tokens_ranking: old.claimable_tokens_ranking,
+					imbalance_tokens_ranking: old.imbalance_tokens_ranking,
+					total_tokens_won_ranking: old.total_tokens_won_ranking,
+					locked_tokens_movie: old.locked_tokens_movie,
+					claimable_tokens_movie: old.claimable_tokens_movie,
+				})
+			});
+			if previous_key.is_none() {
+				break
+			}
+			translated = translated.saturating_add(1);
+		}
+		(previous_key, translated)
+	}
+}
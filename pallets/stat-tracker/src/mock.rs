@@ -7,7 +7,7 @@ use frame_support::{
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Percent,
 	testing::Header,
 };
 
@@ -79,7 +79,8 @@ impl pallet_balances::Config for Test {
 // Stat Tracker
 
 parameter_types! {
-	pub const DefaultReputation: u32 = 15;
+	pub const ReputationDecay: Percent = Percent::from_percent(10);
+	pub const ReputationDecayPeriod: u64 = 100;
 	pub const WalletNameStringLimit: u32 = 50;
 	pub const PalletStatTrackerId : PalletId = PalletId(*b"kine/trk");
 }
@@ -87,9 +88,10 @@ parameter_types! {
 impl kine_stat_tracker::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type DefaultReputation = DefaultReputation;
 	type NameStringLimit = WalletNameStringLimit;
 	type PalletId = PalletStatTrackerId;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ReputationDecayPeriod;
 }
 
 
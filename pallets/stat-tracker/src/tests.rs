@@ -1,5 +1,6 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, Error, FeatureType, ReputationBreakdown, ReputationDomain, ReputationHandler, TokenType, UnregistrationBlockers, WalletStats, WalletTokens};
+use frame_support::{assert_noop, assert_ok, traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade}};
+use crate::migrations::{self, v1::{MigrateToV1, OldTokens}, MIGRATION_PAGE_SIZE};
 
 #[test]
 fn it_works_for_default_value() {
@@ -66,8 +67,182 @@ fn correct_error_for_none_value() {
 
 // do_calculate_imbalance_change
 
-// do_calculate_reputation_change
+// do_update_reputation
+
+// do_get_decayed_score
 
 // do_is_wallet_registered
 
 // account_id
+
+
+
+#[test]
+fn reputation_is_tracked_per_domain() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(StatTrackerModule::do_update_reputation(1, ReputationDomain::Curation, 20, false), 20);
+		assert_eq!(StatTrackerModule::do_update_reputation(1, ReputationDomain::Moderation, 5, false), 5);
+		// slashes stop at 0
+		assert_eq!(StatTrackerModule::do_update_reputation(1, ReputationDomain::Moderation, 8, true), 0);
+		assert_eq!(StatTrackerModule::do_update_reputation(1, ReputationDomain::Creation, 3, false), 3);
+
+		assert_eq!(
+			StatTrackerModule::reputation_breakdown(1),
+			ReputationBreakdown { curation: 20, moderation: 0, creation: 3, community: 0, total: 23 }
+		);
+		assert_eq!(<StatTrackerModule as ReputationHandler<u64>>::total_reputation(&1), 23);
+		assert_eq!(<StatTrackerModule as ReputationHandler<u64>>::reputation(&2, ReputationDomain::Curation), 0);
+	});
+}
+
+#[test]
+fn reputation_decays_every_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		StatTrackerModule::do_update_reputation(1, ReputationDomain::Curation, 100, false);
+
+		System::set_block_number(99);
+		assert_eq!(StatTrackerModule::do_get_reputation(1, ReputationDomain::Curation), 100);
+		System::set_block_number(100);
+		assert_eq!(StatTrackerModule::do_get_reputation(1, ReputationDomain::Curation), 90);
+		// 100 -> 90 -> 81 -> 72, the decay being rounded up
+		System::set_block_number(350);
+		assert_eq!(StatTrackerModule::do_get_reputation(1, ReputationDomain::Curation), 72);
+
+		// updates apply the pending decay first and keep the period boundaries
+		assert_eq!(StatTrackerModule::do_update_reputation(1, ReputationDomain::Curation, 8, false), 80);
+		assert_eq!(StatTrackerModule::get_wallet_reputation(1, ReputationDomain::Curation).decayed_at, 300);
+		System::set_block_number(400);
+		assert_eq!(StatTrackerModule::do_get_reputation(1, ReputationDomain::Curation), 72);
+
+		// small scores still decay to 0
+		StatTrackerModule::do_update_reputation(2, ReputationDomain::Community, 1, false);
+		System::set_block_number(500);
+		assert_eq!(StatTrackerModule::do_get_reputation(2, ReputationDomain::Community), 0);
+	});
+}
+
+#[test]
+fn unregistering_is_blocked_by_locked_tokens() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			StatTrackerModule::unregister_wallet(RuntimeOrigin::signed(1), "name".into()),
+			Error::<Test>::WalletNotRegisteredStatTracker
+		);
+
+		assert_ok!(StatTrackerModule::register_new_wallet(RuntimeOrigin::signed(1), false, false, "name".into()));
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(1, FeatureType::Festival, TokenType::Locked, 10, false));
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(1, FeatureType::Movie, TokenType::Locked, 5, false));
+		assert_noop!(
+			StatTrackerModule::unregister_wallet(RuntimeOrigin::signed(1), "name".into()),
+			Error::<Test>::WalletHasPendingTokens(UnregistrationBlockers(
+				UnregistrationBlockers::LOCKED_FESTIVAL | UnregistrationBlockers::LOCKED_MOVIE
+			))
+		);
+
+		// once every stake is released the wallet is settled and removed
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(1, FeatureType::Festival, TokenType::Locked, 10, true));
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(1, FeatureType::Movie, TokenType::Locked, 5, true));
+		assert_ok!(StatTrackerModule::unregister_wallet(RuntimeOrigin::signed(1), "name".into()));
+		assert!(!WalletStats::<Test>::contains_key(1));
+		assert!(!WalletTokens::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn unregistering_waits_for_every_claimable_token_to_be_paid() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(StatTrackerModule::register_new_wallet(RuntimeOrigin::signed(1), false, false, "name".into()));
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(1, FeatureType::RankingList, TokenType::Claimable, 7, false));
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(1, FeatureType::Moderation, TokenType::Claimable, 3, false));
+
+		let blockers = UnregistrationBlockers(
+			UnregistrationBlockers::CLAIMABLE_RANKING | UnregistrationBlockers::CLAIMABLE_MODERATION
+		);
+		assert!(blockers.contains(UnregistrationBlockers::CLAIMABLE_RANKING));
+		assert!(!blockers.contains(UnregistrationBlockers::LOCKED_RANKING));
+		assert_noop!(
+			StatTrackerModule::unregister_wallet(RuntimeOrigin::signed(1), "name".into()),
+			Error::<Test>::WalletHasPendingTokens(blockers)
+		);
+
+		// each feature pays its bucket out from its own account
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(1, FeatureType::RankingList, TokenType::Claimable, 7, true));
+		assert_noop!(
+			StatTrackerModule::unregister_wallet(RuntimeOrigin::signed(1), "name".into()),
+			Error::<Test>::WalletHasPendingTokens(UnregistrationBlockers(UnregistrationBlockers::CLAIMABLE_MODERATION))
+		);
+		assert_ok!(StatTrackerModule::do_update_wallet_tokens(1, FeatureType::Moderation, TokenType::Claimable, 3, true));
+
+		assert_ok!(StatTrackerModule::unregister_wallet(RuntimeOrigin::signed(1), "name".into()));
+		assert!(!WalletTokens::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn unregistering_keeps_the_reputation() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(StatTrackerModule::register_new_wallet(RuntimeOrigin::signed(1), false, false, "name".into()));
+		StatTrackerModule::do_update_reputation(1, ReputationDomain::Moderation, 12, false);
+
+		assert_ok!(StatTrackerModule::unregister_wallet(RuntimeOrigin::signed(1), "name".into()));
+		assert_eq!(StatTrackerModule::do_get_reputation(1, ReputationDomain::Moderation), 12);
+
+		assert_ok!(StatTrackerModule::register_new_wallet(RuntimeOrigin::signed(1), false, false, "name".into()));
+		assert_eq!(StatTrackerModule::do_get_reputation(1, ReputationDomain::Moderation), 12);
+	});
+}
+
+fn put_old_tokens(who: u64, reputation_moderation: u32, locked_tokens_moderation: u128, claimable_tokens_moderation: u128) {
+	let old_tokens = OldTokens {
+		reputation_moderation,
+		locked_tokens_moderation,
+		claimable_tokens_moderation,
+		locked_tokens_festival: 0,
+		claimable_tokens_festival: 0,
+		total_tokens_won_festival: 0,
+		locked_tokens_ranking: 0,
+		claimable_tokens_ranking: 0,
+		imbalance_tokens_ranking: (0u128, 0u128),
+		total_tokens_won_ranking: 0,
+		locked_tokens_movie: 0,
+		claimable_tokens_movie: 0,
+	};
+	frame_support::storage::unhashed::put(&WalletTokens::<Test>::hashed_key_for(who), &old_tokens);
+}
+
+#[test]
+fn migration_keeps_the_moderation_reputation() {
+	new_test_ext().execute_with(|| {
+		put_old_tokens(1, 15, 100, 20);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(StatTrackerModule::on_chain_storage_version(), 1);
+		assert_eq!(StatTrackerModule::do_get_reputation(1, ReputationDomain::Moderation), 15);
+		assert_eq!(StatTrackerModule::do_get_reputation(1, ReputationDomain::Curation), 0);
+		let tokens = WalletTokens::<Test>::get(1).unwrap();
+		assert_eq!((tokens.locked_tokens_moderation, tokens.claimable_tokens_moderation), (100, 20));
+	});
+}
+
+#[test]
+fn migration_translates_a_page_per_block() {
+	new_test_ext().execute_with(|| {
+		let wallets = MIGRATION_PAGE_SIZE as u64 + 1;
+		for who in 1..=wallets {
+			put_old_tokens(who, 3, who as u128, 0);
+		}
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert!(migrations::is_migrating::<Test>());
+		assert_eq!(StatTrackerModule::on_chain_storage_version(), 0);
+
+		StatTrackerModule::on_initialize(1);
+		assert!(!migrations::is_migrating::<Test>());
+		assert_eq!(StatTrackerModule::on_chain_storage_version(), 1);
+		for who in 1..=wallets {
+			assert_eq!(WalletTokens::<Test>::get(who).unwrap().locked_tokens_moderation, who as u128);
+			assert_eq!(StatTrackerModule::do_get_reputation(who, ReputationDomain::Moderation), 3);
+		}
+	});
+}
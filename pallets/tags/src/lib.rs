@@ -55,6 +55,25 @@
 					type CategoryStringLimit: Get<u32>;
 					type TagStringLimit: Get<u32>;
 				}
+
+				// Implemented by every pallet holding reportable content, so that the
+				// moderation pallet can hide it after a successful report and restore it
+				// if the verdict is later reversed.
+				pub trait ContentModeration<ContentId> {
+					fn suspend_content(content_id: &ContentId) -> DispatchResult;
+					fn reinstate_content(content_id: &ContentId) -> DispatchResult;
+				}
+
+				impl<ContentId> ContentModeration<ContentId> for () {
+					fn suspend_content(_content_id: &ContentId) -> DispatchResult { Ok(()) }
+					fn reinstate_content(_content_id: &ContentId) -> DispatchResult { Ok(()) }
+				}
+
+				// Implemented next to ContentModeration, so that reports can only target content
+				// that exists and are filed against its owner. Content without an owner returns None.
+				pub trait ContentOwnership<ContentId, AccountId> {
+					fn content_owner(content_id: &ContentId) -> Result<Option<AccountId>, DispatchError>;
+				}
 	
 	
 	
@@ -203,6 +222,18 @@
 						>,
 						OptionQuery
 					>;
+
+
+				// Tags hidden by a successful report. They can't be given to new content.
+				#[pallet::storage]
+				pub type SuspendedTags <T: Config> =
+					StorageDoubleMap<
+						_, 
+						Blake2_128Concat, (CategoryType<T>, CategoryId<T>),
+						Blake2_128Concat, TagId<T>,
+						(),
+						OptionQuery
+					>;
 	
 	
 	
@@ -216,6 +247,8 @@
 			pub enum Event<T: Config> {
 				CategoryCreated(T::AccountId, CategoryId<T>),
 				TagCreated(T::AccountId, TagId<T>, CategoryId<T>),
+				TagSuspended(CategoryId<T>, TagId<T>),
+				TagReinstated(CategoryId<T>, TagId<T>),
 			}
 	
 	
@@ -233,6 +266,8 @@
 				
 				TagAlreadyExists,
 				NonexistentTag,
+				TagSuspended,
+				TagNotSuspended,
 			}
 	
 			
@@ -361,8 +396,12 @@
 							let tag_list = Categories::<T>::try_get((category_type.clone(), category_id.clone()));
 							ensure!(tag_list.is_ok(), Error::<T>::NonexistentCategory);
 							
-							let tag_data = Tags::<T>::try_get((category_type.clone(), category_id), tag_id);
+							let tag_data = Tags::<T>::try_get((category_type.clone(), category_id.clone()), tag_id.clone());
 							ensure!(tag_data.is_ok(), Error::<T>::NonexistentTag);
+							ensure!(
+								!SuspendedTags::<T>::contains_key((category_type.clone(), category_id), tag_id),
+								Error::<T>::TagSuspended
+							);
 						}
 	
 						Ok(())
@@ -392,13 +431,78 @@
 	
 						Ok(())
 					}
+
+
+					// Removes the content from each of the tags' content lists.
+					pub fn do_remove_tag_data (
+						category_type: CategoryType<T>,
+						category_tag_list: BoundedVec<(CategoryId<T>, TagId<T>), T::MaxTags>,
+						content_id: BoundedVec<u8, T::ContentStringLimit>,
+					)-> Result<(), DispatchError> {
+							
+						for (category_id, tag_id) in category_tag_list {
+	
+							Tags::<T>::try_mutate_exists(
+							(category_type.clone(), category_id), 
+							tag_id, |content_with_tag| -> DispatchResult {
+								
+								let tag_content_data = content_with_tag.as_mut().ok_or(Error::<T>::BadMetadata)?;
+								tag_content_data.content_with_tag.retain(|id| id != &content_id);
+	
+								Ok(())
+							})?;
+						}
+	
+						Ok(())
+					}
 	
 	
 	
 	
 					
 				
+			}
+
+
+			// Tags are created at genesis and have no owner.
+			impl<T: Config> ContentOwnership<((CategoryType<T>, CategoryId<T>), TagId<T>), T::AccountId> for Pallet<T> {
+
+				fn content_owner(
+					(category, tag_id): &((CategoryType<T>, CategoryId<T>), TagId<T>),
+				) -> Result<Option<T::AccountId>, DispatchError> {
+
+					ensure!(Tags::<T>::contains_key(category, tag_id), Error::<T>::NonexistentTag);
+					Ok(None)
+				}
+			}
+
+			// Suspended tags stay attached to existing content, they just can't be used again.
+			impl<T: Config> ContentModeration<((CategoryType<T>, CategoryId<T>), TagId<T>)> for Pallet<T> {
+
+				fn suspend_content(
+					((category_type, category_id), tag_id): &((CategoryType<T>, CategoryId<T>), TagId<T>),
+				) -> DispatchResult {
+
+					let category = (category_type.clone(), category_id.clone());
+					ensure!(Tags::<T>::contains_key(category.clone(), tag_id), Error::<T>::NonexistentTag);
+					SuspendedTags::<T>::insert(category, tag_id, ());
+
+					Self::deposit_event(Event::TagSuspended(category_id.clone(), tag_id.clone()));
+					Ok(())
+				}
+
+				fn reinstate_content(
+					((category_type, category_id), tag_id): &((CategoryType<T>, CategoryId<T>), TagId<T>),
+				) -> DispatchResult {
+
+					let category = (category_type.clone(), category_id.clone());
+					ensure!(SuspendedTags::<T>::contains_key(category.clone(), tag_id), Error::<T>::TagNotSuspended);
+					SuspendedTags::<T>::remove(category, tag_id);
+
+					Self::deposit_event(Event::TagReinstated(category_id.clone(), tag_id.clone()));
+					Ok(())
+				}
 			}
 	
 	
-	}
\ No newline at end of file
+	}
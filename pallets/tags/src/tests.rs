@@ -1,5 +1,18 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, Categories, ContentModeration, Error, TagData, TagIdList, Tags};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+fn bounded<S: frame_support::traits::Get<u32>>(value: &str) -> BoundedVec<u8, S> {
+	BoundedVec::truncate_from(value.as_bytes().to_vec())
+}
+
+// Creates the Moderation / Offensive / Spam tag.
+fn spam_tag() -> ((BoundedVec<u8, CategoryStringLimit>, BoundedVec<u8, CategoryStringLimit>), BoundedVec<u8, TagStringLimit>) {
+	let category = (bounded("Moderation"), bounded("Offensive"));
+	let tag_id = bounded("Spam");
+	Categories::<Test>::insert(category.clone(), TagIdList { tag_list: BoundedVec::truncate_from(vec![tag_id.clone()]) });
+	Tags::<Test>::insert(category.clone(), tag_id.clone(), TagData { content_with_tag: BoundedVec::default() });
+	(category, tag_id)
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -22,4 +35,29 @@ fn correct_error_for_none_value() {
 
 // do_validate_tag_data
 
+#[test]
+fn suspended_tags_cant_be_given_to_new_content() {
+	new_test_ext().execute_with(|| {
+		let (category, tag_id) = spam_tag();
+		let (category_type, category_id) = category.clone();
+		let tag_list = BoundedVec::truncate_from(vec![(category_id.clone(), tag_id.clone())]);
+		assert_ok!(TagsModule::do_validate_tag_data(category_type.clone(), tag_list.clone()));
+
+		assert_ok!(TagsModule::suspend_content(&(category.clone(), tag_id.clone())));
+		assert_noop!(TagsModule::do_validate_tag_data(category_type.clone(), tag_list.clone()), Error::<Test>::TagSuspended);
+
+		assert_ok!(TagsModule::reinstate_content(&(category.clone(), tag_id.clone())));
+		assert_ok!(TagsModule::do_validate_tag_data(category_type, tag_list));
+		assert_noop!(TagsModule::reinstate_content(&(category, tag_id)), Error::<Test>::TagNotSuspended);
+	});
+}
+
+#[test]
+fn only_existing_tags_can_be_suspended() {
+	new_test_ext().execute_with(|| {
+		let category = (bounded("Moderation"), bounded("Offensive"));
+		assert_noop!(TagsModule::suspend_content(&(category, bounded("Spam"))), Error::<Test>::NonexistentTag);
+	});
+}
+
 // do_update_tag_data
\ No newline at end of file
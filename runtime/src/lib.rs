@@ -46,7 +46,7 @@ pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{Percent, Perbill, Permill};
 
 /// Import the template pallet.
 // pub use pallet_template;
@@ -156,9 +156,34 @@ parameter_types! {
 
 // Configure FRAME pallets to include in runtime.
 
+/// Refuses the calls of the kine pallets while the storage they use is still being
+/// migrated a page per block.
+pub struct MigrationCallFilter;
+impl frame_support::traits::Contains<RuntimeCall> for MigrationCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		// Every one of these pallets updates the wallet tokens of the stat tracker.
+		if kine_stat_tracker::migrations::is_migrating::<Runtime>() {
+			return !matches!(
+				call,
+				RuntimeCall::StatTrackerModule(..) |
+					RuntimeCall::FestivalModule(..) |
+					RuntimeCall::ModerationModule(..) |
+					RuntimeCall::MovieModule(..) |
+					RuntimeCall::RankingListModule(..)
+			)
+		}
+		match call {
+			RuntimeCall::FestivalModule(..) => !kinera_constellations::migrations::is_migrating::<Runtime>(),
+			RuntimeCall::ModerationModule(..) => !kine_moderation::migrations::is_migrating::<Runtime>(),
+			RuntimeCall::RankingListModule(..) => !kine_ranking_list::migrations::is_migrating::<Runtime>(),
+			_ => true,
+		}
+	}
+}
+
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = MigrationCallFilter;
 	/// The block type for the runtime.
 	type Block = Block;
 	/// Block & extrinsics weights: base values and limits.
@@ -272,6 +297,9 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+// Only good enough for the dev chain, to be replaced by a VRF based source.
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
 /// Configure the pallet-template in pallets/template.
 // impl pallet_template::Config for Runtime {
 // 	type RuntimeEvent = RuntimeEvent;
@@ -284,12 +312,27 @@ impl pallet_sudo::Config for Runtime {
 parameter_types! {
 	pub const ReportJustificationLimit: u32 = 250;
 	pub const MaxReportsByModerator: u32 = 3;
+	pub const MaxModerators: u32 = 1000;
 	pub const TotalTierOneModerators: u32 = 3;
 	pub const MaxReportsByTier: u32 = 23;
+	pub const MaxReportCoSigners: u32 = 10;
+	pub const MaxInfringementTypes: u32 = 11;
+	pub const MaxEvidencePerReport: u32 = 20;
+	pub const EvidenceCidLimit: u32 = 64;
 	pub const MinimumReputationForSeniorship: u32 = 30;
 	pub const MinimumReputationForModeration: u32 = 10;
 	pub const MinimumTokensForModeration: u32 = 10000;
+	pub const MinimumTokensForSeniority: u32 = 20000;
+	pub const ModeratorUnbondingPeriod: BlockNumber = 7 * DAYS;
 	pub const PalletModerationId: PalletId = PalletId(*b"ModStash");
+	pub const ReportVotingPeriod: BlockNumber = 3 * DAYS;
+	pub const ReportRevealPeriod: BlockNumber = 1 * DAYS;
+	pub const ReportAppealWindow: BlockNumber = 2 * DAYS;
+	pub const MaxReportDeadlinesPerBlock: u32 = 50;
+	pub const ModeratorMetricsMinimumReports: u32 = 20;
+	pub const MinimumMajorityAgreement: Percent = Percent::from_percent(60);
+	pub const MaxMissedModerationDeadlines: u32 = 5;
+	pub const MaxModeratorResponseTimes: u32 = 50;
 }
 
 impl kine_moderation::Config for Runtime{
@@ -298,18 +341,106 @@ impl kine_moderation::Config for Runtime{
 	type JustificationLimit = ReportJustificationLimit;
     
 	type ContentId = u32;
+	type MovieIdLimit = LinkStringLimit;
 	type MaxReportsByModerator = MaxReportsByModerator;
+	type MaxModerators = MaxModerators;
 	type TotalTierOneModerators = TotalTierOneModerators;
 	type MaxReportsByTier = MaxReportsByTier;
+	type MaxCoSigners = MaxReportCoSigners;
+	type MaxInfringementTypes = MaxInfringementTypes;
+	type MaxEvidencePerReport = MaxEvidencePerReport;
+	type EvidenceCidLimit = EvidenceCidLimit;
 	
+	type Reputation = StatTrackerModule;
 	type MinimumReputationForSeniorship = MinimumReputationForSeniorship;
 	type MinimumReputationForModeration = MinimumReputationForModeration;
 	type MinimumTokensForModeration = MinimumTokensForModeration;
+	type MinimumTokensForSeniority = MinimumTokensForSeniority;
+	type UnbondingPeriod = ModeratorUnbondingPeriod;
 	type MovieCollateral = MovieCollateral;
+	type VotingPeriod = ReportVotingPeriod;
+	type AppealWindow = ReportAppealWindow;
+	type RevealPeriod = ReportRevealPeriod;
+	type MaxDeadlinesPerBlock = MaxReportDeadlinesPerBlock;
+	type Randomness = RandomnessCollectiveFlip;
+	type ContentModerator = ReportedContent;
+	type ContentOwners = ReportedContent;
+	type ConflictsOfInterest = ModerationConflicts;
+	type MetricsMinimumReports = ModeratorMetricsMinimumReports;
+	type MinimumMajorityAgreement = MinimumMajorityAgreement;
+	type MaxMissedDeadlines = MaxMissedModerationDeadlines;
+	type MaxResponseTimes = MaxModeratorResponseTimes;
+	type FinalAppealOrigin = frame_system::EnsureRoot<AccountId>;
+	type ModerationAdminOrigin = frame_system::EnsureRoot<AccountId>;
 
 	type PalletId = PalletModerationId;
 }
 
+// Routes reports to the pallet holding the reported content, to find its owner
+// and to suspend or restore it once the verdict is known.
+pub struct ReportedContent;
+
+impl kine_tags::ContentModeration<kine_moderation::ContentRefOf<Runtime>> for ReportedContent {
+	fn suspend_content(content: &kine_moderation::ContentRefOf<Runtime>) -> sp_runtime::DispatchResult {
+		use kine_tags::ContentModeration;
+		match content {
+			kine_moderation::ContentRef::Movie(movie_id) => MovieModule::suspend_content(movie_id),
+			kine_moderation::ContentRef::Festival(festival_id) => FestivalModule::suspend_content(festival_id),
+			kine_moderation::ContentRef::RankingList(list_id) => RankingListModule::suspend_content(list_id),
+			kine_moderation::ContentRef::Community(community_id) => CommunitiesModule::suspend_content(community_id),
+			kine_moderation::ContentRef::Tag(category, tag_id) => TagsModule::suspend_content(&(category.clone(), tag_id.clone())),
+		}
+	}
+
+	fn reinstate_content(content: &kine_moderation::ContentRefOf<Runtime>) -> sp_runtime::DispatchResult {
+		use kine_tags::ContentModeration;
+		match content {
+			kine_moderation::ContentRef::Movie(movie_id) => MovieModule::reinstate_content(movie_id),
+			kine_moderation::ContentRef::Festival(festival_id) => FestivalModule::reinstate_content(festival_id),
+			kine_moderation::ContentRef::RankingList(list_id) => RankingListModule::reinstate_content(list_id),
+			kine_moderation::ContentRef::Community(community_id) => CommunitiesModule::reinstate_content(community_id),
+			kine_moderation::ContentRef::Tag(category, tag_id) => TagsModule::reinstate_content(&(category.clone(), tag_id.clone())),
+		}
+	}
+}
+
+impl kine_tags::ContentOwnership<kine_moderation::ContentRefOf<Runtime>, AccountId> for ReportedContent {
+	fn content_owner(content: &kine_moderation::ContentRefOf<Runtime>) -> Result<Option<AccountId>, sp_runtime::DispatchError> {
+		use kine_tags::ContentOwnership;
+		match content {
+			kine_moderation::ContentRef::Movie(movie_id) => MovieModule::content_owner(movie_id),
+			kine_moderation::ContentRef::Festival(festival_id) => FestivalModule::content_owner(festival_id),
+			kine_moderation::ContentRef::RankingList(list_id) => RankingListModule::content_owner(list_id),
+			kine_moderation::ContentRef::Community(community_id) => CommunitiesModule::content_owner(community_id),
+			kine_moderation::ContentRef::Tag(category, tag_id) => TagsModule::content_owner(&(category.clone(), tag_id.clone())),
+		}
+	}
+}
+
+// Moderators sharing a community with the reportee, or who voted in the reported festival,
+// aren't drafted for the report.
+pub struct ModerationConflicts;
+
+impl kine_moderation::ConflictOfInterest<AccountId, kine_moderation::ContentRefOf<Runtime>> for ModerationConflicts {
+	fn has_conflict(moderator_id: &AccountId, reportee_id: &AccountId, content: &kine_moderation::ContentRefOf<Runtime>) -> bool {
+		let reportee_communities = CommunitiesModule::user_communities(reportee_id);
+		let shares_community = 
+			CommunitiesModule::user_communities(moderator_id).iter()
+			.any(|community| reportee_communities.iter().any(|other| other.id == community.id));
+		let voted_in_festival = match content {
+			kine_moderation::ContentRef::Festival(festival_id) => 
+				kinera_constellations::FestivalVoterStakes::<Runtime>::contains_key(festival_id, moderator_id),
+			_ => false,
+		};
+		shares_community || voted_in_festival
+	}
+
+	// both wallets' communities and the festival stake
+	fn check_weight() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads(3)
+	}
+}
+
 
 
 // Movie
@@ -325,6 +456,7 @@ impl kine_movie::Config for Runtime {
     type StringLimit = MovieStringLimit;
     type LinkStringLimit = LinkStringLimit;
     type MovieCollateral = MovieCollateral;
+    type OnMovieTagged = RankingListModule;
 }
 
 // Festival
@@ -336,6 +468,8 @@ parameter_types! {
 	pub const FestBlockSafetyMargin: u32 = 1; // prev 10
 	pub const MaxFestivalsPerBlock: u32 = 500;
 	pub const MaxVotes: u32 = 2000;
+	pub const MaxSponsorsPerFestival: u32 = 100;
+	pub const FestivalFilmmakerShare: Perbill = Perbill::from_percent(10);
 	pub const PalletFestivalId: PalletId = PalletId(*b"FesStash");
 }
 
@@ -349,6 +483,9 @@ impl kinera_constellations::Config for Runtime{
 	type MaxFestivalsPerBlock = MaxFestivalsPerBlock;
 	type MaxVotes = MaxVotes;
 	type FestBlockSafetyMargin = FestBlockSafetyMargin;
+	type MaxSponsorsPerFestival = MaxSponsorsPerFestival;
+	type FilmmakerShare = FestivalFilmmakerShare;
+	type Reputation = StatTrackerModule;
 	type PalletId = PalletFestivalId;
 }
 
@@ -365,22 +502,25 @@ impl kinera_communities::Config for Runtime {
   type Currency = Balances;
   type MaxCategoryLength = ConstU32<400>;
   type MaxShortDescLength = ConstU32<1000>;
+  type Reputation = StatTrackerModule;
 }
 
 
 // Stat Tracker
 parameter_types! {
-	pub const DefaultReputation: u32 = 15;
 	pub const WalletNameStringLimit: u32 = 50;
 	pub const PalletStatTrackerId : PalletId = PalletId(*b"kine/trk");
+	pub const ReputationDecay: Percent = Percent::from_percent(5);
+	pub const ReputationDecayPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl kine_stat_tracker::Config for Runtime {
  	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type DefaultReputation = DefaultReputation;
 	type NameStringLimit = CategoryStringLimit;
 	type PalletId = PalletStatTrackerId;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ReputationDecayPeriod;
 }
 
 // Tags
@@ -409,16 +549,57 @@ parameter_types!{
 	pub const MaxMoviesInList: u32 = 100000;
 	pub const MinimumListDuration: u32 = 3600; // six hours in blocks
 	pub const MaxVotersPerList: u32 = 10000000;
+	pub const MaxVotesPerVoter: u32 = 1000;
+	pub const VoterPageSize: u32 = 500;
+	pub const MaxCuratorsPerList: u32 = 100;
+	pub const EloKFactor: u32 = 32;
+	pub const MaxJudgementsPerWindow: u32 = 20;
+	pub const JudgementWindow: u32 = 600;
+	pub const MaxListsPerCurator: u32 = 100;
+	pub const MaxCuratorCommission: Perbill = Perbill::from_percent(20);
 	pub const MaxListsPerBlock: u32 = 50;
+	pub const RewardedMoviesPerList: u32 = 10;
+	pub const RankingSnapshotRetention: u32 = 52;
+	pub const RankingSnapshotDepth: u32 = 100;
+	pub const MaxAutoInsertionsPerBlock: u32 = 100;
+	pub const MaxPendingInsertions: u32 = 10000;
+	pub const ListCreationDeposit: Balance = 1000;
+	pub const BlocksPerYear: u32 = 5256000; // 6 second blocks
+	pub const DefaultRankingRewardApy: Perbill = Perbill::from_percent(18);
+	pub const DefaultRankingRewardPeriod: BlockNumber = 100800; // one week in blocks
+	pub const DefaultRankingRewardBudget: Balance = 1_000_000_000;
 }
 
 impl kine_ranking_list::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+	type LockCurrency = Balances;
 	type MaxListsPerBlock = MaxListsPerBlock;
 	type MaxVotersPerList = MaxVotersPerList;
+	type MaxVotesPerVoter = MaxVotesPerVoter;
+	type VoterPageSize = VoterPageSize;
+	type MaxCuratorsPerList = MaxCuratorsPerList;
+	type EloKFactor = EloKFactor;
+	type MaxJudgementsPerWindow = MaxJudgementsPerWindow;
+	type JudgementWindow = JudgementWindow;
+	type Randomness = RandomnessCollectiveFlip;
+	type MaxListsPerCurator = MaxListsPerCurator;
+	type MaxCuratorCommission = MaxCuratorCommission;
 	type MaxMoviesInList = MaxMoviesInList;
 	type MinimumListDuration = MinimumListDuration;
     type RankingStringLimit = RankingStringLimit;
+	type MaxAutoInsertionsPerBlock = MaxAutoInsertionsPerBlock;
+	type MaxPendingInsertions = MaxPendingInsertions;
+	type ListCreationDeposit = ListCreationDeposit;
+	type ListAdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type SnapshotRetention = RankingSnapshotRetention;
+	type SnapshotDepth = RankingSnapshotDepth;
+	type RewardedMoviesPerList = RewardedMoviesPerList;
+	type Reputation = StatTrackerModule;
+	type BlocksPerYear = BlocksPerYear;
+	type DefaultRewardApy = DefaultRankingRewardApy;
+	type DefaultRewardPeriod = DefaultRankingRewardPeriod;
+	type DefaultRewardBudget = DefaultRankingRewardBudget;
+	type RewardsOrigin = frame_system::EnsureRoot<AccountId>;
     type PalletId = PalletRankingListId;
 }
 
@@ -437,6 +618,7 @@ construct_runtime!(
 		Balances: pallet_balances,
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
+		RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
 		// Include the custom logic from the pallet-template in the runtime.
 		// TemplateModule: pallet_template,
 		FestivalModule: kinera_constellations,
@@ -475,6 +657,17 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations applied on the next runtime upgrade. The stat tracker goes first, the
+/// other migrations update the wallet tokens it translates.
+type Migrations = (
+	kine_stat_tracker::migrations::v1::MigrateToV1<Runtime>,
+	kinera_constellations::migrations::v1::MigrateToV1<Runtime>,
+	kine_ranking_list::migrations::v1::MigrateToV1<Runtime>,
+	kine_ranking_list::migrations::v2::MigrateToV2<Runtime>,
+	kine_ranking_list::migrations::v3::MigrateToV3<Runtime>,
+	kine_moderation::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -482,6 +675,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -496,6 +690,8 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo, Sudo]
+		[kinera_constellations, FestivalModule]
+		[kine_ranking_list, RankingListModule]
 		// [pallet_template, TemplateModule]
 	);
 }
@@ -618,6 +814,27 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl kinera_constellations_runtime_api::FestivalPassApi<Block, AccountId, u32> for Runtime {
+		fn has_valid_pass(festival_id: u32, who: AccountId) -> bool {
+			FestivalModule::has_valid_pass(festival_id, who)
+		}
+		fn can_access_movie(festival_id: u32, who: AccountId, movie_id: Vec<u8>) -> bool {
+			FestivalModule::can_access_movie(festival_id, who, movie_id)
+		}
+	}
+
+	impl kine_ranking_list_runtime_api::RankingHistoryApi<Block, BlockNumber, Balance> for Runtime {
+		fn movie_rank_history(list_id: u32, movie_id: Vec<u8>) -> Vec<(u32, BlockNumber, u32, Balance)> {
+			RankingListModule::movie_rank_history(list_id, movie_id)
+		}
+	}
+
+	impl kine_stat_tracker_runtime_api::ReputationApi<Block, AccountId, kine_stat_tracker::ReputationBreakdown> for Runtime {
+		fn reputation_breakdown(who: AccountId) -> kine_stat_tracker::ReputationBreakdown {
+			StatTrackerModule::reputation_breakdown(who)
+		}
+	}
+
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
 		fn account_nonce(account: AccountId) -> Nonce {
 			System::account_nonce(account)